@@ -1,16 +1,25 @@
 use crate::data_loader::DataLoader;
-use ckb_std::ckb_constants::Source;
-use ckb_std::ckb_types::packed::{CellOutput, OutPoint, Script, WitnessArgs};
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::ckb_types::packed::{CellOutput, Header, OutPoint, Script, WitnessArgs};
 use ckb_std::error::SysError;
 use ckb_std::high_level::{
-    load_cell, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
-    load_cell_type_hash, load_input_out_point, load_script, load_script_hash, load_witness_args,
+    load_cell, load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash,
+    load_cell_occupied_capacity, load_cell_type, load_cell_type_hash, load_header,
+    load_input_out_point, load_input_since, load_script, load_script_hash, load_tx_hash,
+    load_witness_args,
 };
+use ckb_std::syscalls;
 use std::prelude::v1::*;
 
 pub struct Chain {}
 
+const BUF_SIZE: usize = 1024;
+
 impl DataLoader for Chain {
+    fn load_tx_hash(&self) -> Result<[u8; 32], SysError> {
+        load_tx_hash()
+    }
+
     fn load_script_hash(&self) -> Result<[u8; 32], SysError> {
         load_script_hash()
     }
@@ -23,6 +32,20 @@ impl DataLoader for Chain {
         load_cell_data(index, source)
     }
 
+    // the syscall fills the whole buffer and reports the full length when the data is longer.
+    fn load_cell_data_prefix(
+        &self,
+        buf: &mut [u8],
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        match syscalls::load_cell_data(buf, 0, index, source) {
+            Ok(len) => Ok(len),
+            Err(SysError::LengthNotEnough(_)) => Ok(buf.len()),
+            Err(err) => Err(err),
+        }
+    }
+
     fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
         load_cell_lock_hash(index, source)
     }
@@ -31,10 +54,53 @@ impl DataLoader for Chain {
         load_input_out_point(index, source)
     }
 
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        load_input_since(index, source)
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        load_header(index, source)
+    }
+
     fn load_cell(&self, index: usize, source: Source) -> Result<CellOutput, SysError> {
         load_cell(index, source)
     }
 
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        load_cell_capacity(index, source)
+    }
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        load_cell_occupied_capacity(index, source)
+    }
+
+    // high_level has no raw version of this syscall, so grow the buffer the way its
+    // `load_data` does when the field is longer than `BUF_SIZE`.
+    fn load_cell_by_field(
+        &self,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<Vec<u8>, SysError> {
+        let mut buf = [0u8; BUF_SIZE];
+        match syscalls::load_cell_by_field(&mut buf, 0, index, source, field) {
+            Ok(len) => Ok(buf[..len].to_vec()),
+            Err(SysError::LengthNotEnough(actual_size)) => {
+                let mut data = vec![0u8; actual_size];
+                data[..BUF_SIZE].copy_from_slice(&buf);
+                syscalls::load_cell_by_field(
+                    &mut data[BUF_SIZE..],
+                    BUF_SIZE,
+                    index,
+                    source,
+                    field,
+                )?;
+                Ok(data)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError> {
         load_cell_type(index, source)
     }
@@ -1,5 +1,5 @@
-use ckb_std::ckb_constants::Source;
-use ckb_std::ckb_types::packed::{CellOutput, OutPoint, Script, WitnessArgs};
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::ckb_types::packed::{CellOutput, Header, OutPoint, Script, WitnessArgs};
 use ckb_std::error::SysError;
 #[cfg(feature = "std")]
 use mockall::predicate::*;
@@ -9,18 +9,45 @@ use std::prelude::v1::*;
 
 #[cfg_attr(feature = "std", automock)]
 pub trait DataLoader {
+    fn load_tx_hash(&self) -> Result<[u8; 32], SysError>;
+
     fn load_script_hash(&self) -> Result<[u8; 32], SysError>;
 
     fn load_witness_args(&self, index: usize, source: Source) -> Result<WitnessArgs, SysError>;
 
     fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, SysError>;
 
+    /// Load only the leading bytes of the cell data into `buf`, returning how many were loaded:
+    /// `buf.len()`, or the data length if the data is shorter.
+    fn load_cell_data_prefix(
+        &self,
+        buf: &mut [u8],
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError>;
+
     fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError>;
 
     fn load_input_out_point(&self, index: usize, source: Source) -> Result<OutPoint, SysError>;
 
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError>;
+
     fn load_cell(&self, index: usize, source: Source) -> Result<CellOutput, SysError>;
 
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError>;
+
+    /// Load the raw bytes of a single cell field, as returned by the `load_cell_by_field` syscall.
+    fn load_cell_by_field(
+        &self,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<Vec<u8>, SysError>;
+
     fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError>;
 
     fn load_cell_lock(&self, index: usize, source: Source) -> Result<Script, SysError>;
@@ -33,3 +60,96 @@ pub trait DataLoader {
 
     fn load_script(&self) -> Result<Script, SysError>;
 }
+
+type QueryFn<L, T> = fn(&L, usize, Source) -> Result<T, SysError>;
+
+/// Iterates a per-index loader of a `DataLoader` over a `Source`, from index 0 until the loader
+/// returns `IndexOutOfBound`. Any other error panics, as `ckb_std::high_level::QueryIter` does.
+pub struct LoaderIter<'a, L: ?Sized, T> {
+    loader: &'a L,
+    query_fn: QueryFn<L, T>,
+    index: usize,
+    source: Source,
+}
+
+impl<'a, L: ?Sized, T> LoaderIter<'a, L, T> {
+    pub fn new(loader: &'a L, query_fn: QueryFn<L, T>, source: Source) -> Self {
+        Self {
+            loader,
+            query_fn,
+            index: 0,
+            source,
+        }
+    }
+}
+
+impl<'a, L: ?Sized, T> Iterator for LoaderIter<'a, L, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.query_fn)(self.loader, self.index, self.source) {
+            Ok(item) => {
+                self.index += 1;
+                Some(item)
+            }
+            Err(SysError::IndexOutOfBound) => None,
+            Err(err) => panic!(
+                "iter {:?} return an error: {:?}, index: {}",
+                self.source, err, self.index
+            ),
+        }
+    }
+}
+
+/// Iterator helpers available on every `DataLoader`.
+pub trait DataLoaderIter: DataLoader {
+    fn iter_cells(&self, source: Source) -> LoaderIter<Self, CellOutput> {
+        LoaderIter::new(self, Self::load_cell, source)
+    }
+
+    fn iter_cell_data(&self, source: Source) -> LoaderIter<Self, Vec<u8>> {
+        LoaderIter::new(self, Self::load_cell_data, source)
+    }
+
+    fn iter_cell_locks(&self, source: Source) -> LoaderIter<Self, Script> {
+        LoaderIter::new(self, Self::load_cell_lock, source)
+    }
+
+    fn iter_cell_lock_hashes(&self, source: Source) -> LoaderIter<Self, [u8; 32]> {
+        LoaderIter::new(self, Self::load_cell_lock_hash, source)
+    }
+
+    fn iter_cell_types(&self, source: Source) -> LoaderIter<Self, Option<Script>> {
+        LoaderIter::new(self, Self::load_cell_type, source)
+    }
+
+    fn iter_cell_type_hashes(&self, source: Source) -> LoaderIter<Self, Option<[u8; 32]>> {
+        LoaderIter::new(self, Self::load_cell_type_hash, source)
+    }
+
+    fn iter_cell_capacities(&self, source: Source) -> LoaderIter<Self, u64> {
+        LoaderIter::new(self, Self::load_cell_capacity, source)
+    }
+
+    fn iter_cell_occupied_capacities(&self, source: Source) -> LoaderIter<Self, u64> {
+        LoaderIter::new(self, Self::load_cell_occupied_capacity, source)
+    }
+
+    fn iter_headers(&self, source: Source) -> LoaderIter<Self, Header> {
+        LoaderIter::new(self, Self::load_header, source)
+    }
+
+    fn iter_input_out_points(&self, source: Source) -> LoaderIter<Self, OutPoint> {
+        LoaderIter::new(self, Self::load_input_out_point, source)
+    }
+
+    fn iter_input_since(&self, source: Source) -> LoaderIter<Self, u64> {
+        LoaderIter::new(self, Self::load_input_since, source)
+    }
+
+    fn iter_witness_args(&self, source: Source) -> LoaderIter<Self, WitnessArgs> {
+        LoaderIter::new(self, Self::load_witness_args, source)
+    }
+}
+
+impl<T: DataLoader + ?Sized> DataLoaderIter for T {}
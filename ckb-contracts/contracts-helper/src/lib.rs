@@ -7,3 +7,8 @@ pub mod chain;
 pub mod data_loader;
 pub mod debug;
 pub mod entry;
+#[cfg(feature = "std")]
+pub mod mock_transaction;
+pub mod owner_cell;
+pub mod since;
+pub mod verifier;
@@ -0,0 +1,510 @@
+use crate::data_loader::DataLoader;
+use crate::owner_cell::{TYPE_ID_CODE_HASH, TYPE_ID_HASH_TYPE};
+use blake2b_ref::Blake2bBuilder;
+use ckb_std::ckb_constants::{CellField, Source};
+use ckb_std::ckb_types::packed::{CellOutput, Header, OutPoint, Script, WitnessArgs};
+use ckb_std::ckb_types::prelude::*;
+use ckb_std::error::SysError;
+use std::prelude::v1::*;
+
+pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+
+pub fn blake2b_256(s: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32)
+        .personal(CKB_HASH_PERSONALIZATION)
+        .build();
+    blake2b.update(s);
+    blake2b.finalize(&mut result);
+    result
+}
+
+pub fn calc_script_hash(script: &Script) -> [u8; 32] {
+    blake2b_256(script.as_slice())
+}
+
+/// A type id type script of `args`, like the one of an owner cell.
+pub fn type_id_script(args: [u8; 32]) -> Script {
+    Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(TYPE_ID_HASH_TYPE.into())
+        .args(args.to_vec().pack())
+        .build()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptGroupType {
+    Lock,
+    Type,
+}
+
+const BYTE_SHANNONS: u64 = 100_000_000;
+
+fn script_occupied_bytes(script: &Script) -> u64 {
+    // code_hash + hash_type + args
+    32 + 1 + script.args().raw_data().len() as u64
+}
+
+/// A live cell, with `header` being the block it was committed in. Inputs and cell deps
+/// expose that header through `load_header`.
+#[derive(Debug, Clone, Default)]
+pub struct MockCell {
+    pub output: CellOutput,
+    pub data: Vec<u8>,
+    pub header: Option<Header>,
+}
+
+impl MockCell {
+    pub fn new(lock: Script, type_: Option<Script>, data: Vec<u8>) -> Self {
+        let output = CellOutput::new_builder()
+            .lock(lock)
+            .type_(type_.pack())
+            .build();
+        Self {
+            output,
+            data,
+            header: None,
+        }
+    }
+
+    pub fn capacity(mut self, capacity: u64) -> Self {
+        self.output = self.output.as_builder().capacity(capacity.pack()).build();
+        self
+    }
+
+    pub fn header(mut self, header: Header) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    pub fn occupied_capacity(&self) -> u64 {
+        let type_bytes = self
+            .output
+            .type_()
+            .to_opt()
+            .map(|script| script_occupied_bytes(&script))
+            .unwrap_or(0);
+        // capacity + lock + type + data
+        let bytes =
+            8 + script_occupied_bytes(&self.output.lock()) + type_bytes + self.data.len() as u64;
+        bytes * BYTE_SHANNONS
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockInput {
+    pub out_point: OutPoint,
+    pub since: u64,
+    pub cell: MockCell,
+}
+
+/// A transaction described by its cells instead of the syscalls a script makes on it.
+///
+/// `script` is the script being verified, `script_group_type` tells whether it runs as a
+/// lock or a type script, which decides the cells `Source::GroupInput` and
+/// `Source::GroupOutput` refer to.
+#[derive(Debug, Clone)]
+pub struct MockTransaction {
+    pub script: Script,
+    pub script_group_type: ScriptGroupType,
+    pub inputs: Vec<MockInput>,
+    pub outputs: Vec<MockCell>,
+    pub cell_deps: Vec<MockCell>,
+    pub header_deps: Vec<Header>,
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+impl MockTransaction {
+    pub fn new(script: Script, script_group_type: ScriptGroupType) -> Self {
+        Self {
+            script,
+            script_group_type,
+            inputs: vec![],
+            outputs: vec![],
+            cell_deps: vec![],
+            header_deps: vec![],
+            witnesses: vec![],
+        }
+    }
+
+    pub fn input(self, cell: MockCell) -> Self {
+        self.input_with_since(cell, 0)
+    }
+
+    pub fn input_with_since(mut self, cell: MockCell, since: u64) -> Self {
+        let index = self.inputs.len() as u32;
+        let out_point = OutPoint::new_builder()
+            .tx_hash(blake2b_256(&index.to_le_bytes()).pack())
+            .index(index.pack())
+            .build();
+        self.inputs.push(MockInput {
+            out_point,
+            since,
+            cell,
+        });
+        self
+    }
+
+    pub fn output(mut self, cell: MockCell) -> Self {
+        self.outputs.push(cell);
+        self
+    }
+
+    pub fn cell_dep(mut self, cell: MockCell) -> Self {
+        self.cell_deps.push(cell);
+        self
+    }
+
+    pub fn header_dep(mut self, header: Header) -> Self {
+        self.header_deps.push(header);
+        self
+    }
+
+    pub fn witness(mut self, witness: Vec<u8>) -> Self {
+        self.witnesses.push(witness);
+        self
+    }
+
+    fn in_group(&self, cell: &MockCell) -> bool {
+        match self.script_group_type {
+            ScriptGroupType::Lock => cell.output.lock().as_slice() == self.script.as_slice(),
+            ScriptGroupType::Type => match cell.output.type_().to_opt() {
+                Some(type_) => type_.as_slice() == self.script.as_slice(),
+                None => false,
+            },
+        }
+    }
+
+    fn group_input_indices(&self) -> Vec<usize> {
+        (0..self.inputs.len())
+            .filter(|&i| self.in_group(&self.inputs[i].cell))
+            .collect()
+    }
+
+    // lock scripts are only executed on inputs, so their groups never have outputs.
+    fn group_output_indices(&self) -> Vec<usize> {
+        if self.script_group_type == ScriptGroupType::Lock {
+            return vec![];
+        }
+        (0..self.outputs.len())
+            .filter(|&i| self.in_group(&self.outputs[i]))
+            .collect()
+    }
+
+    fn load_mock_input(&self, index: usize, source: Source) -> Result<&MockInput, SysError> {
+        let input = match source {
+            Source::Input => self.inputs.get(index),
+            Source::GroupInput => self
+                .group_input_indices()
+                .get(index)
+                .map(|&i| &self.inputs[i]),
+            _ => None,
+        };
+        input.ok_or(SysError::IndexOutOfBound)
+    }
+
+    fn load_mock_cell(&self, index: usize, source: Source) -> Result<&MockCell, SysError> {
+        let cell = match source {
+            Source::Input => self.inputs.get(index).map(|input| &input.cell),
+            Source::Output => self.outputs.get(index),
+            Source::CellDep => self.cell_deps.get(index),
+            Source::HeaderDep => None,
+            Source::GroupInput => self
+                .group_input_indices()
+                .get(index)
+                .map(|&i| &self.inputs[i].cell),
+            Source::GroupOutput => self
+                .group_output_indices()
+                .get(index)
+                .map(|&i| &self.outputs[i]),
+        };
+        cell.ok_or(SysError::IndexOutOfBound)
+    }
+}
+
+impl DataLoader for MockTransaction {
+    // not the real transaction hash, but it changes whenever the inputs or outputs change.
+    fn load_tx_hash(&self) -> Result<[u8; 32], SysError> {
+        let mut preimage = vec![];
+        for input in self.inputs.iter() {
+            preimage.extend_from_slice(input.out_point.as_slice());
+        }
+        for output in self.outputs.iter() {
+            preimage.extend_from_slice(output.output.as_slice());
+            preimage.extend_from_slice(&output.data);
+        }
+        Ok(blake2b_256(&preimage))
+    }
+
+    fn load_script_hash(&self) -> Result<[u8; 32], SysError> {
+        Ok(calc_script_hash(&self.script))
+    }
+
+    fn load_witness_args(&self, index: usize, source: Source) -> Result<WitnessArgs, SysError> {
+        let index = match source {
+            Source::Input | Source::Output => Some(index),
+            Source::GroupInput => self.group_input_indices().get(index).copied(),
+            Source::GroupOutput => self.group_output_indices().get(index).copied(),
+            Source::CellDep | Source::HeaderDep => None,
+        };
+        let witness = index
+            .and_then(|i| self.witnesses.get(i))
+            .ok_or(SysError::IndexOutOfBound)?;
+        WitnessArgs::from_slice(witness).map_err(|_| SysError::Encoding)
+    }
+
+    fn load_cell_data(&self, index: usize, source: Source) -> Result<Vec<u8>, SysError> {
+        Ok(self.load_mock_cell(index, source)?.data.clone())
+    }
+
+    fn load_cell_data_prefix(
+        &self,
+        buf: &mut [u8],
+        index: usize,
+        source: Source,
+    ) -> Result<usize, SysError> {
+        let data = &self.load_mock_cell(index, source)?.data;
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        Ok(len)
+    }
+
+    fn load_cell_lock_hash(&self, index: usize, source: Source) -> Result<[u8; 32], SysError> {
+        Ok(calc_script_hash(&self.load_cell_lock(index, source)?))
+    }
+
+    fn load_input_out_point(&self, index: usize, source: Source) -> Result<OutPoint, SysError> {
+        Ok(self.load_mock_input(index, source)?.out_point.clone())
+    }
+
+    fn load_input_since(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.load_mock_input(index, source)?.since)
+    }
+
+    fn load_header(&self, index: usize, source: Source) -> Result<Header, SysError> {
+        match source {
+            Source::HeaderDep => self
+                .header_deps
+                .get(index)
+                .cloned()
+                .ok_or(SysError::IndexOutOfBound),
+            Source::Output | Source::GroupOutput => Err(SysError::IndexOutOfBound),
+            _ => self
+                .load_mock_cell(index, source)?
+                .header
+                .clone()
+                .ok_or(SysError::ItemMissing),
+        }
+    }
+
+    fn load_cell(&self, index: usize, source: Source) -> Result<CellOutput, SysError> {
+        Ok(self.load_mock_cell(index, source)?.output.clone())
+    }
+
+    fn load_cell_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self
+            .load_mock_cell(index, source)?
+            .output
+            .capacity()
+            .unpack())
+    }
+
+    fn load_cell_occupied_capacity(&self, index: usize, source: Source) -> Result<u64, SysError> {
+        Ok(self.load_mock_cell(index, source)?.occupied_capacity())
+    }
+
+    fn load_cell_by_field(
+        &self,
+        index: usize,
+        source: Source,
+        field: CellField,
+    ) -> Result<Vec<u8>, SysError> {
+        let cell = self.load_mock_cell(index, source)?;
+        let data = match field {
+            CellField::Capacity => cell.output.capacity().as_slice().to_vec(),
+            CellField::DataHash => blake2b_256(&cell.data).to_vec(),
+            CellField::Lock => cell.output.lock().as_slice().to_vec(),
+            CellField::LockHash => calc_script_hash(&cell.output.lock()).to_vec(),
+            CellField::Type => cell
+                .output
+                .type_()
+                .to_opt()
+                .ok_or(SysError::ItemMissing)?
+                .as_slice()
+                .to_vec(),
+            CellField::TypeHash => {
+                calc_script_hash(&cell.output.type_().to_opt().ok_or(SysError::ItemMissing)?)
+                    .to_vec()
+            }
+            CellField::OccupiedCapacity => cell.occupied_capacity().to_le_bytes().to_vec(),
+        };
+        Ok(data)
+    }
+
+    fn load_cell_type(&self, index: usize, source: Source) -> Result<Option<Script>, SysError> {
+        Ok(self.load_mock_cell(index, source)?.output.type_().to_opt())
+    }
+
+    fn load_cell_lock(&self, index: usize, source: Source) -> Result<Script, SysError> {
+        Ok(self.load_mock_cell(index, source)?.output.lock())
+    }
+
+    fn load_cell_type_hash(
+        &self,
+        index: usize,
+        source: Source,
+    ) -> Result<Option<[u8; 32]>, SysError> {
+        Ok(self
+            .load_cell_type(index, source)?
+            .map(|script| calc_script_hash(&script)))
+    }
+
+    fn load_script(&self) -> Result<Script, SysError> {
+        Ok(self.script.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::DataLoaderIter;
+    use ckb_std::ckb_types::bytes::Bytes;
+    use ckb_std::ckb_types::packed::RawHeader;
+
+    fn script(args: u8) -> Script {
+        Script::new_builder()
+            .args(Bytes::from(vec![args]).pack())
+            .build()
+    }
+
+    #[test]
+    fn test_type_script_groups() {
+        let tx = MockTransaction::new(script(1), ScriptGroupType::Type)
+            .input(MockCell::new(script(0), None, vec![0]))
+            .input(MockCell::new(script(0), Some(script(1)), vec![1]))
+            .output(MockCell::new(script(0), Some(script(2)), vec![2]))
+            .output(MockCell::new(script(0), Some(script(1)), vec![3]))
+            .witness(WitnessArgs::default().as_bytes().to_vec());
+
+        assert_eq!(tx.load_cell_data(0, Source::GroupInput), Ok(vec![1]));
+        assert_eq!(
+            tx.load_cell_data(1, Source::GroupInput),
+            Err(SysError::IndexOutOfBound)
+        );
+        assert_eq!(tx.load_cell_data(0, Source::GroupOutput), Ok(vec![3]));
+        assert_eq!(
+            tx.load_input_out_point(0, Source::GroupInput)
+                .unwrap()
+                .as_slice(),
+            tx.inputs[1].out_point.as_slice()
+        );
+        // the witness of the first group input is at index 1, which is not provided.
+        assert_eq!(
+            tx.load_witness_args(0, Source::GroupInput).unwrap_err(),
+            SysError::IndexOutOfBound
+        );
+        assert!(tx.load_witness_args(0, Source::Input).is_ok());
+    }
+
+    #[test]
+    fn test_lock_script_groups() {
+        let tx = MockTransaction::new(script(1), ScriptGroupType::Lock)
+            .input(MockCell::new(script(0), None, vec![0]))
+            .input(MockCell::new(script(1), None, vec![1]))
+            .output(MockCell::new(script(1), None, vec![2]))
+            .cell_dep(MockCell::new(script(0), Some(script(3)), vec![3]));
+
+        assert_eq!(tx.load_cell_data(0, Source::GroupInput), Ok(vec![1]));
+        assert_eq!(
+            tx.load_cell_data(0, Source::GroupOutput),
+            Err(SysError::IndexOutOfBound)
+        );
+        assert_eq!(
+            tx.load_cell_type_hash(0, Source::CellDep),
+            Ok(Some(calc_script_hash(&script(3))))
+        );
+        assert_eq!(
+            tx.load_cell_lock_hash(1, Source::Input),
+            Ok(calc_script_hash(&script(1)))
+        );
+        assert_eq!(
+            tx.load_cell_data(0, Source::HeaderDep),
+            Err(SysError::IndexOutOfBound)
+        );
+        assert_eq!(
+            tx.load_cell_data(1, Source::CellDep),
+            Err(SysError::IndexOutOfBound)
+        );
+    }
+
+    #[test]
+    fn test_cell_data_prefix() {
+        let tx = MockTransaction::new(script(1), ScriptGroupType::Lock)
+            .input(MockCell::new(script(1), None, vec![1, 2, 3, 4]))
+            .input(MockCell::new(script(1), None, vec![5]));
+
+        let mut buf = [0u8; 2];
+        assert_eq!(tx.load_cell_data_prefix(&mut buf, 0, Source::Input), Ok(2));
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(
+            tx.load_cell_data_prefix(&mut buf, 1, Source::GroupInput),
+            Ok(1)
+        );
+        assert_eq!(buf, [5, 2]);
+        assert_eq!(
+            tx.load_cell_data_prefix(&mut buf, 2, Source::Input),
+            Err(SysError::IndexOutOfBound)
+        );
+    }
+
+    #[test]
+    fn test_headers_since_and_capacity() {
+        let header = Header::new_builder()
+            .raw(RawHeader::new_builder().number(100u64.pack()).build())
+            .build();
+        let tx = MockTransaction::new(script(1), ScriptGroupType::Lock)
+            .input_with_since(
+                MockCell::new(script(1), None, vec![0; 10])
+                    .capacity(1000)
+                    .header(header.clone()),
+                42,
+            )
+            .input(MockCell::new(script(0), Some(script(2)), vec![]))
+            .header_dep(header.clone());
+
+        assert_eq!(
+            tx.load_header(0, Source::GroupInput).unwrap().as_slice(),
+            header.as_slice()
+        );
+        assert_eq!(
+            tx.load_header(1, Source::Input).unwrap_err(),
+            SysError::ItemMissing
+        );
+        assert_eq!(
+            tx.load_header(0, Source::HeaderDep).unwrap().as_slice(),
+            header.as_slice()
+        );
+        assert_eq!(
+            tx.iter_input_since(Source::Input).collect::<Vec<_>>(),
+            vec![42, 0]
+        );
+        assert_eq!(tx.load_cell_capacity(0, Source::Input), Ok(1000));
+        // 8 bytes capacity + 34 bytes lock + 10 bytes data
+        assert_eq!(
+            tx.load_cell_occupied_capacity(0, Source::Input),
+            Ok(52 * BYTE_SHANNONS)
+        );
+        assert_eq!(
+            tx.load_cell_by_field(0, Source::Input, CellField::Type),
+            Err(SysError::ItemMissing)
+        );
+        assert_eq!(
+            tx.load_cell_by_field(1, Source::Input, CellField::TypeHash),
+            Ok(calc_script_hash(&script(2)).to_vec())
+        );
+        assert_eq!(
+            tx.iter_cell_type_hashes(Source::Input).collect::<Vec<_>>(),
+            vec![None, Some(calc_script_hash(&script(2)))]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! The owner cell of a bridge, found by the type hash its scripts carry.
+
+use crate::data_loader::{DataLoader, DataLoaderIter};
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::packed::Script;
+use ckb_std::ckb_types::prelude::*;
+
+/// Code hash of the type id system script, "TYPE_ID" in the last bytes, run with hash type `type`.
+pub const TYPE_ID_CODE_HASH: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x54, 0x59, 0x50,
+    0x45, 0x5f, 0x49, 0x44,
+];
+pub const TYPE_ID_HASH_TYPE: u8 = 1;
+
+/// Whether `script` is a type id, only one live cell can have it so its hash can't be forged.
+pub fn is_type_id(script: &Script) -> bool {
+    script.code_hash().as_slice() == TYPE_ID_CODE_HASH
+        && u8::from(script.hash_type()) == TYPE_ID_HASH_TYPE
+        && script.args().raw_data().len() == 32
+}
+
+/// The index and source of the owner cell of `owner_cell_type_hash`, a cell dep or an input
+/// when the tx spends it. Exactly one cell of the tx can have the type, and it must be a type id.
+pub fn find_owner_cell<T: DataLoader>(
+    data_loader: &T,
+    owner_cell_type_hash: &[u8],
+) -> (usize, Source) {
+    try_find_owner_cell(data_loader, owner_cell_type_hash).expect("owner cell not found")
+}
+
+/// Like `find_owner_cell`, but none if no cell of the tx has the type.
+pub fn try_find_owner_cell<T: DataLoader>(
+    data_loader: &T,
+    owner_cell_type_hash: &[u8],
+) -> Option<(usize, Source)> {
+    let mut owner_cells = [Source::CellDep, Source::Input].iter().flat_map(|&source| {
+        data_loader
+            .iter_cell_type_hashes(source)
+            .enumerate()
+            .filter(|(_, cell_type_hash)| {
+                cell_type_hash.map_or(false, |hash| hash == owner_cell_type_hash)
+            })
+            .map(move |(index, _)| (index, source))
+    });
+    let (index, source) = owner_cells.next()?;
+    if let Some((other_index, other_source)) = owner_cells.next() {
+        panic!(
+            "owner cell is not unique, {:?} {:?} and {:?} {:?}",
+            source, index, other_source, other_index
+        );
+    }
+    let owner_typescript = data_loader
+        .load_cell_type(index, source)
+        .expect("load cell type fail")
+        .expect("owner cell has a type");
+    if !is_type_id(&owner_typescript) {
+        panic!("owner cell type is not a type id, {:?} {:?}", source, index);
+    }
+    Some((index, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_transaction::{
+        calc_script_hash, type_id_script, MockCell, MockTransaction, ScriptGroupType,
+    };
+    use ckb_std::ckb_types::{bytes::Bytes, packed::Script};
+
+    fn script(code_hash: [u8; 32], hash_type: u8, args: Vec<u8>) -> Script {
+        Script::new_builder()
+            .code_hash(code_hash.pack())
+            .hash_type(hash_type.into())
+            .args(Bytes::from(args).pack())
+            .build()
+    }
+
+    fn owner_typescript() -> Script {
+        type_id_script([4u8; 32])
+    }
+
+    fn cell(type_script: Script, data: Vec<u8>) -> MockCell {
+        MockCell::new(script([0u8; 32], 0, vec![]), Some(type_script), data)
+    }
+
+    fn tx() -> MockTransaction {
+        MockTransaction::new(script([1u8; 32], 0, vec![]), ScriptGroupType::Type)
+            .cell_dep(cell(script([2u8; 32], 0, vec![]), vec![]))
+    }
+
+    #[test]
+    fn test_owner_cell_in_cell_deps() {
+        let tx = tx().cell_dep(cell(owner_typescript(), vec![1]));
+        let owner_cell_type_hash = calc_script_hash(&owner_typescript());
+        assert_eq!(
+            find_owner_cell(&tx, &owner_cell_type_hash),
+            (1, Source::CellDep)
+        );
+    }
+
+    #[test]
+    fn test_owner_cell_in_inputs() {
+        let tx = tx()
+            .input(cell(script([2u8; 32], 0, vec![]), vec![]))
+            .input(cell(owner_typescript(), vec![1]));
+        let owner_cell_type_hash = calc_script_hash(&owner_typescript());
+        assert_eq!(
+            find_owner_cell(&tx, &owner_cell_type_hash),
+            (1, Source::Input)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "owner cell not found")]
+    fn test_owner_cell_missing() {
+        find_owner_cell(&tx(), &calc_script_hash(&owner_typescript()));
+    }
+
+    #[test]
+    fn test_try_find_missing_owner_cell() {
+        let owner_cell_type_hash = calc_script_hash(&owner_typescript());
+        assert_eq!(try_find_owner_cell(&tx(), &owner_cell_type_hash), None);
+        let tx = tx().cell_dep(cell(owner_typescript(), vec![1]));
+        assert_eq!(
+            try_find_owner_cell(&tx, &owner_cell_type_hash),
+            Some((1, Source::CellDep))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "owner cell is not unique")]
+    fn test_owner_cell_twice_in_cell_deps() {
+        let tx = tx()
+            .cell_dep(cell(owner_typescript(), vec![1]))
+            .cell_dep(cell(owner_typescript(), vec![2]));
+        find_owner_cell(&tx, &calc_script_hash(&owner_typescript()));
+    }
+
+    #[test]
+    #[should_panic(expected = "owner cell is not unique")]
+    fn test_owner_cell_in_cell_deps_and_inputs() {
+        let tx = tx()
+            .cell_dep(cell(owner_typescript(), vec![1]))
+            .input(cell(owner_typescript(), vec![2]));
+        find_owner_cell(&tx, &calc_script_hash(&owner_typescript()));
+    }
+
+    #[test]
+    fn test_owner_cell_must_be_a_type_id() {
+        let not_type_ids = [
+            script([4u8; 32], TYPE_ID_HASH_TYPE, vec![4u8; 32]),
+            script(TYPE_ID_CODE_HASH, 0, vec![4u8; 32]),
+            script(TYPE_ID_CODE_HASH, TYPE_ID_HASH_TYPE, vec![4u8; 20]),
+        ];
+        for typescript in not_type_ids.iter() {
+            let tx = tx().cell_dep(cell(typescript.clone(), vec![1]));
+            let owner_cell_type_hash = calc_script_hash(typescript);
+            let result = std::panic::catch_unwind(|| find_owner_cell(&tx, &owner_cell_type_hash));
+            let err = result.expect_err("owner cell of another type accepted");
+            let message = err
+                .downcast_ref::<String>()
+                .expect("panic with a formatted message");
+            assert!(message.starts_with("owner cell type is not a type id"));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! The `since` field of a cell input, see RFC 0017 "Transaction valid since".
+
+const RELATIVE_FLAG: u64 = 1 << 63;
+const METRIC_MASK: u64 = 0b11 << 61;
+const RESERVED_MASK: u64 = 0b1_1111 << 56;
+const VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SinceMetric {
+    BlockNumber,
+    EpochNumberWithFraction,
+    Timestamp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Since(pub u64);
+
+impl Since {
+    pub fn is_relative(self) -> bool {
+        self.0 & RELATIVE_FLAG != 0
+    }
+
+    /// `None` when the metric flag or the reserved bits are invalid.
+    pub fn metric(self) -> Option<SinceMetric> {
+        if self.0 & RESERVED_MASK != 0 {
+            return None;
+        }
+        match (self.0 & METRIC_MASK) >> 61 {
+            0 => Some(SinceMetric::BlockNumber),
+            1 => Some(SinceMetric::EpochNumberWithFraction),
+            2 => Some(SinceMetric::Timestamp),
+            _ => None,
+        }
+    }
+
+    pub fn value(self) -> u64 {
+        self.0 & VALUE_MASK
+    }
+
+    /// Whether an input with this since can only be committed once `required` is satisfied too,
+    /// which needs the same relative flag and metric, and a value no less than `required`.
+    pub fn is_at_least(self, required: Since) -> bool {
+        if self.is_relative() != required.is_relative() {
+            return false;
+        }
+        match (self.metric(), required.metric()) {
+            (
+                Some(SinceMetric::EpochNumberWithFraction),
+                Some(SinceMetric::EpochNumberWithFraction),
+            ) => epoch_is_at_least(self.value(), required.value()),
+            (Some(metric), Some(required_metric)) if metric == required_metric => {
+                self.value() >= required.value()
+            }
+            _ => false,
+        }
+    }
+}
+
+// number: bits 0..24, index: bits 24..40, length: bits 40..56.
+fn epoch_is_at_least(epoch: u64, required: u64) -> bool {
+    let number = |v: u64| v & 0xff_ffff;
+    let index = |v: u64| u128::from((v >> 24) & 0xffff);
+    let length = |v: u64| u128::from((v >> 40) & 0xffff);
+    if number(epoch) != number(required) {
+        return number(epoch) > number(required);
+    }
+    // a zero length is the start of the epoch.
+    let (epoch_index, epoch_length) = match length(epoch) {
+        0 => (0, 1),
+        length => (index(epoch), length),
+    };
+    let (required_index, required_length) = match length(required) {
+        0 => (0, 1),
+        length => (index(required), length),
+    };
+    epoch_index * required_length >= required_index * epoch_length
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Since, SinceMetric};
+
+    fn epoch(number: u64, index: u64, length: u64) -> Since {
+        Since(0x2000_0000_0000_0000 | (length << 40) | (index << 24) | number)
+    }
+
+    #[test]
+    fn test_since_metric() {
+        assert_eq!(Since(100).metric(), Some(SinceMetric::BlockNumber));
+        assert_eq!(
+            epoch(1, 0, 1).metric(),
+            Some(SinceMetric::EpochNumberWithFraction)
+        );
+        assert_eq!(
+            Since(0xc000_0000_0000_0064).metric(),
+            Some(SinceMetric::Timestamp)
+        );
+        assert!(Since(0xc000_0000_0000_0064).is_relative());
+        assert_eq!(Since(0x6000_0000_0000_0000).metric(), None);
+        assert_eq!(Since(0x0100_0000_0000_0064).metric(), None);
+    }
+
+    #[test]
+    fn test_since_is_at_least() {
+        assert!(Since(100).is_at_least(Since(100)));
+        assert!(Since(101).is_at_least(Since(100)));
+        assert!(!Since(99).is_at_least(Since(100)));
+        // relative flag and metric must match.
+        assert!(!Since(0x8000_0000_0000_0064).is_at_least(Since(100)));
+        assert!(!Since(0x4000_0000_0000_0064).is_at_least(Since(100)));
+        assert!(!Since(0x0100_0000_0000_0064).is_at_least(Since(100)));
+    }
+
+    #[test]
+    fn test_epoch_since_is_at_least() {
+        assert!(epoch(2, 0, 1).is_at_least(epoch(1, 999, 1000)));
+        assert!(!epoch(1, 999, 1000).is_at_least(epoch(2, 0, 1)));
+        assert!(epoch(1, 1, 2).is_at_least(epoch(1, 500, 1000)));
+        assert!(!epoch(1, 499, 1000).is_at_least(epoch(1, 1, 2)));
+        assert!(epoch(1, 0, 0).is_at_least(epoch(1, 0, 1)));
+        assert!(!epoch(1, 0, 0).is_at_least(epoch(1, 1, 2)));
+    }
+}
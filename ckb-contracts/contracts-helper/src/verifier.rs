@@ -0,0 +1,178 @@
+//! Dispatches a script group to the rules of what the tx does with its cells, so a contract
+//! doesn't infer it from the data it finds in the group.
+
+use crate::data_loader::{DataLoader, DataLoaderIter};
+use ckb_std::ckb_constants::Source;
+
+/// How many inputs and outputs the current script group has.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptGroup {
+    pub inputs: usize,
+    pub outputs: usize,
+}
+
+impl ScriptGroup {
+    pub fn load<T: DataLoader>(data_loader: &T) -> ScriptGroup {
+        ScriptGroup {
+            inputs: data_loader.iter_cell_capacities(Source::GroupInput).count(),
+            outputs: data_loader
+                .iter_cell_capacities(Source::GroupOutput)
+                .count(),
+        }
+    }
+}
+
+/// The rules of a type script, one per shape of its group. A group always has a cell, so
+/// exactly one of them runs.
+pub trait TypeScriptVerifier {
+    /// The group has outputs only, the tx creates cells of the type.
+    fn on_create(&self, group: ScriptGroup);
+
+    /// The group has both inputs and outputs.
+    fn on_update(&self, group: ScriptGroup);
+
+    /// The group has inputs only, the tx destroys cells of the type.
+    fn on_destroy(&self, group: ScriptGroup);
+}
+
+/// The rules of a lock script, which only runs for the inputs it locks.
+pub trait LockScriptVerifier {
+    fn verify(&self, group: ScriptGroup);
+}
+
+pub fn verify_type_script<V: TypeScriptVerifier>(group: ScriptGroup, verifier: &V) {
+    match (group.inputs, group.outputs) {
+        (0, 0) => panic!("script group has no cells"),
+        (0, _) => verifier.on_create(group),
+        (_, 0) => verifier.on_destroy(group),
+        _ => verifier.on_update(group),
+    }
+}
+
+pub fn verify_lock_script<V: LockScriptVerifier>(group: ScriptGroup, verifier: &V) {
+    if group.inputs == 0 {
+        panic!("script group has no cells");
+    }
+    verifier.verify(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_transaction::{MockCell, MockTransaction, ScriptGroupType};
+    use ckb_std::ckb_types::{packed::Script, prelude::*};
+    use std::cell::RefCell;
+
+    fn script(code_hash: u8) -> Script {
+        Script::new_builder()
+            .code_hash([code_hash; 32].pack())
+            .build()
+    }
+
+    fn typed_cell(type_script: Script) -> MockCell {
+        MockCell::new(script(0), Some(type_script), vec![])
+    }
+
+    #[derive(Default)]
+    struct RecordingVerifier {
+        calls: RefCell<Vec<(&'static str, ScriptGroup)>>,
+    }
+
+    impl TypeScriptVerifier for RecordingVerifier {
+        fn on_create(&self, group: ScriptGroup) {
+            self.calls.borrow_mut().push(("create", group));
+        }
+
+        fn on_update(&self, group: ScriptGroup) {
+            self.calls.borrow_mut().push(("update", group));
+        }
+
+        fn on_destroy(&self, group: ScriptGroup) {
+            self.calls.borrow_mut().push(("destroy", group));
+        }
+    }
+
+    impl LockScriptVerifier for RecordingVerifier {
+        fn verify(&self, group: ScriptGroup) {
+            self.calls.borrow_mut().push(("lock", group));
+        }
+    }
+
+    fn dispatch(tx: &MockTransaction) -> Vec<(&'static str, ScriptGroup)> {
+        let verifier = RecordingVerifier::default();
+        verify_type_script(ScriptGroup::load(tx), &verifier);
+        verifier.calls.into_inner()
+    }
+
+    #[test]
+    fn test_type_script_group_shapes() {
+        let create = MockTransaction::new(script(1), ScriptGroupType::Type)
+            .input(typed_cell(script(2)))
+            .output(typed_cell(script(1)))
+            .output(typed_cell(script(1)));
+        assert_eq!(
+            dispatch(&create),
+            vec![(
+                "create",
+                ScriptGroup {
+                    inputs: 0,
+                    outputs: 2
+                }
+            )]
+        );
+
+        let update = MockTransaction::new(script(1), ScriptGroupType::Type)
+            .input(typed_cell(script(1)))
+            .output(typed_cell(script(1)));
+        assert_eq!(
+            dispatch(&update),
+            vec![(
+                "update",
+                ScriptGroup {
+                    inputs: 1,
+                    outputs: 1
+                }
+            )]
+        );
+
+        let destroy = MockTransaction::new(script(1), ScriptGroupType::Type)
+            .input(typed_cell(script(1)))
+            .input(typed_cell(script(1)))
+            .output(typed_cell(script(2)));
+        assert_eq!(
+            dispatch(&destroy),
+            vec![(
+                "destroy",
+                ScriptGroup {
+                    inputs: 2,
+                    outputs: 0
+                }
+            )]
+        );
+    }
+
+    #[test]
+    fn test_lock_script_group() {
+        let tx = MockTransaction::new(script(0), ScriptGroupType::Lock)
+            .input(typed_cell(script(1)))
+            .output(typed_cell(script(1)));
+        let verifier = RecordingVerifier::default();
+        verify_lock_script(ScriptGroup::load(&tx), &verifier);
+        assert_eq!(
+            verifier.calls.into_inner(),
+            vec![(
+                "lock",
+                ScriptGroup {
+                    inputs: 1,
+                    outputs: 0
+                }
+            )]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "script group has no cells")]
+    fn test_empty_type_script_group() {
+        dispatch(&MockTransaction::new(script(1), ScriptGroupType::Type));
+    }
+}
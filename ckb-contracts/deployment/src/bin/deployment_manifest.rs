@@ -0,0 +1,137 @@
+//! Writes the deployment manifest of a network from its contract binaries and deploy tx.
+//!
+//! cargo run -p force-bridge-deployment --bin deployment_manifest -- --network aggron --tx deploy-tx.json
+//!
+//! options:
+//!   --network <devnet|aggron|lina>     required
+//!   --tx <path>                        the deploy or upgrade tx, as a json `Transaction` or the
+//!                                      result of the `get_transaction` rpc
+//!   --build-dir <path>                 defaults to `build/release-<network>`
+//!   --sudt-out-point <tx_hash>:<index> the sUDT cell dep, when not deployed by the tx on devnet
+//!   --upgrade-of <path>                the manifest of the deployment, when the tx upgrades the
+//!                                      binaries of its type id code cells
+//!   --output <path>                    defaults to `build/manifest-<network>.json`
+
+use ckb_tool::ckb_jsonrpc_types as json;
+use ckb_tool::ckb_types::{core::TransactionView, packed, H256};
+use force_bridge_deployment::{manifest::Manifest, Network};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeployTx {
+    Transaction(json::Transaction),
+    Rpc { transaction: json::TransactionView },
+}
+
+impl DeployTx {
+    fn into_view(self) -> TransactionView {
+        let tx: packed::Transaction = match self {
+            DeployTx::Transaction(tx) => tx.into(),
+            DeployTx::Rpc { transaction } => transaction.inner.into(),
+        };
+        tx.into_view()
+    }
+}
+
+fn parse_out_point(s: &str) -> Result<(H256, u32), String> {
+    let mut parts = s.splitn(2, ':');
+    let tx_hash = parts.next().unwrap_or_default();
+    let index = parts
+        .next()
+        .ok_or_else(|| format!("out point {} is not <tx_hash>:<index>", s))?;
+    let tx_hash = tx_hash
+        .trim_start_matches("0x")
+        .parse()
+        .map_err(|err| format!("out point tx hash {}: {:?}", tx_hash, err))?;
+    let index = match index.strip_prefix("0x") {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => index.parse(),
+    }
+    .map_err(|err| format!("out point index {}: {}", index, err))?;
+    Ok((tx_hash, index))
+}
+
+fn run() -> Result<PathBuf, String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() % 2 != 0 {
+        return Err("options are expected as `--name value` pairs".to_string());
+    }
+    let options: HashMap<&str, &str> = args
+        .chunks(2)
+        .map(|pair| (pair[0].as_str(), pair[1].as_str()))
+        .collect();
+    for name in options.keys() {
+        if ![
+            "--network",
+            "--tx",
+            "--build-dir",
+            "--sudt-out-point",
+            "--upgrade-of",
+            "--output",
+        ]
+        .contains(name)
+        {
+            return Err(format!("unknown option {}", name));
+        }
+    }
+
+    let network = options.get("--network").ok_or("--network is required")?;
+    let network: Network = network
+        .parse()
+        .map_err(|_| format!("unknown network {}", network))?;
+    let tx_path = options.get("--tx").ok_or("--tx is required")?;
+    let build_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../build");
+    let build_dir = match options.get("--build-dir") {
+        Some(dir) => PathBuf::from(dir),
+        None => build_path.join(format!("release-{}", network.name())),
+    };
+    let sudt_out_point = options
+        .get("--sudt-out-point")
+        .map(|s| parse_out_point(s))
+        .transpose()?;
+    let output = match options.get("--output") {
+        Some(path) => PathBuf::from(path),
+        None => build_path.join(format!("manifest-{}.json", network.name())),
+    };
+
+    let tx_json =
+        fs::read_to_string(tx_path).map_err(|err| format!("read {}: {}", tx_path, err))?;
+    let deploy_tx: DeployTx =
+        serde_json::from_str(&tx_json).map_err(|err| format!("parse {}: {}", tx_path, err))?;
+    let manifest = match options.get("--upgrade-of") {
+        Some(path) => {
+            if sudt_out_point.is_some() {
+                return Err(
+                    "--sudt-out-point is kept from the manifest of --upgrade-of".to_string()
+                );
+            }
+            let manifest_json =
+                fs::read_to_string(path).map_err(|err| format!("read {}: {}", path, err))?;
+            let deployed: Manifest = serde_json::from_str(&manifest_json)
+                .map_err(|err| format!("parse {}: {}", path, err))?;
+            if deployed.network != network.name() {
+                return Err(format!("{} is a manifest of {}", path, deployed.network));
+            }
+            deployed.upgrade(&build_dir, &deploy_tx.into_view())?
+        }
+        None => Manifest::generate(network, &build_dir, &deploy_tx.into_view(), sudt_out_point)?,
+    };
+    let manifest_json = serde_json::to_string_pretty(&manifest).expect("serialize manifest");
+    fs::write(&output, manifest_json)
+        .map_err(|err| format!("write {}: {}", output.display(), err))?;
+    Ok(output)
+}
+
+fn main() {
+    match run() {
+        Ok(output) => println!("manifest written to {}", output.display()),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! The deployment manifests of the contracts, and the constants of the networks they are built
+//! for.
+
+pub mod manifest;
+mod network;
+
+pub use network::Network;
@@ -0,0 +1,387 @@
+use crate::Network;
+use ckb_tool::ckb_chain_spec::consensus::TYPE_ID_CODE_HASH;
+use ckb_tool::ckb_hash::{blake2b_256, new_blake2b};
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, ScriptHashType, TransactionBuilder, TransactionView},
+    packed::{CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+    H256,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// The contracts of this workspace, by binary name and the key of their entry in the `deps` of
+/// the offchain `config.json`.
+pub const CONTRACTS: [(&str, &str); 3] = [
+    ("bridge-lockscript", "bridgeLock"),
+    ("recipient-typescript", "recipientType"),
+    ("token-info-typescript", "tokenInfoType"),
+];
+
+/// Key of the sUDT entry in the `deps` of the offchain `config.json`.
+pub const SUDT_DEP_KEY: &str = "sudtType";
+
+// The `ConfigItem` of the offchain `config.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutPointItem {
+    pub tx_hash: H256,
+    pub index: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CellDepItem {
+    pub dep_type: String,
+    pub out_point: OutPointItem,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptItem {
+    pub code_hash: H256,
+    pub hash_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigItem {
+    pub cell_dep: CellDepItem,
+    pub script: ScriptItem,
+}
+
+impl ConfigItem {
+    fn new(tx_hash: H256, index: u32, code_hash: H256, hash_type: ScriptHashType) -> Self {
+        let hash_type = match hash_type {
+            ScriptHashType::Data => "data",
+            ScriptHashType::Type => "type",
+        };
+        ConfigItem {
+            cell_dep: CellDepItem {
+                dep_type: "code".to_string(),
+                out_point: OutPointItem {
+                    tx_hash,
+                    index: format!("{:#x}", index),
+                },
+            },
+            script: ScriptItem {
+                code_hash,
+                hash_type: hash_type.to_string(),
+            },
+        }
+    }
+}
+
+/// Where a contract binary lives in the deploy tx, or in the tx which last upgraded it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractInfo {
+    pub output_index: u32,
+    pub data_hash: H256,
+    /// Args of the type id script of the cell, when it is deployed with one.
+    pub type_id_args: Option<H256>,
+    /// Hash of the type id script, the code hash to reference the contract by across upgrades.
+    pub type_hash: Option<H256>,
+    /// The tx which last replaced the binary, `output_index` is then an output of it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upgrade_tx_hash: Option<H256>,
+}
+
+impl ContractInfo {
+    /// A script running the contract, by the type hash when it is deployed with a type id, so
+    /// the script and its hash outlive upgrades of the binary.
+    pub fn script(&self, args: Bytes) -> Script {
+        let (code_hash, hash_type) = match &self.type_hash {
+            Some(type_hash) => (type_hash, ScriptHashType::Type),
+            None => (&self.data_hash, ScriptHashType::Data),
+        };
+        Script::new_builder()
+            .code_hash(code_hash.pack())
+            .hash_type(hash_type.into())
+            .args(args.pack())
+            .build()
+    }
+}
+
+/// The `force_bridge_types::config` of the network, the contracts are built with the `feature`
+/// of `force-bridge-types` which sets it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypesConfig {
+    pub feature: String,
+    pub sudt_code_hash: H256,
+    pub sudt_hash_type: u8,
+}
+
+impl From<Network> for TypesConfig {
+    fn from(network: Network) -> Self {
+        TypesConfig {
+            feature: network.name().to_string(),
+            sudt_code_hash: network.sudt_code_hash().into(),
+            sudt_hash_type: network.sudt_hash_type(),
+        }
+    }
+}
+
+/// The code hashes and cell deps of a deployment on one network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub network: String,
+    pub deploy_tx_hash: H256,
+    pub types_config: TypesConfig,
+    pub contracts: BTreeMap<String, ContractInfo>,
+    /// Ready to be used as the `ckb.deps` of the offchain `config.json`.
+    pub deps: BTreeMap<String, ConfigItem>,
+}
+
+/// Args of the type id script of the output at `output_index` of a tx spending `first_input`.
+pub fn type_id_args(first_input: &CellInput, output_index: u64) -> [u8; 32] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(first_input.as_slice());
+    blake2b.update(&output_index.to_le_bytes());
+    let mut ret = [0u8; 32];
+    blake2b.finalize(&mut ret);
+    ret
+}
+
+pub fn type_id_script(args: [u8; 32]) -> Script {
+    Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(args.to_vec().pack())
+        .build()
+}
+
+/// A tx spending `first_input` which deploys `binaries`, in order, behind their type ids. The
+/// code cells are locked by `lock`, whoever can unlock them can upgrade the contracts.
+pub fn type_id_deploy_tx(
+    first_input: CellInput,
+    binaries: &[Bytes],
+    lock: &Script,
+) -> TransactionView {
+    let mut builder = TransactionBuilder::default().input(first_input.clone());
+    for (index, binary) in binaries.iter().enumerate() {
+        let type_id = type_id_script(type_id_args(&first_input, index as u64));
+        builder = builder
+            .output(code_cell(binary, lock.clone(), Some(type_id)))
+            .output_data(binary.pack());
+    }
+    builder.build()
+}
+
+/// A tx replacing the binaries of the type id code cells at `upgrades`, given with their outputs.
+/// Each new code cell keeps the lock and the type id of the old one, so every script referencing
+/// the contract by type hash, and every cell of these scripts, runs the new binary.
+pub fn type_id_upgrade_tx(upgrades: &[(OutPoint, CellOutput, Bytes)]) -> TransactionView {
+    let mut builder = TransactionBuilder::default();
+    for (out_point, output, binary) in upgrades {
+        builder = builder
+            .input(CellInput::new(out_point.clone(), 0))
+            .output(code_cell(binary, output.lock(), output.type_().to_opt()))
+            .output_data(binary.pack());
+    }
+    builder.build()
+}
+
+fn code_cell(binary: &Bytes, lock: Script, type_: Option<Script>) -> CellOutput {
+    CellOutput::new_builder()
+        .lock(lock)
+        .type_(type_.pack())
+        .build_exact_capacity(Capacity::bytes(binary.len()).expect("binary capacity"))
+        .expect("code cell capacity")
+}
+
+impl Manifest {
+    /// Locates every contract of `build_dir` in the outputs of `deploy_tx`, a build predating a
+    /// contract leaves it out. A contract deployed with a type script must be deployed with its
+    /// type id, and is referenced by the type hash. Devnet txs may deploy the sUDT too, otherwise
+    /// `sudt_out_point` or the public sUDT of the network is used.
+    pub fn generate(
+        network: Network,
+        build_dir: &Path,
+        deploy_tx: &TransactionView,
+        sudt_out_point: Option<(H256, u32)>,
+    ) -> Result<Self, String> {
+        let tx_hash: H256 = deploy_tx.hash().unpack();
+        let first_input = deploy_tx
+            .inputs()
+            .get(0)
+            .ok_or_else(|| "deploy tx has no inputs".to_string())?;
+        let outputs_data: Vec<[u8; 32]> = deploy_tx
+            .outputs_data()
+            .into_iter()
+            .map(|data| blake2b_256(data.raw_data()))
+            .collect();
+        let find_output = |data_hash: &[u8; 32]| {
+            outputs_data
+                .iter()
+                .position(|hash| hash == data_hash)
+                .map(|index| index as u32)
+        };
+
+        let mut contracts = BTreeMap::new();
+        let mut deps = BTreeMap::new();
+        for (name, dep_key) in CONTRACTS.iter() {
+            let path = build_dir.join(name);
+            let binary = match fs::read(&path) {
+                Ok(binary) => binary,
+                Err(err) if err.kind() == ErrorKind::NotFound => continue,
+                Err(err) => return Err(format!("read {}: {}", path.display(), err)),
+            };
+            let data_hash = blake2b_256(&binary);
+            let output_index = find_output(&data_hash)
+                .ok_or_else(|| format!("{} is not deployed by the tx", path.display()))?;
+            let output = deploy_tx
+                .outputs()
+                .get(output_index as usize)
+                .expect("output of outputs data");
+
+            let (type_id_args, type_hash): (Option<H256>, Option<H256>) =
+                match output.type_().to_opt() {
+                    Some(type_) => {
+                        let args = type_id_args(&first_input, u64::from(output_index));
+                        if type_.as_slice() != type_id_script(args).as_slice() {
+                            return Err(format!(
+                                "type script of {} at output {} is not its type id",
+                                name, output_index
+                            ));
+                        }
+                        (Some(args.into()), Some(type_.calc_script_hash().unpack()))
+                    }
+                    None => (None, None),
+                };
+            let dep = match &type_hash {
+                Some(type_hash) => ConfigItem::new(
+                    tx_hash.clone(),
+                    output_index,
+                    type_hash.clone(),
+                    ScriptHashType::Type,
+                ),
+                None => ConfigItem::new(
+                    tx_hash.clone(),
+                    output_index,
+                    data_hash.into(),
+                    ScriptHashType::Data,
+                ),
+            };
+            deps.insert(dep_key.to_string(), dep);
+            contracts.insert(
+                name.to_string(),
+                ContractInfo {
+                    output_index,
+                    data_hash: data_hash.into(),
+                    type_id_args,
+                    type_hash,
+                    upgrade_tx_hash: None,
+                },
+            );
+        }
+
+        if contracts.is_empty() {
+            return Err(format!("no contract in {}", build_dir.display()));
+        }
+
+        let sudt_hash_type = match network.sudt_hash_type() {
+            0 => ScriptHashType::Data,
+            _ => ScriptHashType::Type,
+        };
+        let sudt_out_point = match sudt_out_point.or_else(|| network.sudt_out_point()) {
+            Some(out_point) => out_point,
+            None if sudt_hash_type == ScriptHashType::Data => {
+                let index = find_output(&network.sudt_code_hash()).ok_or_else(|| {
+                    "the sUDT is not deployed by the tx, pass its out point".to_string()
+                })?;
+                (tx_hash.clone(), index)
+            }
+            None => return Err("the sUDT out point is unknown".to_string()),
+        };
+        deps.insert(
+            SUDT_DEP_KEY.to_string(),
+            ConfigItem::new(
+                sudt_out_point.0,
+                sudt_out_point.1,
+                network.sudt_code_hash().into(),
+                sudt_hash_type,
+            ),
+        );
+
+        Ok(Manifest {
+            network: network.name().to_string(),
+            deploy_tx_hash: tx_hash,
+            types_config: network.into(),
+            contracts,
+            deps,
+        })
+    }
+
+    /// The manifest after `upgrade_tx` replaced the binaries of the contracts it has an output
+    /// of with the ones of `build_dir`. Every upgraded contract must keep its type id, so the
+    /// code hashes of the deps stay the same and only their cell deps move to the upgrade tx.
+    pub fn upgrade(&self, build_dir: &Path, upgrade_tx: &TransactionView) -> Result<Self, String> {
+        let tx_hash: H256 = upgrade_tx.hash().unpack();
+        let outputs_data: Vec<[u8; 32]> = upgrade_tx
+            .outputs_data()
+            .into_iter()
+            .map(|data| blake2b_256(data.raw_data()))
+            .collect();
+
+        let mut manifest = self.clone();
+        let mut upgraded = 0;
+        for (name, dep_key) in CONTRACTS.iter() {
+            let path = build_dir.join(name);
+            let binary =
+                fs::read(&path).map_err(|err| format!("read {}: {}", path.display(), err))?;
+            let data_hash = blake2b_256(&binary);
+            let output_index = match outputs_data.iter().position(|hash| hash == &data_hash) {
+                Some(index) => index as u32,
+                None => continue,
+            };
+            let contract = manifest
+                .contracts
+                .get_mut(*name)
+                .ok_or_else(|| format!("{} is not in the manifest", name))?;
+            let type_hash = contract.type_hash.clone().ok_or_else(|| {
+                format!(
+                    "{} is referenced by data hash, it can not be upgraded",
+                    name
+                )
+            })?;
+            let output = upgrade_tx
+                .outputs()
+                .get(output_index as usize)
+                .expect("output of outputs data");
+            let output_type_hash: Option<H256> = output
+                .type_()
+                .to_opt()
+                .map(|type_| type_.calc_script_hash().unpack());
+            if output_type_hash != Some(type_hash) {
+                return Err(format!(
+                    "{} at output {} does not keep its type id",
+                    name, output_index
+                ));
+            }
+
+            contract.output_index = output_index;
+            contract.data_hash = data_hash.into();
+            contract.upgrade_tx_hash = Some(tx_hash.clone());
+            let dep = manifest
+                .deps
+                .get_mut(*dep_key)
+                .expect("dep of every contract");
+            dep.cell_dep.out_point = OutPointItem {
+                tx_hash: tx_hash.clone(),
+                index: format!("{:#x}", output_index),
+            };
+            upgraded += 1;
+        }
+        if upgraded == 0 {
+            return Err("no contract of the build is upgraded by the tx".to_string());
+        }
+        Ok(manifest)
+    }
+}
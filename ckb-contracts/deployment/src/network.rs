@@ -0,0 +1,68 @@
+use ckb_tool::ckb_types::H256;
+use std::str::FromStr;
+
+/// The CKB network a contract build targets, see the network features of `force-bridge-types`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Network {
+    Devnet,
+    Aggron,
+    Lina,
+}
+
+impl FromStr for Network {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "devnet" => Ok(Network::Devnet),
+            "aggron" => Ok(Network::Aggron),
+            "lina" => Ok(Network::Lina),
+            _ => Err("no match"),
+        }
+    }
+}
+
+impl Network {
+    pub const ALL: [Network; 3] = [Network::Devnet, Network::Aggron, Network::Lina];
+
+    /// Also the `force-bridge-types` feature of the network.
+    pub fn name(self) -> &'static str {
+        match self {
+            Network::Devnet => "devnet",
+            Network::Aggron => "aggron",
+            Network::Lina => "lina",
+        }
+    }
+
+    // ref: https://github.com/nervosnetwork/rfcs/blob/master/rfcs/0024-ckb-system-script-list/0024-ckb-system-script-list.md#simple-udt
+    // kept apart from `force_bridge_types::config` so the binaries are checked against values
+    // which do not depend on the features they were compiled with.
+    pub fn sudt_code_hash(self) -> [u8; 32] {
+        let hex = match self {
+            Network::Devnet => "e1e354d6d643ad42724d40967e334984534e0367405c5ae42a9d7d63d77df419",
+            Network::Aggron => "c5e5dcf215925f7ef4dfaf5f4b4f105bc321c02776d6e7d52a1db3fcd9d011a4",
+            Network::Lina => "5e7a36a77e68eecc013dfa2fe6a23f3b6c344b04005808694ae6dd45eea4cfd5",
+        };
+        let mut hash = [0u8; 32];
+        hex::decode_to_slice(hex, &mut hash).expect("sudt code hash");
+        hash
+    }
+
+    /// 0 for `data`, 1 for `type`.
+    pub fn sudt_hash_type(self) -> u8 {
+        match self {
+            Network::Devnet => 0,
+            Network::Aggron | Network::Lina => 1,
+        }
+    }
+
+    /// The cell dep of the sUDT deployed on public networks, devnets deploy their own.
+    pub fn sudt_out_point(self) -> Option<(H256, u32)> {
+        let tx_hash = match self {
+            Network::Devnet => return None,
+            Network::Aggron => "e12877ebd2c3c364dc46c5c992bcfaf4fee33fa13eebdf82c591fc9825aab769",
+            Network::Lina => "c7813f6a415144643970c2e88e0bb6ca6a8edc5dd7c1022746f628284a9936d5",
+        };
+        Some((tx_hash.parse().expect("sudt tx hash"), 0))
+    }
+}
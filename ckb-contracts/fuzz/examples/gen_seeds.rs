@@ -0,0 +1,16 @@
+//! Writes the seed corpus to `fuzz/seeds/<target>/<name>`.
+//!
+//! cargo run --example gen_seeds
+
+use std::{fs, path::Path};
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("seeds");
+    for (target, seeds) in force_bridge_fuzz::seeds::all() {
+        let dir = root.join(target);
+        fs::create_dir_all(&dir).expect("create seed dir");
+        for (name, seed) in seeds {
+            fs::write(dir.join(name), seed).expect("write seed");
+        }
+    }
+}
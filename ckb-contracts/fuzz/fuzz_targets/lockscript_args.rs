@@ -0,0 +1,15 @@
+#![no_main]
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let args = match BridgeArgsRef::new(data) {
+        Ok(args) => args,
+        Err(_) => return,
+    };
+    if let Some(evm_destination) = args.evm_destination {
+        let _ = evm_destination.is_set();
+    }
+    // only canonical args are accepted, rebuilding them gives back the same bytes.
+    assert_eq!(args.as_bytes().as_ref(), data);
+});
@@ -0,0 +1,13 @@
+#![no_main]
+use force_bridge_types::recipient_cell::RecipientDataView;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(view) = RecipientDataView::new(data) {
+        // only canonical data is accepted, encoding the view gives back the same bytes.
+        let encoded = view
+            .as_molecule_data()
+            .expect("encode accepted recipient data");
+        assert_eq!(encoded.as_ref(), data);
+    }
+});
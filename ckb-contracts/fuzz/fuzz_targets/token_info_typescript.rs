@@ -0,0 +1,11 @@
+#![no_main]
+use force_bridge_fuzz::{expect_rejections, FuzzTx};
+use libfuzzer_sys::fuzz_target;
+use token_info_typescript_lib::{_verify, adapter::ChainAdapter, REJECTIONS};
+
+fuzz_target!(|data: &[u8]| {
+    let chain = FuzzTx::decode(data).to_mock_transaction();
+    expect_rejections(REJECTIONS, || {
+        _verify(ChainAdapter { chain });
+    });
+});
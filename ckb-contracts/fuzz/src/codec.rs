@@ -0,0 +1,80 @@
+use std::cmp::min;
+
+/// Reads fuzzed bytes front to back. Running out of bytes yields zeroes and empty byte strings
+/// rather than an error, so every input decodes to some transaction.
+pub struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        let len = min(buf.len(), self.data.len());
+        buf[..len].copy_from_slice(&self.data[..len]);
+        self.data = &self.data[len..];
+    }
+
+    pub fn u8(&mut self) -> u8 {
+        let mut buf = [0u8; 1];
+        self.fill(&mut buf);
+        buf[0]
+    }
+
+    pub fn u64(&mut self) -> u64 {
+        let mut buf = [0u8; 8];
+        self.fill(&mut buf);
+        u64::from_le_bytes(buf)
+    }
+
+    pub fn byte32(&mut self) -> [u8; 32] {
+        let mut buf = [0u8; 32];
+        self.fill(&mut buf);
+        buf
+    }
+
+    /// A byte string prefixed by its u16 length, cut short at the end of the input.
+    pub fn bytes(&mut self) -> Vec<u8> {
+        let mut len = [0u8; 2];
+        self.fill(&mut len);
+        let len = min(u16::from_le_bytes(len) as usize, self.data.len());
+        let (bytes, rest) = self.data.split_at(len);
+        self.data = rest;
+        bytes.to_vec()
+    }
+}
+
+/// The inverse of `Reader`, used to write the seed corpus.
+#[derive(Default)]
+pub struct Writer {
+    data: Vec<u8>,
+}
+
+impl Writer {
+    pub fn u8(&mut self, v: u8) {
+        self.data.push(v);
+    }
+
+    pub fn u64(&mut self, v: u64) {
+        self.data.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub fn byte32(&mut self, v: &[u8; 32]) {
+        self.data.extend_from_slice(v);
+    }
+
+    pub fn bytes(&mut self, v: &[u8]) {
+        assert!(
+            v.len() <= u16::MAX as usize,
+            "byte string too long to encode"
+        );
+        self.data.extend_from_slice(&(v.len() as u16).to_le_bytes());
+        self.data.extend_from_slice(v);
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+}
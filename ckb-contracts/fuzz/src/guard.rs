@@ -0,0 +1,25 @@
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs `f`, returning quietly when it succeeds or panics with a message starting with one of
+/// `rejections`. Any other panic is raised again, so the fuzzer records the input as a crash.
+pub fn expect_rejections<F: FnOnce()>(rejections: &[&str], f: F) {
+    // silence the hook libfuzzer installs, it aborts before the panic can be inspected.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    if let Err(payload) = result {
+        let message = payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .or_else(|| payload.downcast_ref::<&str>().copied())
+            .unwrap_or_default();
+        if !rejections
+            .iter()
+            .any(|rejection| message.starts_with(rejection))
+        {
+            panic!("undocumented panic: {}", message);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Shared pieces of the fuzz targets: a compact encoding fuzzed bytes decode into a
+//! transaction from, and a guard telling the documented rejections of a contract from bugs.
+
+pub mod codec;
+pub mod guard;
+pub mod seeds;
+pub mod tx;
+
+pub use guard::expect_rejections;
+pub use tx::{FuzzCell, FuzzScript, FuzzTx, RawScript};
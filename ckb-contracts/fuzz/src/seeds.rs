@@ -0,0 +1,604 @@
+//! The seed corpus, built from the txs and data of the contract unit tests.
+
+use crate::tx::{FuzzCell, FuzzScript, FuzzTx, RawScript};
+use ckb_std::ckb_types::packed::{BytesOpt, WitnessArgs};
+use ckb_std::ckb_types::prelude::Pack;
+use contracts_helper::mock_transaction::{calc_script_hash, ScriptGroupType};
+use contracts_helper::owner_cell::{TYPE_ID_CODE_HASH, TYPE_ID_HASH_TYPE};
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::evm::EvmDestination;
+use force_bridge_types::mint_witness::{
+    MintRecordView, MintWitnessVersion, MintWitnessView, RefundRecordView,
+};
+use force_bridge_types::owner_cell::{
+    AssetDecimalsView, BridgedAssetView, LargeMintConfig, MintFeeConfig, MintThresholdView,
+    OwnerCellView, RecipientTypescriptConfig, TwoPhaseMintConfig,
+};
+use force_bridge_types::pending_mint::PendingMintView;
+use force_bridge_types::recipient_cell::{
+    RecipientDataVersion, RecipientDataView, RecipientRefund,
+};
+use force_bridge_types::token_info::TokenInfoView;
+use molecule::prelude::{Builder, Entity};
+
+/// Seeds of a target, keyed by seed name.
+pub type Seeds = Vec<(&'static str, Vec<u8>)>;
+
+/// The seeds of every fuzz target, keyed by target name.
+pub fn all() -> Vec<(&'static str, Seeds)> {
+    vec![
+        ("recipient_data", recipient_data()),
+        ("lockscript_args", lockscript_args()),
+        ("bridge_lockscript", bridge_lockscript()),
+        ("recipient_typescript", recipient_typescript()),
+        ("token_info_typescript", token_info_typescript()),
+    ]
+}
+
+fn script(code_hash: [u8; 32], args: Vec<u8>) -> RawScript {
+    RawScript {
+        code_hash,
+        hash_type: 0,
+        args,
+    }
+}
+
+fn type_id_script(args: [u8; 32]) -> RawScript {
+    RawScript {
+        code_hash: TYPE_ID_CODE_HASH,
+        hash_type: TYPE_ID_HASH_TYPE,
+        args: args.to_vec(),
+    }
+}
+
+// the witness of a bridge cell minting `amount` to `recipient_lock_hash`.
+fn mint_witness(recipient_lock_hash: [u8; 32], amount: u128) -> Vec<u8> {
+    fee_mint_witness(recipient_lock_hash, amount, 0)
+}
+
+// the witness of a bridge cell minting `amount` less `fee` to `recipient_lock_hash`.
+fn fee_mint_witness(recipient_lock_hash: [u8; 32], amount: u128, fee: u128) -> Vec<u8> {
+    witness_of(MintWitnessView {
+        version: MintWitnessVersion::V3,
+        records: vec![MintRecordView {
+            lock_tx_hash: vec![6u8; 32],
+            log_index: 0,
+            amount,
+            fee,
+            recipient_lock_hash,
+        }],
+        refunds: vec![],
+    })
+}
+
+// the witness of a bridge cell refunding the recipient cell of input `recipient_cell_index`.
+fn refund_witness(recipient_cell_index: u32) -> Vec<u8> {
+    witness_of(MintWitnessView {
+        version: MintWitnessVersion::V4,
+        records: vec![],
+        refunds: vec![RefundRecordView {
+            recipient_cell_index,
+        }],
+    })
+}
+
+fn witness_of(mint_witness: MintWitnessView) -> Vec<u8> {
+    let input_type = mint_witness.as_molecule_data().unwrap();
+    WitnessArgs::new_builder()
+        .input_type(BytesOpt::new_builder().set(Some(input_type.pack())).build())
+        .build()
+        .as_bytes()
+        .to_vec()
+}
+
+fn lockscript_args_v1(owner_cell_type_hash: [u8; 32], chain: u8, asset: &str) -> Vec<u8> {
+    BridgeArgsRef {
+        owner_cell_type_hash: &owner_cell_type_hash,
+        chain,
+        asset: asset.as_bytes(),
+        evm_destination: None,
+    }
+    .as_bytes()
+    .to_vec()
+}
+
+// the asset of the seed bridges, in the mint rules of their owner cell.
+fn trx() -> BridgedAssetView {
+    BridgedAssetView {
+        chain: 1,
+        asset: b"trx".to_vec(),
+        evm_destination: None,
+    }
+}
+
+// the owner cell of the bridge the recipient cells burn the sUDT of.
+fn recipient_owner_typescript() -> RawScript {
+    type_id_script([4u8; 32])
+}
+
+fn recipient_owner_cell_type_hash() -> [u8; 32] {
+    calc_script_hash(&recipient_owner_typescript().build())
+}
+
+fn recipient_view(amount: u128) -> RecipientDataView {
+    RecipientDataView {
+        version: RecipientDataVersion::V1,
+        recipient_address: "mock_address".to_string(),
+        chain: 1,
+        asset: "trx".to_string(),
+        bridge_lock_code_hash: [1u8; 32],
+        bridge_lock_hash_type: 0,
+        owner_cell_type_hash: recipient_owner_cell_type_hash(),
+        amount,
+        refund: None,
+        memo: vec![],
+        evm_destination: None,
+    }
+}
+
+fn recipient_data() -> Seeds {
+    let eth = RecipientDataView {
+        recipient_address: "TX3MGfWT5aGv81vTSdZtr6hbHxhMVh1FFM".to_string(),
+        asset: "TRC".to_string(),
+        bridge_lock_code_hash: [1u8; 32],
+        owner_cell_type_hash: [2u8; 32],
+        ..recipient_view(100)
+    };
+    let memo = RecipientDataView {
+        version: RecipientDataVersion::V3,
+        memo: b"deposit id".to_vec(),
+        ..recipient_view(100)
+    };
+    let bsc = RecipientDataView {
+        version: RecipientDataVersion::V4,
+        evm_destination: Some(BSC),
+        ..recipient_view(100)
+    };
+    let xchain_units = RecipientDataView {
+        version: RecipientDataVersion::V5,
+        evm_destination: Some(BSC),
+        ..recipient_view(100 * 10u128.pow(10))
+    };
+    vec![
+        (
+            "burn",
+            recipient_view(10).as_molecule_data().unwrap().to_vec(),
+        ),
+        ("eth", eth.as_molecule_data().unwrap().to_vec()),
+        ("memo", memo.as_molecule_data().unwrap().to_vec()),
+        ("bsc", bsc.as_molecule_data().unwrap().to_vec()),
+        (
+            "xchain_units",
+            xchain_units.as_molecule_data().unwrap().to_vec(),
+        ),
+    ]
+}
+
+fn lockscript_args_v2(
+    owner_cell_type_hash: [u8; 32],
+    chain: u8,
+    asset: &str,
+    evm_destination: EvmDestination,
+) -> Vec<u8> {
+    BridgeArgsRef {
+        owner_cell_type_hash: &owner_cell_type_hash,
+        chain,
+        asset: asset.as_bytes(),
+        evm_destination: Some(evm_destination),
+    }
+    .as_bytes()
+    .to_vec()
+}
+
+const BSC: EvmDestination = EvmDestination {
+    chain_id: 56,
+    bridge_contract_address: [4u8; 20],
+};
+
+fn bsc_owner_cell_data() -> Vec<u8> {
+    OwnerCellView {
+        evm_destinations: vec![BSC],
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+fn lockscript_args() -> Seeds {
+    vec![
+        ("trx", lockscript_args_v1([100u8; 32], 1, "trx")),
+        ("empty_asset", lockscript_args_v1([0u8; 32], 0, "")),
+        ("bsc", lockscript_args_v2([100u8; 32], 1, "0x00", BSC)),
+    ]
+}
+
+// inputs: [bridge cell, owner lock cell], cell deps: [owner cell]
+fn bridge_lockscript() -> Seeds {
+    let owner_lockscript = || FuzzScript::Raw(script([1u8; 32], vec![]));
+    let owner_typescript = type_id_script([2u8; 32]);
+    let owner_cell_type_hash = calc_script_hash(&owner_typescript.build());
+    let bridge_lockscript = script(
+        [3u8; 32],
+        lockscript_args_v1(owner_cell_type_hash, 1, "trx"),
+    );
+    let manage_mode = FuzzTx {
+        inputs: vec![
+            FuzzCell::new(FuzzScript::Current, None, vec![]),
+            FuzzCell::new(owner_lockscript(), None, vec![]),
+        ],
+        cell_deps: vec![FuzzCell::new(
+            owner_lockscript(),
+            Some(FuzzScript::Raw(owner_typescript)),
+            vec![],
+        )],
+        ..FuzzTx::new(bridge_lockscript.clone(), ScriptGroupType::Lock)
+    };
+    let mut owner_not_in_inputs = manage_mode.clone();
+    owner_not_in_inputs.inputs.pop();
+    let mut owner_cell_not_found = manage_mode.clone();
+    owner_cell_not_found.cell_deps.clear();
+    let mut owner_cell_not_unique = manage_mode.clone();
+    owner_cell_not_unique
+        .cell_deps
+        .push(owner_cell_not_unique.cell_deps[0].clone());
+    // the owner cell registers BSC.
+    let mut evm_bridge = FuzzTx {
+        script: script(
+            [3u8; 32],
+            lockscript_args_v2(owner_cell_type_hash, 1, "0x00", BSC),
+        ),
+        ..manage_mode.clone()
+    };
+    evm_bridge.cell_deps[0].data = bsc_owner_cell_data();
+    // outputs: [sudt cell of the owner], recorded in the witness of the bridge cell.
+    let mut mint = manage_mode.clone();
+    mint.outputs = vec![FuzzCell::new(
+        owner_lockscript(),
+        Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+        100u128.to_le_bytes().to_vec(),
+    )];
+    mint.witnesses = vec![mint_witness(
+        calc_script_hash(&script([1u8; 32], vec![]).build()),
+        100,
+    )];
+    // the same mint with the witness of relayers predating the mint records.
+    let mut legacy_mint = mint.clone();
+    legacy_mint.witnesses = vec![witness_of(MintWitnessView {
+        version: MintWitnessVersion::V1,
+        records: vec![MintRecordView {
+            lock_tx_hash: vec![6u8; 32],
+            log_index: 0,
+            amount: 0,
+            fee: 0,
+            recipient_lock_hash: [0u8; 32],
+        }],
+        refunds: vec![],
+    })];
+
+    // the two-phase mint txs, with outputs: [pending mint cell] and inputs: [pending mint cell,
+    // guardian lock cell] to cancel it.
+    let guardian_lockscript = script([4u8; 32], vec![]);
+    let owner_cell_data = OwnerCellView {
+        two_phase_mint: Some(TwoPhaseMintConfig {
+            guardian_lock_hash: calc_script_hash(&guardian_lockscript.build()),
+            challenge_period: 6,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let pending_mint_cell = FuzzCell::new(
+        FuzzScript::Current,
+        Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+        PendingMintView {
+            amount: 100,
+            recipient_lock_hash: [5u8; 32],
+            lock_tx_hash: vec![6u8; 32],
+        }
+        .as_sudt_data(),
+    );
+    let mut pending_mint = manage_mode.clone();
+    pending_mint.cell_deps[0].data = owner_cell_data;
+    pending_mint.outputs = vec![pending_mint_cell.clone()];
+    pending_mint.witnesses = vec![mint_witness([5u8; 32], 100)];
+    let mut cancel = pending_mint.clone();
+    cancel.inputs = vec![
+        pending_mint_cell,
+        FuzzCell::new(FuzzScript::Raw(guardian_lockscript), None, vec![]),
+    ];
+    cancel.outputs.clear();
+
+    // outputs: [sudt cell] above the threshold of the asset, without the high value signer.
+    let large_mint_owner_cell_data = OwnerCellView {
+        large_mint: Some(LargeMintConfig {
+            signer_lock_hash: [7u8; 32],
+            thresholds: vec![MintThresholdView {
+                bridged_asset: trx(),
+                threshold: 1000,
+            }],
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let mut large_mint = manage_mode.clone();
+    large_mint.cell_deps[0].data = large_mint_owner_cell_data;
+    large_mint.outputs = vec![FuzzCell::new(
+        owner_lockscript(),
+        Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+        1001u128.to_le_bytes().to_vec(),
+    )];
+
+    // inputs: [bridge cell, owner lock cell, recipient cell of 100], outputs: [sudt cell of the
+    // refund lock], the owner refunding the recipient cell.
+    let recipient_typescript = script([10u8; 32], vec![]);
+    let refund_lockscript = script([11u8; 32], vec![]);
+    let refund_owner_cell_data = OwnerCellView {
+        recipient_typescript: Some(RecipientTypescriptConfig {
+            code_hash: [10u8; 32],
+            hash_type: 0,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let refunded_recipient_data = RecipientDataView {
+        version: RecipientDataVersion::V2,
+        bridge_lock_code_hash: [3u8; 32],
+        owner_cell_type_hash,
+        refund: Some(RecipientRefund {
+            lock_hash: calc_script_hash(&refund_lockscript.build()),
+            since: 0,
+        }),
+        ..recipient_view(100)
+    }
+    .as_molecule_data()
+    .unwrap()
+    .to_vec();
+    let mut refund = manage_mode.clone();
+    refund.cell_deps[0].data = refund_owner_cell_data;
+    refund.inputs.push(FuzzCell::new(
+        FuzzScript::Raw(script([0u8; 32], vec![])),
+        Some(FuzzScript::Raw(recipient_typescript)),
+        refunded_recipient_data,
+    ));
+    refund.outputs = vec![FuzzCell::new(
+        FuzzScript::Raw(refund_lockscript),
+        Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+        100u128.to_le_bytes().to_vec(),
+    )];
+    refund.witnesses = vec![refund_witness(2)];
+
+    // outputs: [sudt cell of the owner, sudt cell of the fee collector], the owner charged a
+    // fee of 3.
+    let collector_lockscript = script([9u8; 32], vec![]);
+    let mint_fee_owner_cell_data = OwnerCellView {
+        mint_fee: Some(MintFeeConfig {
+            collector_lock_hash: calc_script_hash(&collector_lockscript.build()),
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let mut mint_fee = manage_mode.clone();
+    mint_fee.cell_deps[0].data = mint_fee_owner_cell_data;
+    mint_fee.outputs = vec![
+        FuzzCell::new(
+            owner_lockscript(),
+            Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+            97u128.to_le_bytes().to_vec(),
+        ),
+        FuzzCell::new(
+            FuzzScript::Raw(collector_lockscript),
+            Some(FuzzScript::Sudt(bridge_lockscript)),
+            3u128.to_le_bytes().to_vec(),
+        ),
+    ];
+    mint_fee.witnesses = vec![fee_mint_witness(
+        calc_script_hash(&script([1u8; 32], vec![]).build()),
+        100,
+        3,
+    )];
+
+    vec![
+        ("manage_mode", manage_mode.encode()),
+        ("owner_not_in_inputs", owner_not_in_inputs.encode()),
+        ("owner_cell_not_found", owner_cell_not_found.encode()),
+        ("owner_cell_not_unique", owner_cell_not_unique.encode()),
+        ("mint", mint.encode()),
+        ("legacy_mint", legacy_mint.encode()),
+        ("evm_bridge", evm_bridge.encode()),
+        ("pending_mint", pending_mint.encode()),
+        ("cancel_pending_mint", cancel.encode()),
+        ("large_mint", large_mint.encode()),
+        ("mint_fee", mint_fee.encode()),
+        ("refund", refund.encode()),
+    ]
+}
+
+// inputs: [sudt cell], outputs: [recipient cell, sudt cell]
+fn burn_tx(input_amount: u128, output_amount: u128, amount: u128) -> FuzzTx {
+    let always_success = || FuzzScript::Raw(script([0u8; 32], vec![]));
+    let bridge_lockscript = script(
+        [1u8; 32],
+        lockscript_args_v1(recipient_owner_cell_type_hash(), 1, "trx"),
+    );
+    let sudt_cell = |amount: u128| {
+        FuzzCell::new(
+            always_success(),
+            Some(FuzzScript::Sudt(bridge_lockscript.clone())),
+            amount.to_le_bytes().to_vec(),
+        )
+    };
+    FuzzTx {
+        inputs: vec![sudt_cell(input_amount)],
+        outputs: vec![
+            FuzzCell::new(
+                always_success(),
+                Some(FuzzScript::Current),
+                recipient_view(amount).as_molecule_data().unwrap().to_vec(),
+            ),
+            sudt_cell(output_amount),
+        ],
+        cell_deps: vec![FuzzCell::new(
+            always_success(),
+            Some(FuzzScript::Raw(recipient_owner_typescript())),
+            vec![],
+        )],
+        ..FuzzTx::new(script([2u8; 32], vec![]), ScriptGroupType::Type)
+    }
+}
+
+fn recipient_typescript() -> Seeds {
+    let mut other_owners = burn_tx(100, 90, 10);
+    let other_bridge_lockscript = script(
+        [1u8; 32],
+        lockscript_args_v1(recipient_owner_cell_type_hash(), 1, "other"),
+    );
+    let other_sudt_cell = |amount: u128| {
+        FuzzCell::new(
+            FuzzScript::Raw(script([0u8; 32], vec![])),
+            Some(FuzzScript::Sudt(other_bridge_lockscript.clone())),
+            amount.to_le_bytes().to_vec(),
+        )
+    };
+    other_owners.inputs.push(other_sudt_cell(1000));
+    other_owners.outputs.push(other_sudt_cell(1));
+
+    // burns the sUDT of the asset bridged to BSC.
+    let mut evm_burn = burn_tx(100, 90, 10);
+    let evm_sudt = FuzzScript::Sudt(script(
+        [1u8; 32],
+        lockscript_args_v2(recipient_owner_cell_type_hash(), 1, "trx", BSC),
+    ));
+    evm_burn.inputs[0].type_ = Some(evm_sudt.clone());
+    evm_burn.outputs[1].type_ = Some(evm_sudt);
+    evm_burn.outputs[0].data = RecipientDataView {
+        version: RecipientDataVersion::V4,
+        evm_destination: Some(BSC),
+        ..recipient_view(10)
+    }
+    .as_molecule_data()
+    .unwrap()
+    .to_vec();
+    evm_burn.cell_deps[0].data = bsc_owner_cell_data();
+
+    // trx has 18 decimals and its sUDT 8, the 10 sUDT burned are 10 * 10^10 trx.
+    let decimals_owner_cell_data = OwnerCellView {
+        asset_decimals: vec![AssetDecimalsView {
+            bridged_asset: trx(),
+            decimals: AssetDecimals {
+                xchain_decimals: 18,
+                sudt_decimals: 8,
+            },
+        }],
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let in_xchain_units = |amount: u128| {
+        let mut burn = burn_tx(100, 90, amount);
+        burn.outputs[0].data = RecipientDataView {
+            version: RecipientDataVersion::V5,
+            ..recipient_view(amount)
+        }
+        .as_molecule_data()
+        .unwrap()
+        .to_vec();
+        burn.cell_deps[0].data = decimals_owner_cell_data.clone();
+        burn
+    };
+    let decimals_burn = in_xchain_units(10 * 10u128.pow(10));
+    let dust_burn = in_xchain_units(10 * 10u128.pow(10) + 1);
+    let mut xchain_units_without_owner_cell = in_xchain_units(10 * 10u128.pow(10));
+    xchain_units_without_owner_cell.cell_deps.clear();
+    let mut without_owner_cell = burn_tx(100, 90, 10);
+    without_owner_cell.cell_deps.clear();
+    let mut owner_cell_not_unique = burn_tx(100, 90, 10);
+    owner_cell_not_unique
+        .cell_deps
+        .push(owner_cell_not_unique.cell_deps[0].clone());
+
+    vec![
+        ("burn", burn_tx(100, 90, 10).encode()),
+        ("input_less_than_output", burn_tx(90, 100, 10).encode()),
+        ("burned_amount_not_match", burn_tx(100, 80, 10).encode()),
+        ("other_owners", other_owners.encode()),
+        ("evm_burn", evm_burn.encode()),
+        ("decimals_burn", decimals_burn.encode()),
+        ("dust_burn", dust_burn.encode()),
+        ("without_owner_cell", without_owner_cell.encode()),
+        (
+            "xchain_units_without_owner_cell",
+            xchain_units_without_owner_cell.encode(),
+        ),
+        ("owner_cell_not_unique", owner_cell_not_unique.encode()),
+    ]
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: [token info cell], cell deps: [owner cell]
+fn token_info_typescript() -> Seeds {
+    let owner_lockscript = || FuzzScript::Raw(script([1u8; 32], vec![]));
+    let owner_typescript = type_id_script([2u8; 32]);
+    let bridge_lockscript = script(
+        [3u8; 32],
+        lockscript_args_v1(calc_script_hash(&owner_typescript.build()), 1, "trx"),
+    );
+    let sudt_typescript = FuzzScript::Sudt(bridge_lockscript.clone()).build(&Default::default());
+    let token_info = |symbol: &str| {
+        TokenInfoView {
+            decimals: 8,
+            name: "TRON".to_string(),
+            symbol: symbol.to_string(),
+        }
+        .as_bytes()
+    };
+    let token_info_cell = |data: Vec<u8>| {
+        FuzzCell::new(
+            FuzzScript::Raw(script([5u8; 32], vec![])),
+            Some(FuzzScript::Current),
+            data,
+        )
+    };
+    let create = FuzzTx {
+        inputs: vec![
+            FuzzCell::new(FuzzScript::Raw(bridge_lockscript), None, vec![]),
+            FuzzCell::new(owner_lockscript(), None, vec![]),
+        ],
+        outputs: vec![token_info_cell(token_info("TRX"))],
+        cell_deps: vec![FuzzCell::new(
+            owner_lockscript(),
+            Some(FuzzScript::Raw(owner_typescript)),
+            vec![],
+        )],
+        ..FuzzTx::new(
+            script([4u8; 32], calc_script_hash(&sudt_typescript).to_vec()),
+            ScriptGroupType::Type,
+        )
+    };
+    let mut update = create.clone();
+    update.inputs.push(token_info_cell(token_info("TRC")));
+    let mut destroy = update.clone();
+    destroy.outputs.clear();
+    let mut owner_not_in_inputs = create.clone();
+    owner_not_in_inputs.inputs.pop();
+    let mut owner_cell_not_found = create.clone();
+    owner_cell_not_found.cell_deps.clear();
+    let mut token_info_not_unique = create.clone();
+    token_info_not_unique
+        .outputs
+        .push(token_info_cell(token_info("TRX")));
+    let mut token_info_invalid = create.clone();
+    token_info_invalid.outputs[0].data = vec![8];
+
+    vec![
+        ("create", create.encode()),
+        ("update", update.encode()),
+        ("destroy", destroy.encode()),
+        ("owner_not_in_inputs", owner_not_in_inputs.encode()),
+        ("owner_cell_not_found", owner_cell_not_found.encode()),
+        ("token_info_not_unique", token_info_not_unique.encode()),
+        ("token_info_invalid", token_info_invalid.encode()),
+    ]
+}
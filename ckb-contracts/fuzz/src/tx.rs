@@ -0,0 +1,237 @@
+use crate::codec::{Reader, Writer};
+use ckb_std::ckb_types::{bytes::Bytes, packed::Script, prelude::*};
+use contracts_helper::mock_transaction::{
+    calc_script_hash, MockCell, MockTransaction, ScriptGroupType,
+};
+use force_bridge_types::config::{SUDT_CODE_HASH, SUDT_HASH_TYPE};
+
+/// Upper bound of the inputs, outputs, cell deps and witnesses of a decoded tx.
+pub const MAX_ITEMS: u8 = 8;
+
+#[derive(Debug, Clone, Default)]
+pub struct RawScript {
+    pub code_hash: [u8; 32],
+    pub hash_type: u8,
+    pub args: Vec<u8>,
+}
+
+impl RawScript {
+    pub fn build(&self) -> Script {
+        Script::new_builder()
+            .code_hash(self.code_hash.pack())
+            .hash_type(self.hash_type.into())
+            .args(Bytes::from(self.args.clone()).pack())
+            .build()
+    }
+
+    fn decode(reader: &mut Reader) -> Self {
+        Self {
+            code_hash: reader.byte32(),
+            hash_type: reader.u8(),
+            args: reader.bytes(),
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) {
+        writer.byte32(&self.code_hash);
+        writer.u8(self.hash_type);
+        writer.bytes(&self.args);
+    }
+}
+
+/// A script of a fuzzed cell. The shortcuts let the fuzzer reach the scripts the contracts look
+/// for without guessing hashes.
+#[derive(Debug, Clone)]
+pub enum FuzzScript {
+    /// The script under verification.
+    Current,
+    /// The sUDT owned by the given bridge lockscript.
+    Sudt(RawScript),
+    Raw(RawScript),
+}
+
+impl FuzzScript {
+    pub fn build(&self, current: &Script) -> Script {
+        match self {
+            FuzzScript::Current => current.clone(),
+            FuzzScript::Sudt(bridge_lockscript) => Script::new_builder()
+                .code_hash(SUDT_CODE_HASH.pack())
+                .hash_type(SUDT_HASH_TYPE.into())
+                .args(Bytes::from(calc_script_hash(&bridge_lockscript.build()).to_vec()).pack())
+                .build(),
+            FuzzScript::Raw(script) => script.build(),
+        }
+    }
+
+    fn decode(reader: &mut Reader) -> Self {
+        match reader.u8() % 3 {
+            0 => FuzzScript::Current,
+            1 => FuzzScript::Sudt(RawScript::decode(reader)),
+            _ => FuzzScript::Raw(RawScript::decode(reader)),
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) {
+        match self {
+            FuzzScript::Current => writer.u8(0),
+            FuzzScript::Sudt(bridge_lockscript) => {
+                writer.u8(1);
+                bridge_lockscript.encode(writer);
+            }
+            FuzzScript::Raw(script) => {
+                writer.u8(2);
+                script.encode(writer);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FuzzCell {
+    pub capacity: u64,
+    pub lock: FuzzScript,
+    pub type_: Option<FuzzScript>,
+    pub data: Vec<u8>,
+}
+
+impl FuzzCell {
+    pub fn new(lock: FuzzScript, type_: Option<FuzzScript>, data: Vec<u8>) -> Self {
+        Self {
+            capacity: 0,
+            lock,
+            type_,
+            data,
+        }
+    }
+
+    fn build(&self, current: &Script) -> MockCell {
+        let type_ = self.type_.as_ref().map(|type_| type_.build(current));
+        MockCell::new(self.lock.build(current), type_, self.data.clone()).capacity(self.capacity)
+    }
+
+    fn decode(reader: &mut Reader) -> Self {
+        let capacity = reader.u64();
+        let lock = FuzzScript::decode(reader);
+        let type_ = match reader.u8() % 2 {
+            0 => None,
+            _ => Some(FuzzScript::decode(reader)),
+        };
+        Self {
+            capacity,
+            lock,
+            type_,
+            data: reader.bytes(),
+        }
+    }
+
+    fn encode(&self, writer: &mut Writer) {
+        writer.u64(self.capacity);
+        self.lock.encode(writer);
+        match &self.type_ {
+            None => writer.u8(0),
+            Some(type_) => {
+                writer.u8(1);
+                type_.encode(writer);
+            }
+        }
+        writer.bytes(&self.data);
+    }
+}
+
+/// A tx running `script` as a lock or type script, decoded from fuzzed bytes.
+#[derive(Debug, Clone)]
+pub struct FuzzTx {
+    pub script: RawScript,
+    pub script_group_type: ScriptGroupType,
+    pub inputs: Vec<FuzzCell>,
+    pub outputs: Vec<FuzzCell>,
+    pub cell_deps: Vec<FuzzCell>,
+    pub witnesses: Vec<Vec<u8>>,
+}
+
+impl FuzzTx {
+    pub fn new(script: RawScript, script_group_type: ScriptGroupType) -> Self {
+        Self {
+            script,
+            script_group_type,
+            inputs: vec![],
+            outputs: vec![],
+            cell_deps: vec![],
+            witnesses: vec![],
+        }
+    }
+
+    pub fn decode(data: &[u8]) -> Self {
+        let mut reader = Reader::new(data);
+        let script = RawScript::decode(&mut reader);
+        let script_group_type = match reader.u8() % 2 {
+            0 => ScriptGroupType::Lock,
+            _ => ScriptGroupType::Type,
+        };
+        let mut cells = || {
+            let len = reader.u8() % (MAX_ITEMS + 1);
+            (0..len)
+                .map(|_| FuzzCell::decode(&mut reader))
+                .collect::<Vec<_>>()
+        };
+        let inputs = cells();
+        let outputs = cells();
+        let cell_deps = cells();
+        let len = reader.u8() % (MAX_ITEMS + 1);
+        let witnesses = (0..len).map(|_| reader.bytes()).collect();
+        Self {
+            script,
+            script_group_type,
+            inputs,
+            outputs,
+            cell_deps,
+            witnesses,
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+        self.script.encode(&mut writer);
+        writer.u8(match self.script_group_type {
+            ScriptGroupType::Lock => 0,
+            ScriptGroupType::Type => 1,
+        });
+        for cells in [&self.inputs, &self.outputs, &self.cell_deps] {
+            assert!(
+                cells.len() <= MAX_ITEMS as usize,
+                "too many cells to encode"
+            );
+            writer.u8(cells.len() as u8);
+            for cell in cells.iter() {
+                cell.encode(&mut writer);
+            }
+        }
+        assert!(
+            self.witnesses.len() <= MAX_ITEMS as usize,
+            "too many witnesses to encode"
+        );
+        writer.u8(self.witnesses.len() as u8);
+        for witness in self.witnesses.iter() {
+            writer.bytes(witness);
+        }
+        writer.into_inner()
+    }
+
+    pub fn to_mock_transaction(&self) -> MockTransaction {
+        let current = self.script.build();
+        let mut tx = MockTransaction::new(current.clone(), self.script_group_type);
+        for cell in self.inputs.iter() {
+            tx = tx.input(cell.build(&current));
+        }
+        for cell in self.outputs.iter() {
+            tx = tx.output(cell.build(&current));
+        }
+        for cell in self.cell_deps.iter() {
+            tx = tx.cell_dep(cell.build(&current));
+        }
+        for witness in self.witnesses.iter() {
+            tx = tx.witness(witness.clone());
+        }
+        tx
+    }
+}
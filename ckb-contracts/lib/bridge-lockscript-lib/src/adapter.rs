@@ -4,9 +4,16 @@ use mockall::predicate::*;
 use mockall::*;
 
 use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::packed::Script;
 use ckb_std::error::SysError;
-use ckb_std::high_level::QueryIter;
-use contracts_helper::data_loader::DataLoader;
+use contracts_helper::data_loader::{DataLoader, DataLoaderIter};
+use contracts_helper::owner_cell::find_owner_cell;
+use contracts_helper::verifier::ScriptGroup;
+use force_bridge_types::{
+    config::{SUDT_CODE_HASH, SUDT_HASH_TYPE, UDT_LEN},
+    mint_witness::MintWitnessView,
+    pending_mint::PendingMintView,
+};
 use molecule::bytes::Bytes;
 use std::prelude::v1::*;
 
@@ -14,10 +21,49 @@ use std::prelude::v1::*;
 pub trait Adapter {
     fn load_script_args(&self) -> Bytes;
 
+    fn load_script_group(&self) -> ScriptGroup;
+
+    /// Code hash and hash type of the script.
+    fn load_script_code(&self) -> ([u8; 32], u8);
+
     /// check whether there is any input lock script matches the given one
     fn lock_script_exists_in_inputs(&self, hash: &[u8]) -> bool;
 
-    fn get_owner_lock_hash(&self, owner_cell_type_hash: &[u8]) -> [u8; 32];
+    /// Lock hash and data of the owner cell of `owner_cell_type_hash`.
+    fn get_owner_cell(&self, owner_cell_type_hash: &[u8]) -> ([u8; 32], Vec<u8>);
+
+    /// The pending mint cells among the inputs of the script group, `None` for other cells.
+    fn load_input_pending_mints(&self) -> Vec<Option<PendingMintView>>;
+
+    /// The sUDT cells of the bridge locked by the bridge lockscript in the outputs.
+    fn load_output_pending_mints(&self) -> Vec<PendingMintView>;
+
+    fn load_input_since(&self, index: usize) -> u64;
+
+    /// The sUDT amount of the bridge in `source`, of the cells locked by `lock_hash` if given.
+    fn get_sudt_amount(&self, source: Source, lock_hash: Option<[u8; 32]>) -> u128;
+
+    /// The `VersionedMintWitness` in the `input_type` of the witness of the script group, or the
+    /// legacy `MintWitness` as V1, `None` without such a witness.
+    fn load_mint_witness(&self) -> Option<MintWitnessView>;
+
+    /// Data of the input at `index` if it is typed by the given code hash and hash type, `None`
+    /// for other cells or without such an input.
+    fn load_input_data_of_type(
+        &self,
+        index: usize,
+        code_hash: [u8; 32],
+        hash_type: u8,
+    ) -> Option<Vec<u8>>;
+
+    /// The recipient lock hash and amount of every sUDT cell of the bridge in `source`, the
+    /// recipient of a pending mint cell being the one in its data.
+    fn load_mints(&self, source: Source) -> Vec<([u8; 32], u128)>;
+
+    /// Data of the token info cell of the bridge sUDT, typed by the given code hash and hash
+    /// type, in the outputs or else the cell deps. The sUDT is known by its cells in the outputs,
+    /// none without them.
+    fn load_token_info(&self, code_hash: [u8; 32], hash_type: u8) -> Option<Vec<u8>>;
 }
 
 pub struct ChainAdapter<T: DataLoader> {
@@ -32,35 +78,230 @@ where
         self.chain.load_script().unwrap().args().raw_data()
     }
 
+    fn load_script_group(&self) -> ScriptGroup {
+        ScriptGroup::load(&self.chain)
+    }
+
+    fn load_script_code(&self) -> ([u8; 32], u8) {
+        let script = self.chain.load_script().expect("load script fail");
+        let mut code_hash = [0u8; 32];
+        code_hash.copy_from_slice(script.code_hash().raw_data().as_ref());
+        (code_hash, script.hash_type().into())
+    }
+
     fn lock_script_exists_in_inputs(&self, data: &[u8]) -> bool {
-        QueryIter::new(
-            |index, source| self.chain.load_cell_lock_hash(index, source),
-            Source::Input,
-        )
-        .any(|script| script.as_ref() == data)
+        self.chain
+            .iter_cell_lock_hashes(Source::Input)
+            .any(|script| script.as_ref() == data)
     }
 
-    fn get_owner_lock_hash(&self, owner_cell_type_hash: &[u8]) -> [u8; 32] {
-        let mut index = 0;
-        let source = Source::CellDep;
-        loop {
-            let cell_type = self.chain.load_cell_type_hash(index, source);
-            match cell_type {
-                Err(SysError::IndexOutOfBound) => panic!("owner cell not found"),
-                Err(err) => panic!("iter input return an error: {:?}, index: {:?}", err, index),
-                Ok(cell_type_hash_opt) => {
-                    if let Some(cell_type_hash) = cell_type_hash_opt {
-                        if cell_type_hash == owner_cell_type_hash {
-                            let data = self
-                                .chain
-                                .load_cell_lock_hash(index, source)
-                                .expect("load cell data fail");
-                            return data;
-                        }
-                    }
+    fn get_owner_cell(&self, owner_cell_type_hash: &[u8]) -> ([u8; 32], Vec<u8>) {
+        let (index, source) = find_owner_cell(&self.chain, owner_cell_type_hash);
+        let lock_hash = self
+            .chain
+            .load_cell_lock_hash(index, source)
+            .expect("load cell lock hash fail");
+        let data = self
+            .chain
+            .load_cell_data(index, source)
+            .expect("load cell data fail");
+        (lock_hash, data)
+    }
+
+    fn load_input_pending_mints(&self) -> Vec<Option<PendingMintView>> {
+        let script_hash = self.load_bridge_lock_hash();
+        self.chain
+            .iter_cell_types(Source::GroupInput)
+            .enumerate()
+            .map(|(index, cell_type)| {
+                if !is_sudt_typescript(&cell_type, &script_hash) {
+                    return None;
                 }
-            }
-            index += 1;
+                let data = self
+                    .chain
+                    .load_cell_data(index, Source::GroupInput)
+                    .expect("load cell data fail");
+                Some(PendingMintView::from_sudt_data(&data).expect("invalid pending mint cell"))
+            })
+            .collect()
+    }
+
+    fn load_output_pending_mints(&self) -> Vec<PendingMintView> {
+        let script_hash = self.load_bridge_lock_hash();
+        self.sudt_indices(Source::Output, &script_hash, Some(&script_hash))
+            .into_iter()
+            .map(|index| {
+                let data = self
+                    .chain
+                    .load_cell_data(index, Source::Output)
+                    .expect("load cell data fail");
+                PendingMintView::from_sudt_data(&data).expect("invalid pending mint cell")
+            })
+            .collect()
+    }
+
+    fn load_input_since(&self, index: usize) -> u64 {
+        self.chain
+            .load_input_since(index, Source::GroupInput)
+            .expect("load input since fail")
+    }
+
+    fn get_sudt_amount(&self, source: Source, lock_hash: Option<[u8; 32]>) -> u128 {
+        let script_hash = self.load_bridge_lock_hash();
+        self.sudt_indices(source, &script_hash, lock_hash.as_ref())
+            .into_iter()
+            .map(|index| {
+                let data = self
+                    .chain
+                    .load_cell_data(index, source)
+                    .expect("load cell data fail");
+                if data.len() < UDT_LEN {
+                    panic!("invalid sudt cell. index: {}, source: {:?}", index, source);
+                }
+                let mut buf = [0u8; UDT_LEN];
+                buf.copy_from_slice(&data[0..UDT_LEN]);
+                u128::from_le_bytes(buf)
+            })
+            .fold(0u128, |sum, amount| {
+                sum.checked_add(amount).expect("sudt amount overflow")
+            })
+    }
+
+    fn load_mint_witness(&self) -> Option<MintWitnessView> {
+        let witness_args = match self.chain.load_witness_args(0, Source::GroupInput) {
+            Ok(witness_args) => witness_args,
+            Err(SysError::IndexOutOfBound) => return None,
+            Err(_) => panic!("mint witness is invalid"),
+        };
+        witness_args.input_type().to_opt().map(|input_type| {
+            MintWitnessView::new(&input_type.raw_data()).expect("mint witness is invalid")
+        })
+    }
+
+    fn load_input_data_of_type(
+        &self,
+        index: usize,
+        code_hash: [u8; 32],
+        hash_type: u8,
+    ) -> Option<Vec<u8>> {
+        let script = match self.chain.load_cell_type(index, Source::Input) {
+            Ok(script) => script?,
+            Err(SysError::IndexOutOfBound) => return None,
+            Err(_) => panic!("load cell type fail"),
+        };
+        if script.code_hash().raw_data().as_ref() != code_hash
+            || script.hash_type() != hash_type.into()
+        {
+            return None;
         }
+        Some(
+            self.chain
+                .load_cell_data(index, Source::Input)
+                .expect("load cell data fail"),
+        )
+    }
+
+    fn load_mints(&self, source: Source) -> Vec<([u8; 32], u128)> {
+        let script_hash = self.load_bridge_lock_hash();
+        self.sudt_indices(source, &script_hash, None)
+            .into_iter()
+            .map(|index| {
+                let data = self
+                    .chain
+                    .load_cell_data(index, source)
+                    .expect("load cell data fail");
+                let lock_hash = self
+                    .chain
+                    .load_cell_lock_hash(index, source)
+                    .expect("load cell lock hash fail");
+                if lock_hash == script_hash {
+                    let pending_mint =
+                        PendingMintView::from_sudt_data(&data).expect("invalid pending mint cell");
+                    return (pending_mint.recipient_lock_hash, pending_mint.amount);
+                }
+                if data.len() < UDT_LEN {
+                    panic!("invalid sudt cell. index: {}, source: {:?}", index, source);
+                }
+                let mut buf = [0u8; UDT_LEN];
+                buf.copy_from_slice(&data[0..UDT_LEN]);
+                (lock_hash, u128::from_le_bytes(buf))
+            })
+            .collect()
+    }
+
+    fn load_token_info(&self, code_hash: [u8; 32], hash_type: u8) -> Option<Vec<u8>> {
+        let script_hash = self.load_bridge_lock_hash();
+        let sudt_index = *self
+            .sudt_indices(Source::Output, &script_hash, None)
+            .first()?;
+        let sudt_type_hash = self
+            .chain
+            .load_cell_type_hash(sudt_index, Source::Output)
+            .expect("load cell type hash fail")
+            .expect("sudt cell has a type");
+        [Source::Output, Source::CellDep]
+            .iter()
+            .find_map(|source| {
+                self.chain
+                    .iter_cell_types(*source)
+                    .position(|cell_type| {
+                        cell_type.map_or(false, |script| {
+                            script.code_hash().raw_data().as_ref() == code_hash
+                                && script.hash_type() == hash_type.into()
+                                && script.args().raw_data().as_ref() == sudt_type_hash
+                        })
+                    })
+                    .map(|index| (index, *source))
+            })
+            .map(|(index, source)| {
+                self.chain
+                    .load_cell_data(index, source)
+                    .expect("load cell data fail")
+            })
     }
 }
+
+impl<T> ChainAdapter<T>
+where
+    T: DataLoader,
+{
+    fn load_bridge_lock_hash(&self) -> [u8; 32] {
+        self.chain
+            .load_script_hash()
+            .expect("load script hash fail")
+    }
+
+    // indices of the sUDT cells of the bridge in `source`, locked by `lock_hash` if given.
+    fn sudt_indices(
+        &self,
+        source: Source,
+        bridge_lock_hash: &[u8; 32],
+        lock_hash: Option<&[u8; 32]>,
+    ) -> Vec<usize> {
+        self.chain
+            .iter_cell_types(source)
+            .enumerate()
+            .filter(|(index, cell_type)| {
+                is_sudt_typescript(cell_type, bridge_lock_hash)
+                    && lock_hash.map_or(true, |lock_hash| {
+                        &self
+                            .chain
+                            .load_cell_lock_hash(*index, source)
+                            .expect("load cell lock hash fail")
+                            == lock_hash
+                    })
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+fn is_sudt_typescript(script: &Option<Script>, bridge_lock_hash: &[u8]) -> bool {
+    let script = match script {
+        Some(script) => script,
+        None => return false,
+    };
+    script.code_hash().raw_data().as_ref() == SUDT_CODE_HASH.as_ref()
+        && script.args().raw_data().as_ref() == bridge_lock_hash
+        && script.hash_type() == SUDT_HASH_TYPE.into()
+}
@@ -9,8 +9,20 @@ pub mod adapter;
 mod test;
 
 use adapter::Adapter;
-use force_bridge_types::generated::force_bridge_lockscript::ForceBridgeLockscriptArgsReader;
-use molecule::prelude::Reader;
+use ckb_std::ckb_constants::Source;
+use contracts_helper::since::Since;
+use contracts_helper::verifier::{verify_lock_script, LockScriptVerifier, ScriptGroup};
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::mint_witness::{MintWitnessVersion, RefundRecordView};
+use force_bridge_types::owner_cell::{OwnerCellView, TokenInfoConfig, TwoPhaseMintConfig};
+use force_bridge_types::pending_mint::PendingMintView;
+use force_bridge_types::recipient_cell::RecipientDataRef;
+use force_bridge_types::token_info::TokenInfoView;
+use std::prelude::v1::*;
+
+// a relative epoch since of epoch number 0, index 0 and length 1.
+const RELATIVE_EPOCH_SINCE: u64 = 0xa000_0100_0000_0000;
 
 #[cfg(target_arch = "riscv64")]
 pub fn verify() -> i8 {
@@ -20,15 +32,440 @@ pub fn verify() -> i8 {
     0
 }
 
+/// The messages `_verify` rejects a tx with, every rejection panics with a message starting with
+/// one of them. The fuzz targets treat any other panic as a bug.
+pub const REJECTIONS: &[&str] = &[
+    "script group has no cells",
+    "args are invalid",
+    "owner cell not found",
+    "owner cell is not unique",
+    "owner cell type is not a type id",
+    "not authorized to unlock the cell",
+    "owner cell data is invalid",
+    "token info cell not found",
+    "token info is invalid",
+    "token info decimals not match",
+    "invalid pending mint cell",
+    "invalid sudt cell",
+    "sudt amount overflow",
+    "owner can not unlock pending mint cells",
+    "mint is not pending",
+    "cancelled pending mint not burned",
+    "challenge period not over",
+    "finalize can not mint",
+    "pending mint not paid to the recipient",
+    "large mint not signed by the high value signer",
+    "mint witness is invalid",
+    "duplicate mint record",
+    "minted sudt not match the mint records",
+    "mint fee exceeds the amount",
+    "mint fee not collected",
+    "refunds not enabled by the owner cell",
+    "refunded recipient cell is invalid",
+    "refund not of this bridge",
+    "evm destination not registered",
+];
+
+// bridge-lockscript args are versioned, see `force_bridge_types::versioned`. A version only adds
+// fields, every version follows the same rules. The EVM destination of the V2 args only sets the
+// cells and sUDT of the bridge of an asset to an EVM network apart from the bridges of the asset
+// to other networks, the owner unlocks it only for a destination its owner cell registers.
+//
+// Rejections panic with a message of `REJECTIONS`.
 pub fn _verify<T: Adapter>(data_loader: T) {
-    let script_args = data_loader.load_script_args();
-    ForceBridgeLockscriptArgsReader::verify(&script_args, false).expect("args are invalid");
-    let force_bridge_args = ForceBridgeLockscriptArgsReader::new_unchecked(&script_args);
+    let group = data_loader.load_script_group();
+    verify_lock_script(group, &BridgeLockscript { data_loader });
+}
 
-    let owner_cell_type_hash = force_bridge_args.owner_cell_type_hash().raw_data().as_ref();
-    let owner_lock_hash = data_loader.get_owner_lock_hash(owner_cell_type_hash);
+struct BridgeLockscript<T: Adapter> {
+    data_loader: T,
+}
+
+impl<T: Adapter> LockScriptVerifier for BridgeLockscript<T> {
+    fn verify(&self, _group: ScriptGroup) {
+        let data_loader = &self.data_loader;
+        let script_args = data_loader.load_script_args();
+        let args = BridgeArgsRef::new(&script_args).expect("args are invalid");
+        if let Some(evm_destination) = args.evm_destination {
+            if !evm_destination.is_set() {
+                panic!("args are invalid, evm destination is not set");
+            }
+        }
+        verify_unlock(data_loader, &args)
+    }
+}
 
-    if !data_loader.lock_script_exists_in_inputs(&owner_lock_hash) {
-        panic!("not authorized to unlock the cell");
+fn verify_unlock<T: Adapter>(data_loader: &T, args: &BridgeArgsRef) {
+    let (owner_lock_hash, owner_cell_data) = data_loader.get_owner_cell(args.owner_cell_type_hash);
+
+    if owner_cell_data.is_empty() {
+        if !data_loader.lock_script_exists_in_inputs(&owner_lock_hash) {
+            panic!("not authorized to unlock the cell");
+        }
+        verify_evm_destination(args, None);
+        verify_mint_records(data_loader, args, None);
+        return;
+    }
+    let owner_cell = OwnerCellView::new(&owner_cell_data).expect("owner cell data is invalid");
+    if let Some(two_phase_mint) = &owner_cell.two_phase_mint {
+        if two_phase_mint.challenge_period > 0xff_ffff {
+            panic!("owner cell data is invalid, challenge period overflows an epoch number");
+        }
+    }
+    // the recipient typescript takes a burn without the owner cell as unscaled, so the sUDT can
+    // not have more decimals than its asset.
+    if let Some(decimals) = owner_cell.decimals(args) {
+        if decimals.sudt_decimals > decimals.xchain_decimals {
+            panic!(
+                "owner cell data is invalid, sudt decimals {:?} exceed the asset decimals {:?}",
+                decimals.sudt_decimals, decimals.xchain_decimals
+            );
+        }
+    }
+
+    if data_loader.lock_script_exists_in_inputs(&owner_lock_hash) {
+        verify_evm_destination(args, Some(&owner_cell));
+        let large_mint = owner_cell.large_mint.as_ref().and_then(|large_mint| {
+            large_mint
+                .threshold(args)
+                .map(|threshold| (large_mint.signer_lock_hash, threshold))
+        });
+        // a one-phase bridge without a threshold for its asset needs no sUDT accounting.
+        if owner_cell.two_phase_mint.is_some() || large_mint.is_some() {
+            let input_sudt = data_loader.get_sudt_amount(Source::Input, None);
+            let output_sudt = data_loader.get_sudt_amount(Source::Output, None);
+            if owner_cell.two_phase_mint.is_some() {
+                verify_pending_mint(data_loader, input_sudt, output_sudt);
+            }
+            if let Some((signer_lock_hash, threshold)) = large_mint {
+                let minted = output_sudt.saturating_sub(input_sudt);
+                if minted > threshold
+                    && !data_loader.lock_script_exists_in_inputs(&signer_lock_hash)
+                {
+                    panic!(
+                        "large mint not signed by the high value signer, minted {:?}, threshold {:?}",
+                        minted, threshold
+                    );
+                }
+            }
+        }
+        if let Some(token_info) = &owner_cell.token_info {
+            verify_token_info(data_loader, token_info, owner_cell.decimals(args));
+        }
+        verify_mint_records(data_loader, args, Some(&owner_cell));
+        return;
+    }
+    match &owner_cell.two_phase_mint {
+        Some(two_phase_mint) => verify_pending_mint_release(data_loader, two_phase_mint),
+        None => panic!("not authorized to unlock the cell"),
+    }
+}
+
+fn verify_evm_destination(args: &BridgeArgsRef, owner_cell: Option<&OwnerCellView>) {
+    let evm_destination = match args.evm_destination {
+        Some(evm_destination) => evm_destination,
+        None => return,
+    };
+    let registered = owner_cell.map_or(false, |owner_cell| {
+        owner_cell.evm_destinations.contains(&evm_destination)
+    });
+    if !registered {
+        panic!(
+            "evm destination not registered, chain id {:?}, bridge contract address {:?}",
+            evm_destination.chain_id, evm_destination.bridge_contract_address
+        );
+    }
+}
+
+// Every sUDT of the bridge the owner mints is backed by a lock event in xchain, or by the burn
+// of a recipient cell it refunds: what every lock gets, outputs less inputs and pending mints
+// counted to their recipient, is exactly the sum of its records in the mint witness less their
+// fees and of the refunds to it, and no lock event or recipient cell is recorded twice. sUDT
+// already issued may pass through the tx. The fees of all the records are minted to the fee
+// collector, on top of its own records.
+//
+// The legacy witness of relayers predating the records only names the lock txs, an owner cell
+// without data lets the owner mint under it as before.
+fn verify_mint_records<T: Adapter>(
+    data_loader: &T,
+    args: &BridgeArgsRef,
+    owner_cell: Option<&OwnerCellView>,
+) {
+    let (records, refunds) = match data_loader.load_mint_witness() {
+        Some(mint_witness) if mint_witness.version == MintWitnessVersion::V1 => {
+            if owner_cell.is_some() {
+                panic!("mint witness is invalid, the owner cell rules need a versioned witness");
+            }
+            return;
+        }
+        Some(mint_witness) => (mint_witness.records, mint_witness.refunds),
+        None => (vec![], vec![]),
+    };
+    for (index, record) in records.iter().enumerate() {
+        if records[..index].iter().any(|other| {
+            other.lock_tx_hash == record.lock_tx_hash && other.log_index == record.log_index
+        }) {
+            panic!("duplicate mint record");
+        }
+    }
+
+    let mut fees = 0u128;
+    let mut unminted = Vec::with_capacity(records.len());
+    for record in records.iter() {
+        if record.fee > record.amount {
+            panic!(
+                "mint fee exceeds the amount, amount {:?}, fee {:?}",
+                record.amount, record.fee
+            );
+        }
+        fees = fees.checked_add(record.fee).expect("sudt amount overflow");
+        // a lock event the fee takes all of mints nothing to the recipient
+        if record.amount > record.fee {
+            unminted.push((record.recipient_lock_hash, record.amount - record.fee));
+        }
+    }
+    unminted.extend(refund_mints(data_loader, args, owner_cell, &refunds));
+    let mut unminted = amounts_by_lock(unminted);
+    let fee_collector = owner_cell
+        .and_then(|owner_cell| owner_cell.mint_fee)
+        .map(|mint_fee| mint_fee.collector_lock_hash);
+
+    let inputs = amounts_by_lock(data_loader.load_mints(Source::Input));
+    let mut collected = 0u128;
+    for (lock_hash, output) in amounts_by_lock(data_loader.load_mints(Source::Output)) {
+        let input = inputs
+            .iter()
+            .find(|(other, _)| other == &lock_hash)
+            .map_or(0, |(_, amount)| *amount);
+        let mut minted = output.saturating_sub(input);
+        if let Some(index) = unminted.iter().position(|(other, _)| other == &lock_hash) {
+            let (_, amount) = unminted.swap_remove(index);
+            if minted < amount {
+                panic!(
+                    "minted sudt not match the mint records, records not minted {:?}",
+                    (lock_hash, amount - minted)
+                );
+            }
+            minted -= amount;
+        }
+        if minted == 0 {
+            continue;
+        }
+        if Some(lock_hash) == fee_collector {
+            collected = minted;
+        } else {
+            panic!(
+                "minted sudt not match the mint records, no record of the mint {:?}",
+                (lock_hash, minted)
+            );
+        }
+    }
+    if !unminted.is_empty() {
+        panic!(
+            "minted sudt not match the mint records, records not minted {:?}",
+            unminted
+        );
+    }
+    if collected != fees {
+        panic!(
+            "mint fee not collected, fees {:?}, collected {:?}",
+            fees, collected
+        );
     }
 }
+
+// A refund mints the sUDT the burn of a recipient cell of this bridge took back to its refund
+// lock, consuming the cell. Whether the cell may be refunded yet is up to the recipient
+// typescript, which the owner cell sets.
+fn refund_mints<T: Adapter>(
+    data_loader: &T,
+    args: &BridgeArgsRef,
+    owner_cell: Option<&OwnerCellView>,
+    refunds: &[RefundRecordView],
+) -> Vec<([u8; 32], u128)> {
+    if refunds.is_empty() {
+        return vec![];
+    }
+    let owner_cell = owner_cell.expect("refunds not enabled by the owner cell");
+    let recipient_typescript = owner_cell
+        .recipient_typescript
+        .expect("refunds not enabled by the owner cell");
+    let (bridge_lock_code_hash, bridge_lock_hash_type) = data_loader.load_script_code();
+    let decimals = owner_cell.decimals(args);
+    refunds
+        .iter()
+        .enumerate()
+        .map(|(index, refund)| {
+            if refunds[..index].contains(refund) {
+                panic!("duplicate mint record");
+            }
+            let data = data_loader
+                .load_input_data_of_type(
+                    refund.recipient_cell_index as usize,
+                    recipient_typescript.code_hash,
+                    recipient_typescript.hash_type,
+                )
+                .expect("refunded recipient cell is invalid, not a recipient cell");
+            let data = RecipientDataRef::new(&data).expect("refunded recipient cell is invalid");
+            let refund_lock_hash = data
+                .refund
+                .expect("refunded recipient cell is invalid, not refundable")
+                .lock_hash;
+            if data.owner_cell_type_hash != args.owner_cell_type_hash
+                || data.chain != args.chain
+                || data.asset.as_bytes() != args.asset
+                || data.evm_destination != args.evm_destination
+                || data.bridge_lock_code_hash != bridge_lock_code_hash
+                || data.bridge_lock_hash_type != bridge_lock_hash_type
+            {
+                panic!(
+                    "refund not of this bridge, recipient cell {:?}",
+                    refund.recipient_cell_index
+                );
+            }
+            // the recipient typescript burned the amount of data in xchain units scaled exactly.
+            let amount = match decimals.filter(|_| data.version.is_amount_in_xchain_units()) {
+                Some(decimals) => decimals
+                    .to_sudt_amount(data.amount)
+                    .expect("refunded recipient cell is invalid, amount not scaled"),
+                None => data.amount,
+            };
+            (refund_lock_hash, amount)
+        })
+        .collect()
+}
+
+fn amounts_by_lock(amounts: Vec<([u8; 32], u128)>) -> Vec<([u8; 32], u128)> {
+    let mut sums: Vec<([u8; 32], u128)> = Vec::with_capacity(amounts.len());
+    for (lock_hash, amount) in amounts {
+        match sums.iter_mut().find(|(other, _)| other == &lock_hash) {
+            Some((_, sum)) => *sum = sum.checked_add(amount).expect("sudt amount overflow"),
+            None => sums.push((lock_hash, amount)),
+        }
+    }
+    sums
+}
+
+// A bridge whose owner requires token info only mints along with the token info cell of its
+// sUDT, so the first mint creates it and the later ones reference it. The lockscript can not
+// tell the first mint of an asset from the later ones, so once the owner cell configures token
+// info every mint needs the cell, which also keeps a destroyed cell from going unnoticed. Owners
+// opt in per owner cell, leaving `token_info` unset mints without it. The decimals it shows are
+// the sUDT ones the owner cell sets for the asset, if it sets any. Who may create or update the
+// cell is up to the token info typescript.
+fn verify_token_info<T: Adapter>(
+    data_loader: &T,
+    config: &TokenInfoConfig,
+    decimals: Option<AssetDecimals>,
+) {
+    if data_loader.get_sudt_amount(Source::Output, None)
+        <= data_loader.get_sudt_amount(Source::Input, None)
+    {
+        return;
+    }
+    let data = data_loader
+        .load_token_info(config.code_hash, config.hash_type)
+        .expect("token info cell not found");
+    let token_info = TokenInfoView::from_slice(&data).expect("token info is invalid");
+    if let Some(decimals) = decimals {
+        if token_info.decimals != decimals.sudt_decimals {
+            panic!(
+                "token info decimals not match, token info {:?}, sudt {:?}",
+                token_info.decimals, decimals.sudt_decimals
+            );
+        }
+    }
+}
+
+// With a two-phase mint, the owner mints the sUDT of the bridge into pending mint cells, locked
+// by this lockscript. Once the challenge period is over, anyone can finalize a pending mint by
+// paying its amount to the recipient, and until then the guardian can cancel it by burning its
+// amount. A since only sets a lower bound, so no tx can prove the period is not over yet: the
+// guardian can cancel a pending mint until it is finalized, the period being the time it is
+// sure to have. Unlocking the pending mint cells puts this lockscript in the inputs,
+// which is the sUDT owner mode, so neither path may mint.
+fn verify_pending_mint<T: Adapter>(data_loader: &T, input_sudt: u128, output_sudt: u128) {
+    if data_loader
+        .load_input_pending_mints()
+        .iter()
+        .any(Option::is_some)
+    {
+        panic!("owner can not unlock pending mint cells");
+    }
+    let output_pending = sum_amounts(data_loader.load_output_pending_mints().iter());
+    if output_sudt - output_pending > input_sudt {
+        panic!(
+            "mint is not pending, input {:?}, output {:?}, pending {:?}",
+            input_sudt, output_sudt, output_pending
+        );
+    }
+}
+
+fn verify_pending_mint_release<T: Adapter>(data_loader: &T, two_phase_mint: &TwoPhaseMintConfig) {
+    let pending_mints = data_loader
+        .load_input_pending_mints()
+        .into_iter()
+        .collect::<Option<Vec<PendingMintView>>>()
+        .expect("not authorized to unlock the cell");
+    let pending_amount = sum_amounts(pending_mints.iter());
+    let input_sudt = data_loader.get_sudt_amount(Source::Input, None);
+    let output_sudt = data_loader.get_sudt_amount(Source::Output, None);
+
+    if data_loader.lock_script_exists_in_inputs(&two_phase_mint.guardian_lock_hash) {
+        if output_sudt > input_sudt - pending_amount {
+            panic!(
+                "cancelled pending mint not burned, input {:?}, output {:?}, pending {:?}",
+                input_sudt, output_sudt, pending_amount
+            );
+        }
+        return;
+    }
+
+    let challenge_period = Since(RELATIVE_EPOCH_SINCE | two_phase_mint.challenge_period);
+    for index in 0..pending_mints.len() {
+        let since = data_loader.load_input_since(index);
+        if !Since(since).is_at_least(challenge_period) {
+            panic!(
+                "challenge period not over, since {:#x}, required {:#x}",
+                since, challenge_period.0
+            );
+        }
+    }
+    if output_sudt > input_sudt {
+        panic!(
+            "finalize can not mint, input {:?}, output {:?}",
+            input_sudt, output_sudt
+        );
+    }
+    for (index, pending_mint) in pending_mints.iter().enumerate() {
+        let recipient_lock_hash = &pending_mint.recipient_lock_hash;
+        if pending_mints[..index]
+            .iter()
+            .any(|other| &other.recipient_lock_hash == recipient_lock_hash)
+        {
+            continue;
+        }
+        let amount = sum_amounts(
+            pending_mints
+                .iter()
+                .filter(|other| &other.recipient_lock_hash == recipient_lock_hash),
+        );
+        let recipient_input =
+            data_loader.get_sudt_amount(Source::Input, Some(*recipient_lock_hash));
+        let recipient_output =
+            data_loader.get_sudt_amount(Source::Output, Some(*recipient_lock_hash));
+        if recipient_output < recipient_input || recipient_output - recipient_input < amount {
+            panic!(
+                "pending mint not paid to the recipient, input {:?}, output {:?}, pending {:?}",
+                recipient_input, recipient_output, amount
+            );
+        }
+    }
+}
+
+fn sum_amounts<'a, I: Iterator<Item = &'a PendingMintView>>(pending_mints: I) -> u128 {
+    pending_mints.fold(0u128, |sum, pending_mint| {
+        sum.checked_add(pending_mint.amount)
+            .expect("sudt amount overflow")
+    })
+}
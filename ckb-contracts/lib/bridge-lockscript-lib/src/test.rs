@@ -1,75 +1,123 @@
 use crate::_verify;
 use crate::adapter::*;
-use ckb_std::ckb_types::packed::Script;
+use ckb_std::ckb_types::bytes::Bytes;
+use ckb_std::ckb_types::packed::{BytesOpt, Script, WitnessArgs};
 use ckb_std::ckb_types::prelude::Pack;
-use ckb_std::error::SysError;
-use contracts_helper::data_loader::MockDataLoader;
-use force_bridge_types::generated::force_bridge_lockscript::ForceBridgeLockscriptArgs;
+use contracts_helper::mock_transaction::{
+    calc_script_hash, type_id_script, MockCell, MockTransaction, ScriptGroupType,
+};
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use force_bridge_types::config::{SUDT_CODE_HASH, SUDT_HASH_TYPE};
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::evm::EvmDestination;
+use force_bridge_types::mint_witness::{
+    MintRecordView, MintWitnessVersion, MintWitnessView, RefundRecordView,
+};
+use force_bridge_types::owner_cell::{
+    AssetDecimalsView, BridgedAssetView, LargeMintConfig, MintFeeConfig, MintThresholdView,
+    OwnerCellView, RecipientTypescriptConfig, TokenInfoConfig, TwoPhaseMintConfig,
+};
+use force_bridge_types::pending_mint::PendingMintView;
+use force_bridge_types::recipient_cell::{
+    RecipientDataVersion, RecipientDataView, RecipientRefund,
+};
+use force_bridge_types::token_info::TokenInfoView;
 use molecule::prelude::{Builder, Entity};
-use std::convert::TryInto;
 
 struct TestParams {
     owner_cell_type_hash: [u8; 32],
     chain: u8,
     asset: String,
+    /// a `ForceBridgeLockscriptArgsV2` bridge if set.
+    evm_destination: Option<EvmDestination>,
 }
 
 fn get_correct_params() -> TestParams {
     TestParams {
-        owner_cell_type_hash: [2u8; 32],
+        owner_cell_type_hash: calc_script_hash(&owner_typescript()),
         chain: 1,
         asset: "trx".to_string(),
+        evm_destination: None,
     }
 }
 
-fn generate_correct_mock(test_params: TestParams) -> MockDataLoader {
-    let mut mock = MockDataLoader::new();
+fn build_script(code_hash: [u8; 32], args: Bytes) -> Script {
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .args(args.pack())
+        .build()
+}
 
-    let force_bridge_lock_args = ForceBridgeLockscriptArgs::new_builder()
-        .asset(test_params.asset.clone().into())
-        .chain(test_params.chain.into())
-        .owner_cell_type_hash(
-            test_params
-                .owner_cell_type_hash
-                .to_vec()
-                .try_into()
-                .expect("owner_lock_hash convert fail"),
-        )
-        .build();
+fn owner_lockscript() -> Script {
+    build_script([1u8; 32], Default::default())
+}
 
-    let script = Script::new_builder()
-        .args(force_bridge_lock_args.as_bytes().pack())
-        .build();
+fn owner_typescript() -> Script {
+    type_id_script([4u8; 32])
+}
 
-    let owner_lock_hash = [1u8; 32];
-    let owner_cell_type_hash = [2u8; 32];
+fn bridge_lockscript(test_params: &TestParams) -> Script {
+    let force_bridge_lock_args = BridgeArgsRef {
+        owner_cell_type_hash: &test_params.owner_cell_type_hash,
+        chain: test_params.chain,
+        asset: test_params.asset.as_bytes(),
+        evm_destination: test_params.evm_destination,
+    };
+    build_script([3u8; 32], force_bridge_lock_args.as_bytes())
+}
 
-    mock.expect_load_script()
-        .times(1)
-        .returning(move || Ok(script.clone()));
+// inputs: [bridge cell, owner lock cell], cell deps: [owner cell]
+fn generate_correct_tx(test_params: TestParams) -> MockTransaction {
+    let bridge_lockscript = bridge_lockscript(&test_params);
+    MockTransaction::new(bridge_lockscript.clone(), ScriptGroupType::Lock)
+        .cell_dep(MockCell::new(
+            owner_lockscript(),
+            Some(owner_typescript()),
+            vec![],
+        ))
+        .input(MockCell::new(bridge_lockscript, None, vec![]))
+        .input(MockCell::new(owner_lockscript(), None, vec![]))
+}
 
-    mock.expect_load_cell_lock_hash()
-        .times(1)
-        .returning(move |_, _| Ok(owner_lock_hash));
+// the witness of the bridge cell, with a record of a distinct lock event for every mint.
+fn mint_witness(mints: &[(Script, u128)]) -> Vec<u8> {
+    let records = mints
+        .iter()
+        .enumerate()
+        .map(|(index, (lockscript, amount))| MintRecordView {
+            lock_tx_hash: vec![6u8; 32],
+            log_index: index as u64,
+            amount: *amount,
+            fee: 0,
+            recipient_lock_hash: calc_script_hash(lockscript),
+        })
+        .collect();
+    mint_witness_of(MintWitnessVersion::V2, records)
+}
 
-    mock.expect_load_cell_type_hash()
-        .times(1)
-        .returning(move |_, _| Ok(Some(owner_cell_type_hash)));
+fn mint_witness_of(version: MintWitnessVersion, records: Vec<MintRecordView>) -> Vec<u8> {
+    witness_of(MintWitnessView {
+        version,
+        records,
+        refunds: vec![],
+    })
+}
 
-    mock
+fn witness_of(mint_witness: MintWitnessView) -> Vec<u8> {
+    let input_type = mint_witness.as_molecule_data().unwrap();
+    WitnessArgs::new_builder()
+        .input_type(BytesOpt::new_builder().set(Some(input_type.pack())).build())
+        .build()
+        .as_bytes()
+        .to_vec()
 }
 
 #[test]
 fn test_correct_manage_mode() {
     let test_params = get_correct_params();
-    let mut mock = generate_correct_mock(test_params);
+    let tx = generate_correct_tx(test_params);
 
-    let owner_lock_hash = [1u8; 32];
-    mock.expect_load_cell_lock_hash()
-        .times(1)
-        .returning(move |_, _| Ok(owner_lock_hash));
-
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
@@ -80,13 +128,74 @@ fn test_manage_mode_when_owner_cell_type_hash_not_exist_in_header_deps() {
     let mut test_params = get_correct_params();
     test_params.owner_cell_type_hash = [0u8; 32];
 
-    let mut mock = generate_correct_mock(test_params);
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_manage_mode_when_owner_cell_in_inputs() {
+    let test_params = get_correct_params();
+    let bridge_lockscript = bridge_lockscript(&test_params);
+    let tx = MockTransaction::new(bridge_lockscript.clone(), ScriptGroupType::Lock)
+        .input(MockCell::new(bridge_lockscript, None, vec![]))
+        .input(MockCell::new(
+            owner_lockscript(),
+            Some(owner_typescript()),
+            vec![],
+        ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell is not unique")]
+fn test_wrong_when_another_cell_dep_has_the_owner_type() {
+    let test_params = get_correct_params();
+    let mut tx = generate_correct_tx(test_params);
+    tx.cell_deps.insert(
+        0,
+        MockCell::new(
+            build_script([9u8; 32], Default::default()),
+            Some(owner_typescript()),
+            vec![],
+        ),
+    );
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell is not unique")]
+fn test_wrong_when_owner_cell_in_cell_deps_and_inputs() {
+    let test_params = get_correct_params();
+    let tx = generate_correct_tx(test_params).input(MockCell::new(
+        owner_lockscript(),
+        Some(owner_typescript()),
+        vec![],
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
 
-    mock.expect_load_cell_type_hash()
-        .times(1)
-        .returning(move |_, _| Err(SysError::IndexOutOfBound));
+#[test]
+#[should_panic(expected = "owner cell type is not a type id")]
+fn test_wrong_when_owner_cell_type_not_type_id() {
+    let mut test_params = get_correct_params();
+    let owner_typescript = build_script([2u8; 32], Default::default());
+    test_params.owner_cell_type_hash = calc_script_hash(&owner_typescript);
+    let mut tx = generate_correct_tx(test_params);
+    tx.cell_deps[0] = MockCell::new(owner_lockscript(), Some(owner_typescript), vec![]);
 
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
@@ -96,17 +205,1336 @@ fn test_manage_mode_when_owner_cell_type_hash_not_exist_in_header_deps() {
 fn test_manage_mode_when_lock_script_not_exist_in_inputs() {
     let test_params = get_correct_params();
 
-    let mut mock = generate_correct_mock(test_params);
+    let mut tx = generate_correct_tx(test_params);
+    tx.inputs.remove(1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "args are invalid")]
+fn test_manage_mode_when_args_version_unknown() {
+    let test_params = get_correct_params();
+    let mut tx = generate_correct_tx(test_params);
+
+    let mut args = tx.script.args().raw_data().to_vec();
+    args[0] = 0xff;
+    let script = tx.script.clone().as_builder().args(args.pack()).build();
+    for input in tx.inputs.iter_mut() {
+        if input.cell.output.lock().as_slice() == tx.script.as_slice() {
+            input.cell.output = input
+                .cell
+                .output
+                .clone()
+                .as_builder()
+                .lock(script.clone())
+                .build();
+        }
+    }
+    tx.script = script;
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const BSC: EvmDestination = EvmDestination {
+    chain_id: 56,
+    bridge_contract_address: [4u8; 20],
+};
+
+fn get_correct_evm_params() -> TestParams {
+    TestParams {
+        evm_destination: Some(BSC),
+        ..get_correct_params()
+    }
+}
+
+fn evm_owner_cell_data(evm_destinations: Vec<EvmDestination>) -> Vec<u8> {
+    OwnerCellView {
+        evm_destinations,
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: [sudt cell of the recipient], cell deps:
+// [owner cell registering `evm_destinations`]
+fn generate_evm_mint_tx(evm_destinations: Vec<EvmDestination>) -> MockTransaction {
+    let test_params = get_correct_evm_params();
+    let sudt_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let mut tx = generate_correct_tx(test_params)
+        .output(sudt_cell)
+        .witness(mint_witness(&[(recipient_lockscript(), 100)]));
+    tx.cell_deps[0].data = evm_owner_cell_data(evm_destinations);
+    tx
+}
+
+#[test]
+fn test_correct_mint_of_evm_bridge() {
+    let tx = generate_evm_mint_tx(vec![BSC]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination not registered")]
+fn test_evm_bridge_when_destination_not_registered() {
+    let ethereum = EvmDestination { chain_id: 1, ..BSC };
+    let tx = generate_evm_mint_tx(vec![ethereum]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination not registered")]
+fn test_evm_bridge_when_owner_cell_has_no_data() {
+    let mut tx = generate_evm_mint_tx(vec![]);
+    tx.cell_deps[0].data = vec![];
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "not authorized to unlock the cell")]
+fn test_evm_bridge_when_lock_script_not_exist_in_inputs() {
+    let mut tx = generate_correct_tx(get_correct_evm_params());
+    tx.inputs.remove(1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "args are invalid")]
+fn test_evm_bridge_when_evm_destination_not_set() {
+    let mut test_params = get_correct_evm_params();
+    test_params.evm_destination = Some(EvmDestination {
+        chain_id: 56,
+        bridge_contract_address: [0u8; 20],
+    });
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const CHALLENGE_PERIOD: u64 = 6;
+
+// relative, epoch number `epochs`, index 0, length 1.
+fn relative_epochs(epochs: u64) -> u64 {
+    0xa000_0100_0000_0000 | epochs
+}
+
+fn guardian_lockscript() -> Script {
+    build_script([4u8; 32], Default::default())
+}
+
+fn recipient_lockscript() -> Script {
+    build_script([5u8; 32], Default::default())
+}
+
+fn owner_cell_data() -> Vec<u8> {
+    OwnerCellView {
+        two_phase_mint: Some(TwoPhaseMintConfig {
+            guardian_lock_hash: calc_script_hash(&guardian_lockscript()),
+            challenge_period: CHALLENGE_PERIOD,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+fn sudt_cell(test_params: &TestParams, lockscript: Script, data: Vec<u8>) -> MockCell {
+    let sudt_typescript = Script::new_builder()
+        .code_hash(SUDT_CODE_HASH.pack())
+        .hash_type(SUDT_HASH_TYPE.into())
+        .args(Bytes::from(calc_script_hash(&bridge_lockscript(test_params)).to_vec()).pack())
+        .build();
+    MockCell::new(lockscript, Some(sudt_typescript), data)
+}
+
+fn pending_mint_cell(test_params: &TestParams, amount: u128) -> MockCell {
+    let pending_mint = PendingMintView {
+        amount,
+        recipient_lock_hash: calc_script_hash(&recipient_lockscript()),
+        lock_tx_hash: vec![6u8; 32],
+    };
+    sudt_cell(
+        test_params,
+        bridge_lockscript(test_params),
+        pending_mint.as_sudt_data(),
+    )
+}
+
+fn two_phase_tx(test_params: &TestParams) -> MockTransaction {
+    MockTransaction::new(bridge_lockscript(test_params), ScriptGroupType::Lock).cell_dep(
+        MockCell::new(
+            owner_lockscript(),
+            Some(owner_typescript()),
+            owner_cell_data(),
+        ),
+    )
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: [pending mint cell], cell deps: [owner cell]
+fn generate_pending_mint_tx(test_params: TestParams) -> MockTransaction {
+    two_phase_tx(&test_params)
+        .input(MockCell::new(bridge_lockscript(&test_params), None, vec![]))
+        .input(MockCell::new(owner_lockscript(), None, vec![]))
+        .output(pending_mint_cell(&test_params, 100))
+        .witness(mint_witness(&[(recipient_lockscript(), 100)]))
+}
+
+// inputs: [pending mint cell], outputs: [sudt cell of the recipient], cell deps: [owner cell]
+fn generate_finalize_tx(test_params: TestParams, since: u64) -> MockTransaction {
+    two_phase_tx(&test_params)
+        .input_with_since(pending_mint_cell(&test_params, 100), since)
+        .output(sudt_cell(
+            &test_params,
+            recipient_lockscript(),
+            100u128.to_le_bytes().to_vec(),
+        ))
+}
+
+// inputs: [pending mint cell, guardian lock cell], cell deps: [owner cell]
+fn generate_cancel_tx(test_params: TestParams) -> MockTransaction {
+    two_phase_tx(&test_params)
+        .input(pending_mint_cell(&test_params, 100))
+        .input(MockCell::new(guardian_lockscript(), None, vec![]))
+}
+
+#[test]
+fn test_correct_pending_mint() {
+    let tx = generate_pending_mint_tx(get_correct_params());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint is not pending")]
+fn test_pending_mint_when_minting_to_the_recipient_directly() {
+    let test_params = get_correct_params();
+    let sudt_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        1u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_pending_mint_tx(test_params).output(sudt_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner can not unlock pending mint cells")]
+fn test_pending_mint_when_owner_unlocks_pending_mint_cell() {
+    let test_params = get_correct_params();
+    let pending_mint_cell = pending_mint_cell(&test_params, 100);
+    let tx = generate_pending_mint_tx(test_params).input(pending_mint_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell data is invalid")]
+fn test_pending_mint_when_owner_cell_data_invalid() {
+    let mut tx = generate_pending_mint_tx(get_correct_params());
+    tx.cell_deps[0].data = vec![1, 2, 3];
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "invalid pending mint cell")]
+fn test_pending_mint_when_pending_mint_data_invalid() {
+    let test_params = get_correct_params();
+    let sudt_cell = sudt_cell(
+        &test_params,
+        bridge_lockscript(&test_params),
+        1u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_pending_mint_tx(test_params).output(sudt_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_correct_finalize() {
+    let tx = generate_finalize_tx(get_correct_params(), relative_epochs(CHALLENGE_PERIOD));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "challenge period not over")]
+fn test_finalize_before_challenge_period_over() {
+    let tx = generate_finalize_tx(get_correct_params(), relative_epochs(CHALLENGE_PERIOD - 1));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "challenge period not over")]
+fn test_finalize_with_absolute_since() {
+    let tx = generate_finalize_tx(get_correct_params(), 0x2000_0100_0000_0000 | 1000);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "pending mint not paid to the recipient")]
+fn test_finalize_when_paid_to_other_lock() {
+    let test_params = get_correct_params();
+    let mut tx = generate_finalize_tx(test_params, relative_epochs(CHALLENGE_PERIOD));
+    tx.outputs[0].output = tx.outputs[0]
+        .output
+        .clone()
+        .as_builder()
+        .lock(owner_lockscript())
+        .build();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "finalize can not mint")]
+fn test_finalize_when_minting() {
+    let test_params = get_correct_params();
+    let sudt_cell = sudt_cell(
+        &test_params,
+        owner_lockscript(),
+        1u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_finalize_tx(test_params, relative_epochs(CHALLENGE_PERIOD)).output(sudt_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "not authorized to unlock the cell")]
+fn test_finalize_when_unlocking_bridge_cell() {
+    let test_params = get_correct_params();
+    let bridge_cell = MockCell::new(bridge_lockscript(&test_params), None, vec![]);
+    let tx =
+        generate_finalize_tx(test_params, relative_epochs(CHALLENGE_PERIOD)).input(bridge_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_correct_cancel() {
+    let tx = generate_cancel_tx(get_correct_params());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "cancelled pending mint not burned")]
+fn test_cancel_when_pending_mint_not_burned() {
+    let test_params = get_correct_params();
+    let sudt_cell = sudt_cell(
+        &test_params,
+        guardian_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_cancel_tx(test_params).output(sudt_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+// the guardian can cancel until the pending mint is finalized, whatever the since of the tx.
+#[test]
+fn test_cancel_after_challenge_period() {
+    let test_params = get_correct_params();
+    let tx = two_phase_tx(&test_params)
+        .input_with_since(
+            pending_mint_cell(&test_params, 100),
+            relative_epochs(CHALLENGE_PERIOD),
+        )
+        .input(MockCell::new(guardian_lockscript(), None, vec![]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const MINT_THRESHOLD: u128 = 1000;
+
+fn signer_lockscript() -> Script {
+    build_script([7u8; 32], Default::default())
+}
+
+fn large_mint_owner_cell_data(
+    test_params: &TestParams,
+    two_phase_mint: Option<TwoPhaseMintConfig>,
+) -> Vec<u8> {
+    OwnerCellView {
+        two_phase_mint,
+        large_mint: Some(LargeMintConfig {
+            signer_lock_hash: calc_script_hash(&signer_lockscript()),
+            thresholds: vec![
+                MintThresholdView {
+                    bridged_asset: BridgedAssetView {
+                        chain: test_params.chain,
+                        asset: b"other".to_vec(),
+                        evm_destination: test_params.evm_destination,
+                    },
+                    threshold: 0,
+                },
+                MintThresholdView {
+                    bridged_asset: BridgedAssetView {
+                        chain: test_params.chain,
+                        asset: test_params.asset.as_bytes().to_vec(),
+                        evm_destination: test_params.evm_destination,
+                    },
+                    threshold: MINT_THRESHOLD,
+                },
+            ],
+        }),
+        evm_destinations: vec![BSC],
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: [sudt cell of the recipient],
+// cell deps: [owner cell]
+fn generate_large_mint_tx(test_params: TestParams, amount: u128) -> MockTransaction {
+    let sudt_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        amount.to_le_bytes().to_vec(),
+    );
+    let mut tx = generate_correct_tx(test_params)
+        .output(sudt_cell)
+        .witness(mint_witness(&[(recipient_lockscript(), amount)]));
+    tx.cell_deps[0].data = large_mint_owner_cell_data(&get_correct_params(), None);
+    tx
+}
+
+#[test]
+fn test_mint_at_threshold() {
+    let tx = generate_large_mint_tx(get_correct_params(), MINT_THRESHOLD);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "large mint not signed by the high value signer")]
+fn test_large_mint_without_signer() {
+    let tx = generate_large_mint_tx(get_correct_params(), MINT_THRESHOLD + 1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_large_mint_with_signer() {
+    let tx = generate_large_mint_tx(get_correct_params(), MINT_THRESHOLD + 1).input(MockCell::new(
+        signer_lockscript(),
+        None,
+        vec![],
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_large_mint_of_asset_without_threshold() {
+    let mut test_params = get_correct_params();
+    test_params.asset = "eth".to_string();
+    let tx = generate_large_mint_tx(test_params, MINT_THRESHOLD * 10);
+
+    let adapter = ChainAdapter { chain: tx };
 
-    let owner_lock_hash = [3u8; 32];
-    mock.expect_load_cell_lock_hash()
-        .times(1)
-        .returning(move |_, _| Ok(owner_lock_hash));
-    mock.expect_load_cell_lock_hash()
-        .times(1)
-        .returning(move |_, _| Err(SysError::IndexOutOfBound));
+    _verify(adapter);
+}
+
+#[test]
+fn test_large_mint_of_asset_bridged_to_other_network() {
+    // the threshold is set for the bridge of trx without an EVM destination.
+    let tx = generate_large_mint_tx(get_correct_evm_params(), MINT_THRESHOLD * 10);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "large mint not signed by the high value signer")]
+fn test_large_evm_mint_without_signer() {
+    let test_params = get_correct_evm_params();
+    let owner_cell_data = large_mint_owner_cell_data(&test_params, None);
+    let mut tx = generate_large_mint_tx(test_params, MINT_THRESHOLD + 1);
+    tx.cell_deps[0].data = owner_cell_data;
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "large mint not signed by the high value signer")]
+fn test_large_pending_mint_without_signer() {
+    let test_params = get_correct_params();
+    let two_phase_mint = TwoPhaseMintConfig {
+        guardian_lock_hash: calc_script_hash(&guardian_lockscript()),
+        challenge_period: CHALLENGE_PERIOD,
+    };
+    let owner_cell_data = large_mint_owner_cell_data(&test_params, Some(two_phase_mint));
+    let pending_mint_cell = pending_mint_cell(&test_params, MINT_THRESHOLD + 1);
+    let mut tx = generate_pending_mint_tx(test_params);
+    tx.cell_deps[0].data = owner_cell_data;
+    tx.outputs = vec![pending_mint_cell];
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: [sudt cell of the recipient, sudt cell of the
+// owner], cell deps: [owner cell] without mint rules.
+fn generate_mint_tx(test_params: TestParams) -> MockTransaction {
+    let recipient_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let owner_cell = sudt_cell(
+        &test_params,
+        owner_lockscript(),
+        10u128.to_le_bytes().to_vec(),
+    );
+    generate_correct_tx(test_params)
+        .output(recipient_cell)
+        .output(owner_cell)
+}
+
+#[test]
+fn test_correct_mint_with_records() {
+    let tx = generate_mint_tx(get_correct_params()).witness(mint_witness(&[
+        (owner_lockscript(), 10),
+        (recipient_lockscript(), 100),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_mint_records_ignore_sudt_of_other_bridges() {
+    let mut other_params = get_correct_params();
+    other_params.asset = "eth".to_string();
+    let other_sudt_cell = sudt_cell(
+        &other_params,
+        owner_lockscript(),
+        1u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_mint_tx(get_correct_params())
+        .output(other_sudt_cell)
+        .witness(mint_witness(&[
+            (recipient_lockscript(), 100),
+            (owner_lockscript(), 10),
+        ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_mint_records_count_the_sudt_minted_only() {
+    let test_params = get_correct_params();
+    let passing_sudt = sudt_cell(
+        &test_params,
+        signer_lockscript(),
+        50u128.to_le_bytes().to_vec(),
+    );
+    let topped_up_sudt = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        30u128.to_le_bytes().to_vec(),
+    );
+    // the recipient gets 100 on top of its 30, the other sUDT passes through unchanged.
+    let tx = generate_mint_tx(test_params)
+        .input(passing_sudt.clone())
+        .input(topped_up_sudt)
+        .output(passing_sudt)
+        .output(sudt_cell(
+            &get_correct_params(),
+            recipient_lockscript(),
+            30u128.to_le_bytes().to_vec(),
+        ))
+        .witness(mint_witness(&[
+            (owner_lockscript(), 10),
+            (recipient_lockscript(), 100),
+        ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_when_sudt_passing_through_grows() {
+    let test_params = get_correct_params();
+    let tx = generate_mint_tx(test_params)
+        .input(sudt_cell(
+            &get_correct_params(),
+            signer_lockscript(),
+            50u128.to_le_bytes().to_vec(),
+        ))
+        .output(sudt_cell(
+            &get_correct_params(),
+            signer_lockscript(),
+            51u128.to_le_bytes().to_vec(),
+        ))
+        .witness(mint_witness(&[
+            (owner_lockscript(), 10),
+            (recipient_lockscript(), 100),
+        ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_without_records() {
+    let tx = generate_mint_tx(get_correct_params());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+// the witness the relayer wrote before mint records, naming the lock txs of the mints.
+fn legacy_mint_witness() -> Vec<u8> {
+    let record = MintRecordView {
+        lock_tx_hash: vec![6u8; 32],
+        log_index: 0,
+        amount: 0,
+        fee: 0,
+        recipient_lock_hash: [0u8; 32],
+    };
+    mint_witness_of(MintWitnessVersion::V1, vec![record])
+}
+
+#[test]
+fn test_mint_with_legacy_witness() {
+    let tx = generate_mint_tx(get_correct_params()).witness(legacy_mint_witness());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint witness is invalid")]
+fn test_mint_with_legacy_witness_when_owner_cell_sets_rules() {
+    let mut tx = generate_mint_tx(get_correct_params()).witness(legacy_mint_witness());
+    tx.cell_deps[0].data = OwnerCellView::default().as_molecule_data().to_vec();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_when_amount_not_match_record() {
+    let tx = generate_mint_tx(get_correct_params()).witness(mint_witness(&[
+        (recipient_lockscript(), 101),
+        (owner_lockscript(), 9),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_when_recipient_not_match_record() {
+    let tx = generate_mint_tx(get_correct_params()).witness(mint_witness(&[
+        (recipient_lockscript(), 10),
+        (owner_lockscript(), 100),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_manage_mode_with_unminted_record() {
+    let tx = generate_correct_tx(get_correct_params())
+        .witness(mint_witness(&[(recipient_lockscript(), 100)]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "duplicate mint record")]
+fn test_mint_when_lock_event_recorded_twice() {
+    let test_params = get_correct_params();
+    let records = vec![
+        MintRecordView {
+            lock_tx_hash: vec![6u8; 32],
+            log_index: 1,
+            amount: 100,
+            fee: 0,
+            recipient_lock_hash: calc_script_hash(&recipient_lockscript()),
+        };
+        2
+    ];
+    let sudt_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_correct_tx(test_params)
+        .output(sudt_cell.clone())
+        .output(sudt_cell)
+        .witness(mint_witness_of(MintWitnessVersion::V3, records));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint witness is invalid")]
+fn test_mint_when_witness_invalid() {
+    let tx = generate_mint_tx(get_correct_params()).witness(vec![1, 2, 3]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const TOKEN_INFO_CODE_HASH: [u8; 32] = [8u8; 32];
+
+fn token_info_owner_cell_data(sudt_decimals: Option<u8>) -> Vec<u8> {
+    let asset_decimals = sudt_decimals
+        .map(|sudt_decimals| AssetDecimalsView {
+            bridged_asset: BridgedAssetView {
+                chain: 1,
+                asset: b"trx".to_vec(),
+                evm_destination: None,
+            },
+            decimals: AssetDecimals {
+                xchain_decimals: 18,
+                sudt_decimals,
+            },
+        })
+        .into_iter()
+        .collect();
+    OwnerCellView {
+        asset_decimals,
+        token_info: Some(TokenInfoConfig {
+            code_hash: TOKEN_INFO_CODE_HASH,
+            hash_type: 0,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+fn token_info_cell(test_params: &TestParams, data: Vec<u8>) -> MockCell {
+    let sudt_typescript = sudt_cell(test_params, owner_lockscript(), vec![])
+        .output
+        .type_()
+        .to_opt()
+        .unwrap();
+    let token_info_typescript = build_script(
+        TOKEN_INFO_CODE_HASH,
+        Bytes::from(calc_script_hash(&sudt_typescript).to_vec()),
+    );
+    MockCell::new(owner_lockscript(), Some(token_info_typescript), data)
+}
+
+fn token_info_data() -> Vec<u8> {
+    TokenInfoView {
+        decimals: 8,
+        name: "TRON".to_string(),
+        symbol: "TRX".to_string(),
+    }
+    .as_bytes()
+}
+
+// the mint tx, the owner cell of which requires the token info cell of the sUDT.
+fn generate_token_info_mint_tx(sudt_decimals: Option<u8>) -> MockTransaction {
+    let mut tx = generate_mint_tx(get_correct_params()).witness(mint_witness(&[
+        (recipient_lockscript(), 100),
+        (owner_lockscript(), 10),
+    ]));
+    tx.cell_deps[0].data = token_info_owner_cell_data(sudt_decimals);
+    tx
+}
+
+#[test]
+fn test_first_mint_creates_token_info() {
+    let test_params = get_correct_params();
+    let tx = generate_token_info_mint_tx(Some(8))
+        .output(token_info_cell(&test_params, token_info_data()));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_mint_references_token_info() {
+    let test_params = get_correct_params();
+    let tx = generate_token_info_mint_tx(None)
+        .cell_dep(token_info_cell(&test_params, token_info_data()));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_manage_mode_without_mint_needs_no_token_info() {
+    let mut tx = generate_correct_tx(get_correct_params());
+    tx.cell_deps[0].data = token_info_owner_cell_data(None);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info cell not found")]
+fn test_mint_without_token_info() {
+    let tx = generate_token_info_mint_tx(None);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info cell not found")]
+fn test_mint_with_token_info_of_other_sudt() {
+    let mut other_params = get_correct_params();
+    other_params.asset = "eth".to_string();
+    let tx =
+        generate_token_info_mint_tx(None).output(token_info_cell(&other_params, token_info_data()));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info is invalid")]
+fn test_mint_when_token_info_invalid() {
+    let test_params = get_correct_params();
+    let tx = generate_token_info_mint_tx(None).output(token_info_cell(&test_params, vec![8u8]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info decimals not match")]
+fn test_mint_when_token_info_decimals_not_match() {
+    let test_params = get_correct_params();
+    let tx = generate_token_info_mint_tx(Some(6))
+        .output(token_info_cell(&test_params, token_info_data()));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell data is invalid, sudt decimals 20 exceed")]
+fn test_mint_when_sudt_has_more_decimals_than_asset() {
+    let tx = generate_token_info_mint_tx(Some(20));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+fn collector_lockscript() -> Script {
+    build_script([9u8; 32], Default::default())
+}
+
+fn mint_fee_owner_cell_data() -> Vec<u8> {
+    OwnerCellView {
+        mint_fee: Some(MintFeeConfig {
+            collector_lock_hash: calc_script_hash(&collector_lockscript()),
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+// the witness of the bridge cell, with a record of a distinct lock event for every mint of
+// `(recipient, amount, fee)`.
+fn fee_mint_witness(mints: &[(Script, u128, u128)]) -> Vec<u8> {
+    let records = mints
+        .iter()
+        .enumerate()
+        .map(|(index, (lockscript, amount, fee))| MintRecordView {
+            lock_tx_hash: vec![6u8; 32],
+            log_index: index as u64,
+            amount: *amount,
+            fee: *fee,
+            recipient_lock_hash: calc_script_hash(lockscript),
+        })
+        .collect();
+    mint_witness_of(MintWitnessVersion::V3, records)
+}
+
+// inputs: [bridge cell, owner lock cell], outputs: an sudt cell for every `(lock, amount)` of
+// `mints`, cell deps: [owner cell] collecting mint fees.
+fn generate_fee_mint_tx(mints: &[(Script, u128)]) -> MockTransaction {
+    let test_params = get_correct_params();
+    let mut tx = MockTransaction::new(bridge_lockscript(&test_params), ScriptGroupType::Lock)
+        .cell_dep(MockCell::new(
+            owner_lockscript(),
+            Some(owner_typescript()),
+            mint_fee_owner_cell_data(),
+        ))
+        .input(MockCell::new(bridge_lockscript(&test_params), None, vec![]))
+        .input(MockCell::new(owner_lockscript(), None, vec![]));
+    for (lockscript, amount) in mints {
+        tx = tx.output(sudt_cell(
+            &test_params,
+            lockscript.clone(),
+            amount.to_le_bytes().to_vec(),
+        ));
+    }
+    tx
+}
+
+#[test]
+fn test_correct_mint_with_fees() {
+    let tx = generate_fee_mint_tx(&[
+        (recipient_lockscript(), 97),
+        (owner_lockscript(), 9),
+        (collector_lockscript(), 4),
+    ])
+    .witness(fee_mint_witness(&[
+        (recipient_lockscript(), 100, 3),
+        (owner_lockscript(), 10, 1),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_mint_fees_collected_in_several_cells() {
+    let tx = generate_fee_mint_tx(&[
+        (collector_lockscript(), 1),
+        (recipient_lockscript(), 97),
+        (collector_lockscript(), 3),
+        (owner_lockscript(), 9),
+    ])
+    .witness(fee_mint_witness(&[
+        (recipient_lockscript(), 100, 3),
+        (owner_lockscript(), 10, 1),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_mint_fee_of_the_whole_amount() {
+    let tx = generate_fee_mint_tx(&[(collector_lockscript(), 3)]).witness(fee_mint_witness(&[(
+        recipient_lockscript(),
+        3,
+        3,
+    )]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint fee not collected")]
+fn test_mint_when_fee_not_collected() {
+    let tx = generate_fee_mint_tx(&[
+        (recipient_lockscript(), 97),
+        (owner_lockscript(), 9),
+        (collector_lockscript(), 3),
+    ])
+    .witness(fee_mint_witness(&[
+        (recipient_lockscript(), 100, 3),
+        (owner_lockscript(), 10, 1),
+    ]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint fee not collected")]
+fn test_mint_when_collector_takes_more_than_the_fees() {
+    let tx = generate_fee_mint_tx(&[(recipient_lockscript(), 97), (collector_lockscript(), 4)])
+        .witness(fee_mint_witness(&[(recipient_lockscript(), 100, 3)]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_when_recipient_not_charged_the_fee() {
+    let tx = generate_fee_mint_tx(&[(recipient_lockscript(), 100), (collector_lockscript(), 3)])
+        .witness(fee_mint_witness(&[(recipient_lockscript(), 100, 3)]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "mint fee exceeds the amount")]
+fn test_mint_when_fee_exceeds_the_amount() {
+    let tx = generate_fee_mint_tx(&[(collector_lockscript(), 4)]).witness(fee_mint_witness(&[(
+        recipient_lockscript(),
+        3,
+        4,
+    )]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_mint_fee_without_collector() {
+    let test_params = get_correct_params();
+    let recipient_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        97u128.to_le_bytes().to_vec(),
+    );
+    let collector_cell = sudt_cell(
+        &test_params,
+        collector_lockscript(),
+        3u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_correct_tx(test_params)
+        .output(recipient_cell)
+        .output(collector_cell)
+        .witness(fee_mint_witness(&[(recipient_lockscript(), 100, 3)]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const RECIPIENT_TYPESCRIPT_CODE_HASH: [u8; 32] = [10u8; 32];
+
+fn refund_lockscript() -> Script {
+    build_script([11u8; 32], Default::default())
+}
+
+fn refund_owner_cell_data(asset_decimals: Vec<AssetDecimalsView>) -> Vec<u8> {
+    OwnerCellView {
+        asset_decimals,
+        recipient_typescript: Some(RecipientTypescriptConfig {
+            code_hash: RECIPIENT_TYPESCRIPT_CODE_HASH,
+            hash_type: 0,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+// a refundable recipient cell of the bridge of `test_params`, which burned `amount`.
+fn recipient_cell(test_params: &TestParams, amount: u128) -> MockCell {
+    recipient_cell_of_version(test_params, RecipientDataVersion::V2, amount)
+}
+
+fn recipient_cell_of_version(
+    test_params: &TestParams,
+    version: RecipientDataVersion,
+    amount: u128,
+) -> MockCell {
+    let data = RecipientDataView {
+        version,
+        recipient_address: "0x0000000000000000000000000000000000000001".to_string(),
+        chain: test_params.chain,
+        asset: test_params.asset.clone(),
+        bridge_lock_code_hash: [3u8; 32],
+        bridge_lock_hash_type: 0,
+        owner_cell_type_hash: test_params.owner_cell_type_hash,
+        amount,
+        refund: Some(RecipientRefund {
+            lock_hash: calc_script_hash(&refund_lockscript()),
+            since: 0,
+        }),
+        memo: vec![],
+        evm_destination: None,
+    }
+    .as_molecule_data()
+    .unwrap();
+    MockCell::new(
+        recipient_lockscript(),
+        Some(build_script(
+            RECIPIENT_TYPESCRIPT_CODE_HASH,
+            Default::default(),
+        )),
+        data.to_vec(),
+    )
+}
+
+fn refund_witness(records: Vec<MintRecordView>, recipient_cell_indices: &[u32]) -> Vec<u8> {
+    witness_of(MintWitnessView {
+        version: MintWitnessVersion::V4,
+        records,
+        refunds: recipient_cell_indices
+            .iter()
+            .map(|recipient_cell_index| RefundRecordView {
+                recipient_cell_index: *recipient_cell_index,
+            })
+            .collect(),
+    })
+}
+
+// inputs: [bridge cell, owner lock cell, recipient cell of 100], outputs: [sudt cell of the
+// refund lock], cell deps: [owner cell] enabling refunds.
+fn generate_refund_tx(test_params: TestParams) -> MockTransaction {
+    let recipient_cell = recipient_cell(&test_params, 100);
+    let refund_cell = sudt_cell(
+        &test_params,
+        refund_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let mut tx = generate_correct_tx(test_params)
+        .input(recipient_cell)
+        .output(refund_cell);
+    tx.cell_deps[0].data = refund_owner_cell_data(vec![]);
+    tx
+}
+
+#[test]
+fn test_correct_refund() {
+    let tx = generate_refund_tx(get_correct_params()).witness(refund_witness(vec![], &[2]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_refund_along_with_records() {
+    let test_params = get_correct_params();
+    let recipient_cell = sudt_cell(
+        &test_params,
+        recipient_lockscript(),
+        50u128.to_le_bytes().to_vec(),
+    );
+    let record = MintRecordView {
+        lock_tx_hash: vec![6u8; 32],
+        log_index: 0,
+        amount: 50,
+        fee: 0,
+        recipient_lock_hash: calc_script_hash(&recipient_lockscript()),
+    };
+    let tx = generate_refund_tx(test_params)
+        .output(recipient_cell)
+        .witness(refund_witness(vec![record], &[2]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+fn refund_decimals(test_params: &TestParams) -> AssetDecimalsView {
+    AssetDecimalsView {
+        bridged_asset: BridgedAssetView {
+            chain: test_params.chain,
+            asset: test_params.asset.as_bytes().to_vec(),
+            evm_destination: None,
+        },
+        decimals: AssetDecimals {
+            xchain_decimals: 18,
+            sudt_decimals: 8,
+        },
+    }
+}
+
+#[test]
+fn test_refund_with_decimals() {
+    let test_params = get_correct_params();
+    let decimals = refund_decimals(&test_params);
+    // 100 * 10^10 trx were burned as 100 sUDT.
+    let mut tx = generate_refund_tx(test_params).witness(refund_witness(vec![], &[2]));
+    tx.inputs[2].cell = recipient_cell_of_version(
+        &get_correct_params(),
+        RecipientDataVersion::V5,
+        100 * 10u128.pow(10),
+    );
+    tx.cell_deps[0].data = refund_owner_cell_data(vec![decimals]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_refund_of_earlier_version_with_decimals() {
+    // the amount of V2 data is the sUDT burned, whatever the decimals of the owner cell.
+    let test_params = get_correct_params();
+    let decimals = refund_decimals(&test_params);
+    let mut tx = generate_refund_tx(test_params).witness(refund_witness(vec![], &[2]));
+    tx.cell_deps[0].data = refund_owner_cell_data(vec![decimals]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_refund_without_refund_record() {
+    let tx = generate_refund_tx(get_correct_params()).witness(mint_witness(&[]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refunds not enabled by the owner cell")]
+fn test_refund_when_owner_cell_sets_no_recipient_typescript() {
+    let mut tx = generate_refund_tx(get_correct_params()).witness(refund_witness(vec![], &[2]));
+    tx.cell_deps[0].data = OwnerCellView::default().as_molecule_data().to_vec();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refunded recipient cell is invalid")]
+fn test_refund_of_a_cell_without_the_recipient_typescript() {
+    // input 1 is the owner lock cell.
+    let tx = generate_refund_tx(get_correct_params()).witness(refund_witness(vec![], &[1]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund not of this bridge")]
+fn test_refund_of_a_recipient_cell_of_another_bridge() {
+    let mut other_params = get_correct_params();
+    other_params.asset = "eth".to_string();
+    let mut tx = generate_refund_tx(get_correct_params()).witness(refund_witness(vec![], &[2]));
+    tx.inputs[2].cell = recipient_cell(&other_params, 100);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "duplicate mint record")]
+fn test_refund_recorded_twice() {
+    let test_params = get_correct_params();
+    let refund_cell = sudt_cell(
+        &test_params,
+        refund_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
+    let tx = generate_refund_tx(test_params)
+        .output(refund_cell)
+        .witness(refund_witness(vec![], &[2, 2]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "minted sudt not match the mint records")]
+fn test_refund_paid_to_other_lock() {
+    let test_params = get_correct_params();
+    let mut tx = generate_refund_tx(test_params).witness(refund_witness(vec![], &[2]));
+    tx.outputs[0] = sudt_cell(
+        &get_correct_params(),
+        recipient_lockscript(),
+        100u128.to_le_bytes().to_vec(),
+    );
 
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
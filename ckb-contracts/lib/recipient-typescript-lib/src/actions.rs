@@ -1,69 +1,204 @@
 use crate::adapter::Adapter;
 use ckb_std::ckb_constants::Source;
 
-#[cfg(not(feature = "std"))]
-use alloc::string::String;
-
 use blake2b_ref::{Blake2b, Blake2bBuilder};
 use ckb_std::ckb_types::packed::{Byte32, Bytes, Script};
+use contracts_helper::since::Since;
 use force_bridge_types::{
-    generated::basic, generated::force_bridge_lockscript::ForceBridgeLockscriptArgs,
-    recipient_cell::RecipientDataView,
+    bridge_args::BridgeArgsRef,
+    config::{SUDT_CODE_HASH, SUDT_HASH_TYPE},
+    decimals::ScaleError,
+    evm::EvmDestination,
+    owner_cell::OwnerCellView,
+    recipient_cell::{RecipientDataRef, MAX_MEMO_LEN},
 };
 use molecule::prelude::{Builder, Byte, Entity};
 use std::prelude::v1::*;
 
 pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
 
-pub fn verify_burn_token<T: Adapter>(data_loader: T, data: RecipientDataView) {
+pub fn verify_burn_token<T: Adapter>(data_loader: &T, data: RecipientDataRef) {
     if data.amount == 0 {
         panic!(
             "burn amount should be greater than 0, burned {:?}",
             data.amount
         )
     }
-    let force_bridge_lock_hash = calc_xchain_bridge_lock_hash(
+    if data.memo.len() > MAX_MEMO_LEN {
+        panic!(
+            "memo exceeds the max size, len {:?}, max {:?}",
+            data.memo.len(),
+            MAX_MEMO_LEN
+        )
+    }
+    let owner_cell = load_owner_cell(data_loader, &data);
+    if let Some(evm_destination) = data.evm_destination {
+        if !evm_destination.is_set() {
+            panic!(
+                "evm destination is not set, chain id {:?}, bridge contract address {:?}",
+                evm_destination.chain_id, evm_destination.bridge_contract_address
+            )
+        }
+        let registered = owner_cell.as_ref().map_or(false, |owner_cell| {
+            owner_cell.evm_destinations.contains(&evm_destination)
+        });
+        if !registered {
+            panic!(
+                "evm destination not registered, chain id {:?}, bridge contract address {:?}",
+                evm_destination.chain_id, evm_destination.bridge_contract_address
+            )
+        }
+    }
+    let sudt_amount = to_sudt_amount(owner_cell.as_ref(), &data);
+    let sudt_type_hash = calc_sudt_type_hash(&calc_xchain_bridge_lock_hash(
         &data.owner_cell_type_hash,
         data.chain,
-        data.asset,
+        data.asset.as_bytes(),
+        data.evm_destination,
         &data.bridge_lock_code_hash,
         data.bridge_lock_hash_type,
-    );
-    let input_sudt_num =
-        data_loader.get_sudt_amount_from_source(Source::Input, &force_bridge_lock_hash);
-    let output_sudt_num =
-        data_loader.get_sudt_amount_from_source(Source::Output, &force_bridge_lock_hash);
+    ));
+    let input_sudt_num = data_loader.get_sudt_amount_from_source(Source::Input, &sudt_type_hash);
+    let output_sudt_num = data_loader.get_sudt_amount_from_source(Source::Output, &sudt_type_hash);
     if input_sudt_num < output_sudt_num {
         panic!(
             "input sudt less than output sudt, input {:?}, output {:?}",
             input_sudt_num, output_sudt_num
         )
     }
-    if input_sudt_num - output_sudt_num != data.amount {
+    if input_sudt_num - output_sudt_num != sudt_amount {
         panic!(
-            "burned token amount not match data amount, input {:?}, output {:?}, data {:?}",
-            input_sudt_num, output_sudt_num, data.amount
+            "burned token amount not match data amount, input {:?}, output {:?}, data {:?}, sudt {:?}",
+            input_sudt_num, output_sudt_num, data.amount, sudt_amount
         )
     }
 }
 
-fn calc_xchain_bridge_lock_hash(
+// Consuming a V2 recipient cell while minting its bridged sUDT refunds it. The mint itself is
+// authorized by the bridge lockscript, under the rules of the bridge owner, with a refund record
+// of the cell in its mint witness.
+pub fn verify_refund<T: Adapter>(data_loader: &T, inputs: Vec<Option<RecipientDataRef>>) {
+    for (index, data) in inputs.iter().enumerate() {
+        let (data, refund) = match data.as_ref().and_then(|data| Some((data, data.refund?))) {
+            Some(refund) => refund,
+            None => continue,
+        };
+        let sudt_type_hash = calc_sudt_type_hash(&calc_xchain_bridge_lock_hash(
+            &data.owner_cell_type_hash,
+            data.chain,
+            data.asset.as_bytes(),
+            data.evm_destination,
+            &data.bridge_lock_code_hash,
+            data.bridge_lock_hash_type,
+        ));
+        let input_sudt_num =
+            data_loader.get_sudt_amount_from_source(Source::Input, &sudt_type_hash);
+        let output_sudt_num =
+            data_loader.get_sudt_amount_from_source(Source::Output, &sudt_type_hash);
+        if output_sudt_num <= input_sudt_num {
+            continue;
+        }
+        if inputs.len() > 1 {
+            panic!(
+                "refund tx has more than 1 xchain recipient cell, count {:?}",
+                inputs.len()
+            )
+        }
+
+        let since = data_loader.load_input_since(index);
+        if !Since(since).is_at_least(Since(refund.since)) {
+            panic!(
+                "refund deadline not reached, since {:#x}, refund since {:#x}",
+                since, refund.since
+            )
+        }
+        let sudt_amount = to_sudt_amount(load_owner_cell(data_loader, data).as_ref(), data);
+        if output_sudt_num - input_sudt_num != sudt_amount {
+            panic!(
+                "refund amount not match data amount, input {:?}, output {:?}, data {:?}, sudt {:?}",
+                input_sudt_num, output_sudt_num, data.amount, sudt_amount
+            )
+        }
+        let refund_input =
+            data_loader.get_sudt_amount_of_lock(Source::Input, &sudt_type_hash, &refund.lock_hash);
+        let refund_output =
+            data_loader.get_sudt_amount_of_lock(Source::Output, &sudt_type_hash, &refund.lock_hash);
+        if refund_output.checked_sub(refund_input) != Some(sudt_amount) {
+            panic!(
+                "refund not paid to the refund lock, input {:?}, output {:?}, sudt {:?}",
+                refund_input, refund_output, sudt_amount
+            )
+        }
+    }
+}
+
+// The amount of V5 data is in xchain units, scaled by the decimals the owner cell sets for the
+// asset. A burn of an amount below the smallest sUDT unit would lose the dust, so it is rejected
+// rather than rounded. The amount of earlier versions is the sUDT burned.
+fn to_sudt_amount(owner_cell: Option<&OwnerCellView>, data: &RecipientDataRef) -> u128 {
+    if !data.version.is_amount_in_xchain_units() {
+        return data.amount;
+    }
+    let decimals = match owner_cell.and_then(|owner_cell| {
+        owner_cell.decimals(&BridgeArgsRef {
+            owner_cell_type_hash: &data.owner_cell_type_hash,
+            chain: data.chain,
+            asset: data.asset.as_bytes(),
+            evm_destination: data.evm_destination,
+        })
+    }) {
+        Some(decimals) => decimals,
+        None => return data.amount,
+    };
+    decimals
+        .to_sudt_amount(data.amount)
+        .unwrap_or_else(|err| match err {
+            ScaleError::Dust => panic!(
+                "burn amount loses dust, amount {:?}, decimals {:?}",
+                data.amount, decimals
+            ),
+            ScaleError::Overflow => panic!(
+                "sudt amount overflow, amount {:?}, decimals {:?}",
+                data.amount, decimals
+            ),
+        })
+}
+
+// the owner cell of the data, none if the owner cell sets no rules. Data in xchain units needs
+// it for the decimals, a tx of earlier data may leave it out.
+fn load_owner_cell<T: Adapter>(data_loader: &T, data: &RecipientDataRef) -> Option<OwnerCellView> {
+    let owner_cell_data = match data_loader.get_owner_cell_data(&data.owner_cell_type_hash) {
+        Some(owner_cell_data) => owner_cell_data,
+        None if data.version.is_amount_in_xchain_units() => panic!(
+            "owner cell not found, data in xchain units needs it, version {:?}",
+            data.version
+        ),
+        None => return None,
+    };
+    if owner_cell_data.is_empty() {
+        return None;
+    }
+    Some(OwnerCellView::new(&owner_cell_data).expect("owner cell data is invalid"))
+}
+
+// the bridge of the data, a `ForceBridgeLockscriptArgsV2` one if it has an EVM destination.
+pub(crate) fn calc_xchain_bridge_lock_hash(
     owner_cell_type_hash: &[u8; 32],
     chain: u8,
-    asset: String,
+    asset: &[u8],
+    evm_destination: Option<EvmDestination>,
     for_bridge_lock_code_hash: &[u8; 32],
     for_bridge_lock_hash_type: u8,
 ) -> [u8; 32] {
-    let args = ForceBridgeLockscriptArgs::new_builder()
-        .owner_cell_type_hash(
-            basic::Byte32::from_slice(owner_cell_type_hash)
-                .expect("convert light_client_typescript_hash to Bytes32"),
-        )
-        .chain(chain.into())
-        .asset(asset.into())
-        .build();
+    let args = BridgeArgsRef {
+        owner_cell_type_hash,
+        chain,
+        asset,
+        evm_destination,
+    }
+    .as_bytes();
 
-    let bytes_vec = args.as_slice().iter().map(|b| Byte::new(*b)).collect();
+    let bytes_vec = args.iter().map(|b| Byte::new(*b)).collect();
 
     let force_bridge_lockscript = Script::new_builder()
         .code_hash(
@@ -77,6 +212,18 @@ fn calc_xchain_bridge_lock_hash(
     blake2b_256(force_bridge_lockscript.as_slice())
 }
 
+// the type hash of the sUDT owned by the bridge lockscript of `bridge_lock_hash`.
+pub(crate) fn calc_sudt_type_hash(bridge_lock_hash: &[u8; 32]) -> [u8; 32] {
+    let args = bridge_lock_hash.iter().map(|b| Byte::new(*b)).collect();
+    let sudt_typescript = Script::new_builder()
+        .code_hash(Byte32::from_slice(SUDT_CODE_HASH.as_ref()).expect("sudt code hash invalid"))
+        .hash_type(Byte::new(SUDT_HASH_TYPE))
+        .args(Bytes::new_builder().set(args).build())
+        .build();
+
+    blake2b_256(sudt_typescript.as_slice())
+}
+
 fn new_blake2b() -> Blake2b {
     Blake2bBuilder::new(32)
         .personal(CKB_HASH_PERSONALIZATION)
@@ -4,21 +4,38 @@ use mockall::predicate::*;
 use mockall::*;
 
 use ckb_std::ckb_constants::Source;
-use ckb_std::ckb_types::packed::Script;
-use ckb_std::error::SysError;
-use ckb_std::high_level::QueryIter;
-use contracts_helper::data_loader::DataLoader;
-use force_bridge_types::{
-    config::{SUDT_CODE_HASH, SUDT_HASH_TYPE, UDT_LEN},
-    recipient_cell::RecipientDataView,
-};
+use contracts_helper::data_loader::{DataLoader, DataLoaderIter};
+use contracts_helper::owner_cell::try_find_owner_cell;
+use contracts_helper::verifier::ScriptGroup;
+use force_bridge_types::config::UDT_LEN;
 use std::prelude::v1::*;
 
 #[cfg_attr(feature = "std", automock)]
 pub trait Adapter {
-    fn load_output_data(&self) -> Option<RecipientDataView>;
+    fn load_script_group(&self) -> ScriptGroup;
 
-    fn get_sudt_amount_from_source(&self, source: Source, lock_hash: &[u8]) -> u128;
+    /// Data of the recipient cell in the outputs, the contract reads it as a `RecipientDataRef`.
+    fn load_output_data(&self) -> Vec<u8>;
+
+    /// Data of the recipient cells in the inputs.
+    fn load_input_data(&self) -> Vec<Vec<u8>>;
+
+    fn load_input_since(&self, index: usize) -> u64;
+
+    /// The amount of the sUDT of `sudt_type_hash` in the cells of `source`.
+    fn get_sudt_amount_from_source(&self, source: Source, sudt_type_hash: &[u8; 32]) -> u128;
+
+    /// The sUDT amount of the cells of `source` locked by `cell_lock_hash`.
+    fn get_sudt_amount_of_lock(
+        &self,
+        source: Source,
+        sudt_type_hash: &[u8; 32],
+        cell_lock_hash: &[u8; 32],
+    ) -> u128;
+
+    /// Data of the owner cell in the cell deps or the inputs, it sets the decimals of the bridged
+    /// assets and the EVM destinations. None if the tx has no owner cell.
+    fn get_owner_cell_data(&self, owner_cell_type_hash: &[u8]) -> Option<Vec<u8>>;
 }
 pub struct ChainAdapter<T: DataLoader> {
     pub chain: T,
@@ -28,64 +45,86 @@ impl<T> Adapter for ChainAdapter<T>
 where
     T: DataLoader,
 {
-    fn load_output_data(&self) -> Option<RecipientDataView> {
-        let data_list = QueryIter::new(
-            |index, source| self.chain.load_cell_data(index, source),
-            Source::GroupOutput,
-        )
-        .collect::<Vec<Vec<u8>>>();
-        match data_list.len() {
-            0 => None,
-            1 => Some(
-                RecipientDataView::new(data_list[0].as_slice())
-                    .expect("RecipientDataView coding error"),
-            ),
-            _ => panic!("outputs have more than 1 xchain recipient cell"),
-        }
+    fn load_script_group(&self) -> ScriptGroup {
+        ScriptGroup::load(&self.chain)
     }
 
-    fn get_sudt_amount_from_source(&self, source: Source, force_bridge_lock_hash: &[u8]) -> u128 {
-        let mut index = 0;
-        let mut sudt_sum = 0;
-        loop {
-            let cell_type = self.chain.load_cell_type(index, source);
-            match cell_type {
-                Err(SysError::IndexOutOfBound) => break,
-                Err(err) => panic!("iter input return an error: {:?}", err),
-                Ok(cell_type) => {
-                    if !(is_sudt_typescript(cell_type, force_bridge_lock_hash)) {
-                        index += 1;
-                        continue;
-                    }
-
-                    let data = self
-                        .chain
-                        .load_cell_data(index, source)
-                        .expect("laod cell data fail");
-                    let mut buf = [0u8; UDT_LEN];
-                    if data.len() < UDT_LEN {
-                        panic!("invalid sudt cell. index: {}, source: {:?}", index, source);
-                    }
-                    buf.copy_from_slice(&data[0..UDT_LEN]);
-                    sudt_sum += u128::from_le_bytes(buf);
-                    index += 1;
-                }
-            }
-        }
-        sudt_sum
+    fn load_output_data(&self) -> Vec<u8> {
+        self.chain
+            .load_cell_data(0, Source::GroupOutput)
+            .expect("load cell data fail")
+    }
+
+    fn load_input_data(&self) -> Vec<Vec<u8>> {
+        self.chain.iter_cell_data(Source::GroupInput).collect()
+    }
+
+    fn load_input_since(&self, index: usize) -> u64 {
+        self.chain
+            .load_input_since(index, Source::GroupInput)
+            .expect("load input since fail")
     }
-}
 
-fn is_sudt_typescript(script: Option<Script>, lock_hash: &[u8]) -> bool {
-    if script.is_none() {
-        return false;
+    fn get_sudt_amount_from_source(&self, source: Source, sudt_type_hash: &[u8; 32]) -> u128 {
+        self.sum_sudt_amount(source, sudt_type_hash, |_| true)
     }
-    let script = script.unwrap();
-    if script.code_hash().raw_data().as_ref() == SUDT_CODE_HASH.as_ref()
-        && script.args().raw_data().as_ref() == lock_hash
-        && script.hash_type() == SUDT_HASH_TYPE.into()
-    {
-        return true;
+
+    fn get_sudt_amount_of_lock(
+        &self,
+        source: Source,
+        sudt_type_hash: &[u8; 32],
+        cell_lock_hash: &[u8; 32],
+    ) -> u128 {
+        self.sum_sudt_amount(source, sudt_type_hash, |index| {
+            &self
+                .chain
+                .load_cell_lock_hash(index, source)
+                .expect("load cell lock hash fail")
+                == cell_lock_hash
+        })
+    }
+
+    fn get_owner_cell_data(&self, owner_cell_type_hash: &[u8]) -> Option<Vec<u8>> {
+        let (index, source) = try_find_owner_cell(&self.chain, owner_cell_type_hash)?;
+        let data = self
+            .chain
+            .load_cell_data(index, source)
+            .expect("load cell data fail");
+        Some(data)
+    }
+}
+
+impl<T> ChainAdapter<T>
+where
+    T: DataLoader,
+{
+    // Only the type hash of a cell is compared and only the amount of its data loaded, a burn
+    // may spend and create many cells with long scripts and data.
+    fn sum_sudt_amount<F: Fn(usize) -> bool>(
+        &self,
+        source: Source,
+        sudt_type_hash: &[u8; 32],
+        filter: F,
+    ) -> u128 {
+        self.chain
+            .iter_cell_type_hashes(source)
+            .enumerate()
+            .filter(|(index, cell_type_hash)| {
+                cell_type_hash.as_ref() == Some(sudt_type_hash) && filter(*index)
+            })
+            .map(|(index, _)| {
+                let mut buf = [0u8; UDT_LEN];
+                let len = self
+                    .chain
+                    .load_cell_data_prefix(&mut buf, index, source)
+                    .expect("load cell data fail");
+                if len < UDT_LEN {
+                    panic!("invalid sudt cell. index: {}, source: {:?}", index, source);
+                }
+                u128::from_le_bytes(buf)
+            })
+            .fold(0u128, |sum, amount| {
+                sum.checked_add(amount).expect("sudt amount overflow")
+            })
     }
-    false
 }
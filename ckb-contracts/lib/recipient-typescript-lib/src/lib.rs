@@ -10,6 +10,8 @@ pub mod adapter;
 mod test;
 
 pub use adapter::Adapter;
+use contracts_helper::verifier::{verify_type_script, ScriptGroup, TypeScriptVerifier};
+use force_bridge_types::recipient_cell::RecipientDataRef;
 
 #[cfg(target_arch = "riscv64")]
 pub fn verify() -> i8 {
@@ -19,13 +21,79 @@ pub fn verify() -> i8 {
     0
 }
 
-// recipient-typescript has two situations based on whether outputs have recipient-typescript data:
-// 1: if outputs have data, we ensure it's a burn-token tx.
-// 2: if outputs don't have data, it's a destroy receipt-cell tx, it will always success.
+/// What the panic message of a rejected tx starts with, any other panic of `_verify` is a bug to
+/// the fuzz targets.
+pub const REJECTIONS: &[&str] = &[
+    "RecipientDataView coding error",
+    "outputs have more than 1 xchain recipient cell",
+    "recipient cell can not be updated",
+    "script group has no cells",
+    "burn amount should be greater than 0",
+    "input sudt less than output sudt",
+    "burned token amount not match data amount",
+    "invalid sudt cell",
+    "sudt amount overflow",
+    "refund tx has more than 1 xchain recipient cell",
+    "refund deadline not reached",
+    "refund amount not match data amount",
+    "refund not paid to the refund lock",
+    "memo exceeds the max size",
+    "evm destination is not set",
+    "evm destination not registered",
+    "owner cell is not unique",
+    "owner cell type is not a type id",
+    "owner cell data is invalid",
+    "burn amount loses dust",
+    "owner cell not found",
+];
+
+// recipient-typescript rules by what the tx does with the recipient cells:
+// 1: creating one is a burn-token tx. Every data version follows the same rules on the fields it
+//    has. The amount of V5 data is in xchain units, scaled by the decimals of the owner cell,
+//    which must be in the cell deps or the inputs; the amount of earlier versions is the sUDT
+//    burned. The EVM destination of the data, if any, must be registered in the owner cell.
+// 2: destroying them always succeeds, unless the tx mints the bridged sUDT of a cell with a
+//    refund, which V2 data always has and V3 to V5 data may have, and which refunds the cell
+//    once its since is reached.
+// 3: a recipient cell can not be updated, a tx spending one can not create one.
+//
+// Rejections panic with a message of `REJECTIONS`.
 pub fn _verify<T: Adapter>(data_loader: T) -> i8 {
-    let data = data_loader.load_output_data();
-    if let Some(data) = data {
-        actions::verify_burn_token(data_loader, data)
-    }
+    let group = data_loader.load_script_group();
+    verify_type_script(group, &RecipientTypescript { data_loader });
     0
 }
+
+struct RecipientTypescript<T: Adapter> {
+    data_loader: T,
+}
+
+impl<T: Adapter> TypeScriptVerifier for RecipientTypescript<T> {
+    fn on_create(&self, group: ScriptGroup) {
+        if group.outputs > 1 {
+            panic!(
+                "outputs have more than 1 xchain recipient cell, count {:?}",
+                group.outputs
+            );
+        }
+        let data = self.data_loader.load_output_data();
+        let data = RecipientDataRef::new(&data).expect("RecipientDataView coding error");
+        actions::verify_burn_token(&self.data_loader, data)
+    }
+
+    fn on_update(&self, group: ScriptGroup) {
+        panic!(
+            "recipient cell can not be updated, inputs {:?}, outputs {:?}",
+            group.inputs, group.outputs
+        );
+    }
+
+    fn on_destroy(&self, _group: ScriptGroup) {
+        let inputs = self.data_loader.load_input_data();
+        let inputs = inputs
+            .iter()
+            .map(|data| RecipientDataRef::new(data).ok())
+            .collect();
+        actions::verify_refund(&self.data_loader, inputs);
+    }
+}
@@ -1,16 +1,23 @@
 use crate::_verify;
+use crate::actions::{calc_sudt_type_hash, calc_xchain_bridge_lock_hash};
 use crate::adapter::*;
-use ckb_std::ckb_constants::Source;
 use ckb_std::ckb_types::prelude::Pack;
 use ckb_std::ckb_types::{
     bytes::Bytes,
     packed::{self, Script},
 };
-use ckb_std::error::SysError;
-use contracts_helper::data_loader::MockDataLoader;
+use contracts_helper::mock_transaction::{
+    calc_script_hash, type_id_script, MockCell, MockTransaction, ScriptGroupType,
+};
+use force_bridge_types::bridge_args::BridgeArgsRef;
 use force_bridge_types::config::{SUDT_CODE_HASH, SUDT_HASH_TYPE};
-use force_bridge_types::recipient_cell::RecipientDataView;
-use molecule::prelude::{Builder, Entity};
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::evm::EvmDestination;
+use force_bridge_types::owner_cell::{AssetDecimalsView, BridgedAssetView, OwnerCellView};
+use force_bridge_types::recipient_cell::{
+    RecipientDataVersion, RecipientDataView, RecipientRefund, MAX_MEMO_LEN,
+};
+use molecule::prelude::{Builder, Byte, Entity};
 
 struct TestParams {
     input_sudt_amount: u128,
@@ -22,96 +29,151 @@ struct TestParams {
     owner_lock_hash: [u8; 32],
     bridge_lock_code_hash: [u8; 32],
     bridge_lock_hash_type: u8,
-    bridge_lock_hash: [u8; 32],
+    /// burns the sUDT of a `ForceBridgeLockscriptArgsV2` bridge with V4 data if set.
+    evm_destination: Option<EvmDestination>,
+    /// burns with V5 data, whose amount is in xchain units, if set.
+    xchain_units: bool,
+    owner_cell_data: Vec<u8>,
 }
 
 fn get_correct_params() -> TestParams {
-    let recipient_address = "mock_address".to_string();
-    let chain = 1;
-    let asset = "trx".to_string();
-    let owner_lock_hash = [100u8; 32];
-    let bridge_lock_code_hash = [1u8; 32];
-    let bridge_lock_hash_type = 0;
-    let bridge_lock_hash = [
-        218u8, 23, 180, 100, 78, 151, 151, 22, 206, 10, 203, 43, 214, 141, 196, 63, 115, 243, 138,
-        86, 163, 57, 218, 146, 244, 255, 64, 70, 230, 209, 238, 159,
-    ];
-
     TestParams {
         input_sudt_amount: 100,
         output_sudt_amount: 90,
         amount: 10,
-        recipient_address,
-        chain,
-        asset,
-        owner_lock_hash,
-        bridge_lock_code_hash,
-        bridge_lock_hash_type,
-        bridge_lock_hash,
+        recipient_address: "mock_address".to_string(),
+        chain: 1,
+        asset: "trx".to_string(),
+        owner_lock_hash: calc_script_hash(&owner_typescript()),
+        bridge_lock_code_hash: [1u8; 32],
+        bridge_lock_hash_type: 0,
+        evm_destination: None,
+        xchain_units: false,
+        owner_cell_data: vec![],
     }
 }
 
-fn generate_correct_mock(test_params: TestParams) -> MockDataLoader {
-    let mut mock = MockDataLoader::new();
+fn always_success_lockscript() -> Script {
+    Script::new_builder().code_hash([0u8; 32].pack()).build()
+}
+
+fn recipient_typescript() -> Script {
+    Script::new_builder().code_hash([2u8; 32].pack()).build()
+}
 
-    let data = RecipientDataView {
-        recipient_address: test_params.recipient_address,
+fn owner_typescript() -> Script {
+    type_id_script([4u8; 32])
+}
+
+fn owner_cell(test_params: &TestParams) -> MockCell {
+    MockCell::new(
+        always_success_lockscript(),
+        Some(owner_typescript()),
+        test_params.owner_cell_data.clone(),
+    )
+}
+
+fn sudt_typescript(test_params: &TestParams) -> Script {
+    let args = BridgeArgsRef {
+        owner_cell_type_hash: &test_params.owner_lock_hash,
+        chain: test_params.chain,
+        asset: test_params.asset.as_bytes(),
+        evm_destination: test_params.evm_destination,
+    };
+    let bridge_lockscript = Script::new_builder()
+        .code_hash(test_params.bridge_lock_code_hash.pack())
+        .hash_type(Byte::new(test_params.bridge_lock_hash_type))
+        .args(args.as_bytes().pack())
+        .build();
+
+    Script::new_builder()
+        .code_hash(packed::Byte32::from_slice(SUDT_CODE_HASH.as_ref()).unwrap())
+        .hash_type(SUDT_HASH_TYPE.into())
+        .args(Bytes::from(calc_script_hash(&bridge_lockscript).to_vec()).pack())
+        .build()
+}
+
+fn sudt_cell(sudt_typescript: &Script, amount: u128) -> MockCell {
+    MockCell::new(
+        always_success_lockscript(),
+        Some(sudt_typescript.clone()),
+        amount.to_le_bytes().to_vec(),
+    )
+}
+
+fn recipient_data(test_params: &TestParams) -> RecipientDataView {
+    RecipientDataView {
+        version: match (test_params.xchain_units, test_params.evm_destination) {
+            (true, _) => RecipientDataVersion::V5,
+            (false, None) => RecipientDataVersion::V1,
+            (false, Some(_)) => RecipientDataVersion::V4,
+        },
+        recipient_address: test_params.recipient_address.clone(),
         chain: test_params.chain,
-        asset: test_params.asset,
+        asset: test_params.asset.clone(),
         bridge_lock_code_hash: test_params.bridge_lock_code_hash,
         bridge_lock_hash_type: test_params.bridge_lock_hash_type,
         owner_cell_type_hash: test_params.owner_lock_hash,
         amount: test_params.amount,
-    };
+        refund: None,
+        memo: vec![],
+        evm_destination: test_params.evm_destination,
+    }
+}
 
-    let input_sudt_amount = test_params.input_sudt_amount;
-    let output_sudt_amount = test_params.output_sudt_amount;
-    mock.expect_load_cell_data()
-        .times(4)
-        .returning(move |index, source| {
-            if source == Source::GroupOutput {
-                if index == 0 {
-                    Ok(data.as_molecule_data().unwrap().to_vec())
-                } else {
-                    Err(SysError::IndexOutOfBound)
-                }
-            } else if source == Source::Input {
-                Ok(input_sudt_amount.clone().to_le_bytes().to_vec())
-            } else {
-                Ok(output_sudt_amount.clone().to_le_bytes().to_vec())
-            }
-        });
-
-    let correct_bridge_lock_hash = test_params.bridge_lock_hash;
-    let correct_sudt_script = Script::new_builder()
-        .code_hash(packed::Byte32::from_slice(SUDT_CODE_HASH.as_ref()).unwrap())
-        .hash_type(SUDT_HASH_TYPE.into())
-        .args(Bytes::from(correct_bridge_lock_hash.to_vec()).pack())
-        .build();
+// inputs: [sudt cell], outputs: [recipient cell, sudt cell]
+fn generate_correct_tx(test_params: TestParams) -> MockTransaction {
+    let sudt_typescript = sudt_typescript(&test_params);
+    generate_burn_tx(test_params, sudt_typescript)
+}
 
-    mock.expect_load_cell_type()
-        .times(4)
-        .returning(move |index, _| {
-            if index == 0 {
-                Ok(Some(correct_sudt_script.clone()))
-            } else {
-                Err(SysError::IndexOutOfBound)
-            }
-        });
+fn generate_burn_tx(test_params: TestParams, sudt_typescript: Script) -> MockTransaction {
+    let data = recipient_data(&test_params);
 
-    mock
+    MockTransaction::new(recipient_typescript(), ScriptGroupType::Type)
+        .cell_dep(owner_cell(&test_params))
+        .input(sudt_cell(&sudt_typescript, test_params.input_sudt_amount))
+        .output(MockCell::new(
+            always_success_lockscript(),
+            Some(recipient_typescript()),
+            data.as_molecule_data().unwrap().to_vec(),
+        ))
+        .output(sudt_cell(&sudt_typescript, test_params.output_sudt_amount))
 }
 
 #[test]
 fn test_burn_token_correct() {
     let test_params = get_correct_params();
-    let mock = generate_correct_mock(test_params);
+    let tx = generate_correct_tx(test_params);
 
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
 
+// the hashes of the bridges created before the args and the data were versioned, every existing
+// bridge cell and sUDT depends on them.
+#[test]
+fn test_unversioned_bridge_hashes_are_kept() {
+    let bridge_lock_hash =
+        calc_xchain_bridge_lock_hash(&[100u8; 32], 1, b"trx", None, &[1u8; 32], 0);
+    assert_eq!(
+        bridge_lock_hash,
+        [
+            218u8, 23, 180, 100, 78, 151, 151, 22, 206, 10, 203, 43, 214, 141, 196, 63, 115, 243,
+            138, 86, 163, 57, 218, 146, 244, 255, 64, 70, 230, 209, 238, 159
+        ]
+    );
+    // the sUDT of the devnet build, the one the libs are tested with.
+    assert_eq!(
+        calc_sudt_type_hash(&bridge_lock_hash),
+        [
+            36u8, 104, 225, 102, 30, 129, 88, 145, 92, 57, 92, 29, 5, 157, 243, 88, 152, 81, 32,
+            99, 229, 120, 191, 177, 10, 216, 5, 230, 76, 201, 100, 17
+        ]
+    );
+}
+
 #[test]
 #[should_panic(expected = "input sudt less than output sudt")]
 fn test_wrong_when_input_less_than_output() {
@@ -119,9 +181,9 @@ fn test_wrong_when_input_less_than_output() {
     test_params.input_sudt_amount = 90;
     test_params.output_sudt_amount = 100;
 
-    let mock = generate_correct_mock(test_params);
+    let tx = generate_correct_tx(test_params);
 
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
@@ -132,9 +194,577 @@ fn test_wrong_when_burned_amount_not_equal_data_amount() {
     let mut test_params = get_correct_params();
     test_params.output_sudt_amount = 80;
 
-    let mock = generate_correct_mock(test_params);
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_ignores_sudt_of_other_owners() {
+    let test_params = get_correct_params();
+    let mut other_owner_params = get_correct_params();
+    other_owner_params.asset = "other".to_string();
+    let other_sudt_typescript = sudt_typescript(&other_owner_params);
+
+    let tx = generate_correct_tx(test_params)
+        .input(sudt_cell(&other_sudt_typescript, 1000))
+        .output(sudt_cell(&other_sudt_typescript, 1));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "outputs have more than 1 xchain recipient cell")]
+fn test_wrong_when_outputs_have_two_recipient_cells() {
+    let test_params = get_correct_params();
+    let data = recipient_data(&test_params);
+
+    let tx = generate_correct_tx(test_params).output(MockCell::new(
+        always_success_lockscript(),
+        Some(recipient_typescript()),
+        data.as_molecule_data().unwrap().to_vec(),
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "recipient cell can not be updated")]
+fn test_wrong_when_recipient_cell_updated() {
+    let test_params = get_correct_params();
+    let data = recipient_data(&test_params);
+
+    let tx = generate_correct_tx(test_params).input(MockCell::new(
+        always_success_lockscript(),
+        Some(recipient_typescript()),
+        data.as_molecule_data().unwrap().to_vec(),
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "sudt amount overflow")]
+fn test_wrong_when_sudt_amount_overflows() {
+    let test_params = get_correct_params();
+    let sudt_typescript = sudt_typescript(&test_params);
+
+    let tx = generate_correct_tx(test_params).input(sudt_cell(&sudt_typescript, u128::MAX));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_reads_only_the_amount_of_sudt_data() {
+    let test_params = get_correct_params();
+    let sudt_typescript = sudt_typescript(&test_params);
+    let mut data = 50u128.to_le_bytes().to_vec();
+    data.extend_from_slice(&[7u8; 1024]);
+
+    let mut tx = generate_correct_tx(test_params);
+    tx.inputs[0].cell.data = data.clone();
+    let tx = tx.input(MockCell::new(
+        always_success_lockscript(),
+        Some(sudt_typescript),
+        data,
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "invalid sudt cell")]
+fn test_wrong_when_sudt_data_too_short() {
+    let test_params = get_correct_params();
+    let sudt_typescript = sudt_typescript(&test_params);
+
+    let tx = generate_correct_tx(test_params).input(MockCell::new(
+        always_success_lockscript(),
+        Some(sudt_typescript),
+        vec![0u8; 15],
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const REFUND_SINCE: u64 = 1000;
+
+fn refund_lockscript() -> Script {
+    Script::new_builder()
+        .code_hash([3u8; 32].pack())
+        .args(Bytes::from(vec![9u8]).pack())
+        .build()
+}
+
+// inputs: [recipient cell with since], outputs: [sudt cell of the refund lock]
+fn generate_refund_tx(test_params: TestParams, since: u64) -> MockTransaction {
+    let data = recipient_data(&test_params);
+    let data = RecipientDataView {
+        version: match data.version {
+            RecipientDataVersion::V1 => RecipientDataVersion::V2,
+            version => version,
+        },
+        refund: Some(RecipientRefund {
+            lock_hash: calc_script_hash(&refund_lockscript()),
+            since: REFUND_SINCE,
+        }),
+        ..data
+    };
+    let sudt_typescript = sudt_typescript(&test_params);
+
+    MockTransaction::new(recipient_typescript(), ScriptGroupType::Type)
+        .cell_dep(owner_cell(&test_params))
+        .input_with_since(
+            MockCell::new(
+                always_success_lockscript(),
+                Some(recipient_typescript()),
+                data.as_molecule_data().unwrap().to_vec(),
+            ),
+            since,
+        )
+        .output(MockCell::new(
+            refund_lockscript(),
+            Some(sudt_typescript),
+            test_params.output_sudt_amount.to_le_bytes().to_vec(),
+        ))
+}
+
+fn get_correct_refund_params() -> TestParams {
+    let mut test_params = get_correct_params();
+    test_params.input_sudt_amount = 0;
+    test_params.output_sudt_amount = test_params.amount;
+    test_params
+}
+
+#[test]
+fn test_refund_correct() {
+    let tx = generate_refund_tx(get_correct_refund_params(), REFUND_SINCE);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_destroy_refundable_cell_before_deadline_without_mint() {
+    let mut test_params = get_correct_refund_params();
+    test_params.output_sudt_amount = 0;
+    let tx = generate_refund_tx(test_params, 0);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund deadline not reached")]
+fn test_wrong_when_refund_deadline_not_reached() {
+    let tx = generate_refund_tx(get_correct_refund_params(), REFUND_SINCE - 1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund deadline not reached")]
+fn test_wrong_when_refund_since_metric_differs() {
+    // a timestamp since is not comparable with the block number deadline.
+    let tx = generate_refund_tx(
+        get_correct_refund_params(),
+        0x4000_0000_0000_0000 | REFUND_SINCE,
+    );
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund amount not match data amount")]
+fn test_wrong_when_refund_amount_not_equal_data_amount() {
+    let mut test_params = get_correct_refund_params();
+    test_params.output_sudt_amount += 1;
+    let tx = generate_refund_tx(test_params, REFUND_SINCE);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund not paid to the refund lock")]
+fn test_wrong_when_refund_paid_to_other_lock() {
+    let mut test_params = get_correct_refund_params();
+    test_params.output_sudt_amount = 5;
+    let sudt_typescript = sudt_typescript(&test_params);
+    // the minted amount matches, but half of it goes to another lock.
+    let tx = generate_refund_tx(test_params, REFUND_SINCE).output(sudt_cell(&sudt_typescript, 5));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "refund tx has more than 1 xchain recipient cell")]
+fn test_wrong_when_refund_tx_has_more_than_one_recipient_cell() {
+    let test_params = get_correct_refund_params();
+    let data = recipient_data(&test_params);
+    let tx = generate_refund_tx(test_params, REFUND_SINCE).input(MockCell::new(
+        always_success_lockscript(),
+        Some(recipient_typescript()),
+        data.as_molecule_data().unwrap().to_vec(),
+    ));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+fn generate_memo_tx(memo_len: usize) -> MockTransaction {
+    let test_params = get_correct_params();
+    let data = RecipientDataView {
+        version: RecipientDataVersion::V3,
+        memo: vec![1u8; memo_len],
+        ..recipient_data(&test_params)
+    };
+    let mut tx = generate_correct_tx(test_params);
+    tx.outputs[0].data = data.as_molecule_data().unwrap().to_vec();
+    tx
+}
+
+#[test]
+fn test_burn_token_with_memo() {
+    let tx = generate_memo_tx(MAX_MEMO_LEN);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "memo exceeds the max size")]
+fn test_wrong_when_memo_exceeds_max_size() {
+    let tx = generate_memo_tx(MAX_MEMO_LEN + 1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+const BSC: EvmDestination = EvmDestination {
+    chain_id: 56,
+    bridge_contract_address: [4u8; 20],
+};
+
+fn get_correct_evm_params() -> TestParams {
+    TestParams {
+        evm_destination: Some(BSC),
+        owner_cell_data: OwnerCellView {
+            evm_destinations: vec![BSC],
+            ..Default::default()
+        }
+        .as_molecule_data()
+        .to_vec(),
+        ..get_correct_params()
+    }
+}
+
+#[test]
+fn test_burn_token_to_evm_destination() {
+    let tx = generate_correct_tx(get_correct_evm_params());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "burned token amount not match data amount")]
+fn test_wrong_when_burning_sudt_of_another_evm_network() {
+    let polygon_sudt_typescript = sudt_typescript(&TestParams {
+        evm_destination: Some(EvmDestination {
+            chain_id: 137,
+            ..BSC
+        }),
+        ..get_correct_evm_params()
+    });
+    let tx = generate_burn_tx(get_correct_evm_params(), polygon_sudt_typescript);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "burned token amount not match data amount")]
+fn test_wrong_when_burning_sudt_of_v1_bridge_to_evm_destination() {
+    let v1_sudt_typescript = sudt_typescript(&get_correct_params());
+    let tx = generate_burn_tx(get_correct_evm_params(), v1_sudt_typescript);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination not registered")]
+fn test_wrong_when_evm_destination_not_registered() {
+    let tx = generate_correct_tx(TestParams {
+        evm_destination: Some(EvmDestination {
+            chain_id: 137,
+            ..BSC
+        }),
+        ..get_correct_evm_params()
+    });
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination not registered")]
+fn test_wrong_when_evm_destination_and_owner_cell_has_no_data() {
+    let tx = generate_correct_tx(TestParams {
+        owner_cell_data: vec![],
+        ..get_correct_evm_params()
+    });
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination is not set")]
+fn test_wrong_when_evm_destination_not_set() {
+    let tx = generate_correct_tx(TestParams {
+        evm_destination: Some(EvmDestination { chain_id: 0, ..BSC }),
+        ..get_correct_params()
+    });
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+// the sUDT of trx has 8 decimals, trx 18
+fn owner_cell_data_with_decimals() -> Vec<u8> {
+    OwnerCellView {
+        asset_decimals: vec![AssetDecimalsView {
+            bridged_asset: BridgedAssetView {
+                chain: 1,
+                asset: b"trx".to_vec(),
+                evm_destination: None,
+            },
+            decimals: AssetDecimals {
+                xchain_decimals: 18,
+                sudt_decimals: 8,
+            },
+        }],
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+fn get_correct_decimals_params() -> TestParams {
+    let mut test_params = get_correct_params();
+    test_params.amount = 10 * 10u128.pow(10);
+    test_params.xchain_units = true;
+    test_params.owner_cell_data = owner_cell_data_with_decimals();
+    test_params
+}
+
+#[test]
+fn test_burn_token_with_decimals() {
+    let tx = generate_correct_tx(get_correct_decimals_params());
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_of_asset_without_decimals() {
+    let mut test_params = get_correct_params();
+    test_params.asset = "eth".to_string();
+    test_params.xchain_units = true;
+    test_params.owner_cell_data = owner_cell_data_with_decimals();
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_to_evm_destination_without_decimals() {
+    // the decimals are set for the bridge of trx without an EVM destination.
+    let mut test_params = get_correct_evm_params();
+    test_params.xchain_units = true;
+    let mut owner_cell = OwnerCellView::new(&owner_cell_data_with_decimals()).unwrap();
+    owner_cell.evm_destinations = vec![BSC];
+    test_params.owner_cell_data = owner_cell.as_molecule_data().to_vec();
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "burn amount loses dust")]
+fn test_wrong_when_burn_amount_loses_dust() {
+    let mut test_params = get_correct_decimals_params();
+    test_params.amount += 1;
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "burned token amount not match data amount")]
+fn test_wrong_when_burned_amount_not_scaled() {
+    let mut test_params = get_correct_decimals_params();
+    test_params.input_sudt_amount = test_params.amount;
+    test_params.output_sudt_amount = 0;
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_without_owner_cell() {
+    let mut tx = generate_correct_tx(get_correct_params());
+    tx.cell_deps.clear();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_burn_token_of_earlier_version_is_not_scaled() {
+    // the amount of V1 data is the sUDT burned, whatever the decimals of the owner cell.
+    let mut test_params = get_correct_params();
+    test_params.owner_cell_data = owner_cell_data_with_decimals();
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell not found")]
+fn test_wrong_when_burn_in_xchain_units_without_owner_cell() {
+    let mut tx = generate_correct_tx(get_correct_decimals_params());
+    tx.cell_deps.clear();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "evm destination not registered")]
+fn test_wrong_when_burn_to_evm_destination_without_owner_cell() {
+    let mut tx = generate_correct_tx(get_correct_evm_params());
+    tx.cell_deps.clear();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell is not unique")]
+fn test_wrong_when_two_cell_deps_have_the_owner_type() {
+    let mut test_params = get_correct_decimals_params();
+    let other_owner_cell = owner_cell(&test_params);
+    test_params.owner_cell_data = vec![];
+    let tx = generate_correct_tx(test_params).cell_dep(other_owner_cell);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell data is invalid")]
+fn test_wrong_when_owner_cell_data_invalid() {
+    let mut test_params = get_correct_params();
+    test_params.owner_cell_data = vec![1u8; 8];
+
+    let tx = generate_correct_tx(test_params);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_refund_with_decimals() {
+    let mut test_params = get_correct_decimals_params();
+    test_params.input_sudt_amount = 0;
+    test_params.output_sudt_amount = 10;
+
+    let tx = generate_refund_tx(test_params, REFUND_SINCE);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_refund_of_earlier_version_is_not_scaled() {
+    let mut test_params = get_correct_refund_params();
+    test_params.owner_cell_data = owner_cell_data_with_decimals();
+
+    let tx = generate_refund_tx(test_params, REFUND_SINCE);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell not found")]
+fn test_wrong_when_refund_in_xchain_units_without_owner_cell() {
+    let mut test_params = get_correct_decimals_params();
+    test_params.input_sudt_amount = 0;
+    test_params.output_sudt_amount = 10;
+
+    let mut tx = generate_refund_tx(test_params, REFUND_SINCE);
+    tx.cell_deps.clear();
 
-    let adapter = ChainAdapter { chain: mock };
+    let adapter = ChainAdapter { chain: tx };
 
     _verify(adapter);
 }
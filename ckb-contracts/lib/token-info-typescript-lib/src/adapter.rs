@@ -0,0 +1,110 @@
+#[cfg(feature = "std")]
+use mockall::predicate::*;
+#[cfg(feature = "std")]
+use mockall::*;
+
+use blake2b_ref::Blake2bBuilder;
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::packed::{Byte32, Bytes, Script};
+use contracts_helper::data_loader::{DataLoader, DataLoaderIter};
+use contracts_helper::owner_cell::find_owner_cell;
+use contracts_helper::verifier::ScriptGroup;
+use force_bridge_types::config::{SUDT_CODE_HASH, SUDT_HASH_TYPE};
+use molecule::prelude::{Builder, Byte, Entity};
+use std::prelude::v1::*;
+
+pub const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+
+#[cfg_attr(feature = "std", automock)]
+pub trait Adapter {
+    /// The sUDT type hash the token info cells of the script describe.
+    fn load_script_args(&self) -> Vec<u8>;
+
+    fn load_script_group(&self) -> ScriptGroup;
+
+    /// Data of the token info cell in the outputs.
+    fn load_output_data(&self) -> Vec<u8>;
+
+    /// Args of the bridge lockscript the sUDT of `sudt_type_hash` is owned by, if a cell of the
+    /// inputs or the outputs is locked by it.
+    fn load_bridge_args(&self, sudt_type_hash: [u8; 32]) -> Option<Vec<u8>>;
+
+    fn get_owner_lock_hash(&self, owner_cell_type_hash: &[u8]) -> [u8; 32];
+
+    fn lock_script_exists_in_inputs(&self, hash: &[u8]) -> bool;
+}
+
+pub struct ChainAdapter<T: DataLoader> {
+    pub chain: T,
+}
+
+impl<T> Adapter for ChainAdapter<T>
+where
+    T: DataLoader,
+{
+    fn load_script_args(&self) -> Vec<u8> {
+        self.chain
+            .load_script()
+            .expect("load script fail")
+            .args()
+            .raw_data()
+            .to_vec()
+    }
+
+    fn load_script_group(&self) -> ScriptGroup {
+        ScriptGroup::load(&self.chain)
+    }
+
+    fn load_output_data(&self) -> Vec<u8> {
+        self.chain
+            .load_cell_data(0, Source::GroupOutput)
+            .expect("load cell data fail")
+    }
+
+    fn load_bridge_args(&self, sudt_type_hash: [u8; 32]) -> Option<Vec<u8>> {
+        [Source::Input, Source::Output].iter().find_map(|source| {
+            self.chain
+                .iter_cell_lock_hashes(*source)
+                .position(|lock_hash| sudt_type_hash_of(&lock_hash) == sudt_type_hash)
+                .map(|index| {
+                    self.chain
+                        .load_cell_lock(index, *source)
+                        .expect("load cell lock fail")
+                        .args()
+                        .raw_data()
+                        .to_vec()
+                })
+        })
+    }
+
+    fn get_owner_lock_hash(&self, owner_cell_type_hash: &[u8]) -> [u8; 32] {
+        let (index, source) = find_owner_cell(&self.chain, owner_cell_type_hash);
+        self.chain
+            .load_cell_lock_hash(index, source)
+            .expect("load cell lock hash fail")
+    }
+
+    fn lock_script_exists_in_inputs(&self, hash: &[u8]) -> bool {
+        self.chain
+            .iter_cell_lock_hashes(Source::Input)
+            .any(|lock_hash| lock_hash.as_ref() == hash)
+    }
+}
+
+// the type hash of the sUDT owned by the lock of `lock_hash`.
+fn sudt_type_hash_of(lock_hash: &[u8; 32]) -> [u8; 32] {
+    let args = lock_hash.iter().map(|b| Byte::new(*b)).collect();
+    let sudt_typescript = Script::new_builder()
+        .code_hash(Byte32::from_slice(SUDT_CODE_HASH.as_ref()).expect("sudt code hash invalid"))
+        .hash_type(Byte::new(SUDT_HASH_TYPE))
+        .args(Bytes::new_builder().set(args).build())
+        .build();
+
+    let mut result = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32)
+        .personal(CKB_HASH_PERSONALIZATION)
+        .build();
+    blake2b.update(sudt_typescript.as_slice());
+    blake2b.finalize(&mut result);
+    result
+}
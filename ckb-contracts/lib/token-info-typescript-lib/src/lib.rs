@@ -0,0 +1,112 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+extern crate no_std_compat as std;
+
+pub mod adapter;
+#[cfg(test)]
+mod test;
+
+pub use adapter::Adapter;
+use contracts_helper::verifier::{verify_type_script, ScriptGroup, TypeScriptVerifier};
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use force_bridge_types::token_info::TokenInfoView;
+
+#[cfg(target_arch = "riscv64")]
+pub fn verify() -> i8 {
+    let chain = contracts_helper::chain::Chain {};
+    let adapter = adapter::ChainAdapter { chain };
+    _verify(adapter);
+    0
+}
+
+/// The prefixes of the panic messages `_verify` rejects a tx with.
+pub const REJECTIONS: &[&str] = &[
+    "script group has no cells",
+    "args are invalid",
+    "token info cell must be unique",
+    "token info is invalid",
+    "not authorized to change the token info",
+    "owner cell not found",
+    "owner cell is not unique",
+    "owner cell type is not a type id",
+];
+
+// token-info-typescript guards the token info cell of a bridged sUDT, in the format of the sUDT
+// Info RFC, the args being the sUDT type hash. The cell is unique in a tx, and only the owner of
+// the bridge the sUDT belongs to can create, update or destroy it: a cell of the tx is locked by
+// the bridge lockscript whose sUDT has the type hash of the args, and the owner cell of the
+// bridge is in the cell deps or the inputs with its lock in the inputs.
+//
+// Rejections panic with a message of `REJECTIONS`.
+pub fn _verify<T: Adapter>(data_loader: T) -> i8 {
+    let group = data_loader.load_script_group();
+    verify_type_script(group, &TokenInfoTypescript { data_loader });
+    0
+}
+
+struct TokenInfoTypescript<T: Adapter> {
+    data_loader: T,
+}
+
+impl<T: Adapter> TypeScriptVerifier for TokenInfoTypescript<T> {
+    fn on_create(&self, group: ScriptGroup) {
+        self.verify_owner(group);
+        self.verify_output();
+    }
+
+    fn on_update(&self, group: ScriptGroup) {
+        self.verify_owner(group);
+        self.verify_output();
+    }
+
+    fn on_destroy(&self, group: ScriptGroup) {
+        self.verify_owner(group);
+    }
+}
+
+impl<T: Adapter> TokenInfoTypescript<T> {
+    fn verify_output(&self) {
+        let data = self.data_loader.load_output_data();
+        TokenInfoView::from_slice(&data).expect("token info is invalid");
+    }
+
+    // the token info cell is unique, and changed by the owner of the bridge of its sUDT.
+    fn verify_owner(&self, group: ScriptGroup) {
+        let args = self.data_loader.load_script_args();
+        if args.len() != 32 {
+            panic!("args are invalid, len {:?}", args.len());
+        }
+        let mut sudt_type_hash = [0u8; 32];
+        sudt_type_hash.copy_from_slice(&args);
+
+        if group.inputs > 1 || group.outputs > 1 {
+            panic!(
+                "token info cell must be unique, inputs {:?}, outputs {:?}",
+                group.inputs, group.outputs
+            );
+        }
+
+        let bridge_args = self
+            .data_loader
+            .load_bridge_args(sudt_type_hash)
+            .expect("not authorized to change the token info, bridge cell not found");
+        let owner_cell_type_hash = owner_cell_type_hash(&bridge_args)
+            .expect("not authorized to change the token info, bridge args are invalid");
+        let owner_lock_hash = self.data_loader.get_owner_lock_hash(&owner_cell_type_hash);
+        if !self
+            .data_loader
+            .lock_script_exists_in_inputs(&owner_lock_hash)
+        {
+            panic!("not authorized to change the token info, owner not in inputs");
+        }
+    }
+}
+
+fn owner_cell_type_hash(bridge_args: &[u8]) -> Option<[u8; 32]> {
+    let args = BridgeArgsRef::new(bridge_args).ok()?;
+    let mut owner_cell_type_hash = [0u8; 32];
+    owner_cell_type_hash.copy_from_slice(args.owner_cell_type_hash);
+    Some(owner_cell_type_hash)
+}
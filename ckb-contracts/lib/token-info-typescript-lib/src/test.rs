@@ -0,0 +1,236 @@
+use crate::_verify;
+use crate::adapter::*;
+use ckb_std::ckb_types::bytes::Bytes;
+use ckb_std::ckb_types::packed::Script;
+use ckb_std::ckb_types::prelude::Pack;
+use contracts_helper::mock_transaction::{
+    calc_script_hash, type_id_script, MockCell, MockTransaction, ScriptGroupType,
+};
+use force_bridge_types::bridge_args::BridgeArgsRef;
+use force_bridge_types::config::{SUDT_CODE_HASH, SUDT_HASH_TYPE};
+use force_bridge_types::token_info::TokenInfoView;
+use molecule::prelude::{Builder, Entity};
+
+fn build_script(code_hash: [u8; 32], args: Bytes) -> Script {
+    Script::new_builder()
+        .code_hash(code_hash.pack())
+        .args(args.pack())
+        .build()
+}
+
+fn owner_lockscript() -> Script {
+    build_script([1u8; 32], Default::default())
+}
+
+fn owner_typescript() -> Script {
+    type_id_script([4u8; 32])
+}
+
+fn bridge_lockscript(asset: &str) -> Script {
+    let args = BridgeArgsRef {
+        owner_cell_type_hash: &calc_script_hash(&owner_typescript()),
+        chain: 1,
+        asset: asset.as_bytes(),
+        evm_destination: None,
+    };
+    build_script([3u8; 32], args.as_bytes())
+}
+
+fn token_info_typescript(asset: &str) -> Script {
+    let sudt_typescript = Script::new_builder()
+        .code_hash(SUDT_CODE_HASH.pack())
+        .hash_type(SUDT_HASH_TYPE.into())
+        .args(Bytes::from(calc_script_hash(&bridge_lockscript(asset)).to_vec()).pack())
+        .build();
+    build_script(
+        [4u8; 32],
+        Bytes::from(calc_script_hash(&sudt_typescript).to_vec()),
+    )
+}
+
+fn token_info_cell(data: Vec<u8>) -> MockCell {
+    MockCell::new(
+        build_script([5u8; 32], Default::default()),
+        Some(token_info_typescript("trx")),
+        data,
+    )
+}
+
+fn token_info_data(symbol: &str) -> Vec<u8> {
+    TokenInfoView {
+        decimals: 8,
+        name: "TRON".to_string(),
+        symbol: symbol.to_string(),
+    }
+    .as_bytes()
+}
+
+// inputs: [bridge cell, owner lock cell], cell deps: [owner cell]
+fn owner_tx() -> MockTransaction {
+    MockTransaction::new(token_info_typescript("trx"), ScriptGroupType::Type)
+        .cell_dep(MockCell::new(
+            owner_lockscript(),
+            Some(owner_typescript()),
+            vec![],
+        ))
+        .input(MockCell::new(bridge_lockscript("trx"), None, vec![]))
+        .input(MockCell::new(owner_lockscript(), None, vec![]))
+}
+
+// outputs: [token info cell]
+fn generate_create_tx() -> MockTransaction {
+    owner_tx().output(token_info_cell(token_info_data("TRX")))
+}
+
+#[test]
+fn test_correct_create() {
+    let adapter = ChainAdapter {
+        chain: generate_create_tx(),
+    };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_correct_update() {
+    let tx = owner_tx()
+        .input(token_info_cell(token_info_data("TRX")))
+        .output(token_info_cell(token_info_data("TRON")));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_correct_destroy() {
+    let tx = owner_tx().input(token_info_cell(token_info_data("TRX")));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_create_with_bridge_cell_in_outputs() {
+    let mut tx = generate_create_tx().output(MockCell::new(bridge_lockscript("trx"), None, vec![]));
+    tx.inputs.remove(0);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "not authorized to change the token info")]
+fn test_update_without_owner() {
+    let mut tx = owner_tx()
+        .input(token_info_cell(token_info_data("TRX")))
+        .output(token_info_cell(token_info_data("FAKE")));
+    tx.inputs.remove(1);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "not authorized to change the token info")]
+fn test_create_without_bridge_cell() {
+    let mut tx = generate_create_tx();
+    tx.inputs.remove(0);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "not authorized to change the token info")]
+fn test_create_with_bridge_cell_of_other_asset() {
+    let mut tx = generate_create_tx();
+    tx.inputs[0].cell = MockCell::new(bridge_lockscript("eth"), None, vec![]);
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell not found")]
+fn test_create_when_owner_cell_not_in_cell_deps() {
+    let mut tx = generate_create_tx();
+    tx.cell_deps.clear();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+fn test_update_with_owner_cell_in_inputs() {
+    let mut tx = owner_tx()
+        .input(token_info_cell(token_info_data("TRX")))
+        .output(token_info_cell(token_info_data("TRON")));
+    let owner_cell = tx.cell_deps.remove(0);
+    tx.inputs[1].cell = owner_cell;
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "owner cell is not unique")]
+fn test_create_when_another_cell_dep_has_the_owner_type() {
+    let mut tx = generate_create_tx();
+    tx.cell_deps.insert(
+        0,
+        MockCell::new(
+            build_script([9u8; 32], Default::default()),
+            Some(owner_typescript()),
+            vec![],
+        ),
+    );
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info is invalid")]
+fn test_create_when_token_info_invalid() {
+    let tx = owner_tx().output(token_info_cell(vec![8u8, 0]));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "token info cell must be unique")]
+fn test_create_two_token_info_cells() {
+    let tx = generate_create_tx().output(token_info_cell(token_info_data("TRX")));
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
+
+#[test]
+#[should_panic(expected = "args are invalid")]
+fn test_create_when_args_invalid() {
+    let mut tx = generate_create_tx();
+    tx.script = build_script([4u8; 32], Bytes::from(vec![1u8; 20]));
+    tx.outputs[0].output = tx.outputs[0]
+        .output
+        .clone()
+        .as_builder()
+        .type_(Some(tx.script.clone()).pack())
+        .build();
+
+    let adapter = ChainAdapter { chain: tx };
+
+    _verify(adapter);
+}
@@ -1,27 +1,312 @@
 use crate::utils::{case_builder::*, case_runner};
-use ckb_tool::ckb_types::packed::Script;
-use ckb_tool::ckb_types::prelude::Unpack;
+use crate::Loader;
+use ckb_tool::ckb_types::packed::{CellOutput, Script};
+use ckb_tool::ckb_types::prelude::{Builder, Entity, Pack, Unpack};
+use force_bridge_deployment::manifest::type_id_script;
 use force_bridge_types::config::CKB_UNITS;
-use molecule::prelude::Entity;
+use force_bridge_types::mint_witness::{
+    MintRecordView, MintWitnessVersion, MintWitnessView, RefundRecordView,
+};
+use force_bridge_types::owner_cell::{
+    MintFeeConfig, OwnerCellView, RecipientTypescriptConfig, TokenInfoConfig,
+};
+use force_bridge_types::recipient_cell::{
+    RecipientDataVersion, RecipientDataView, RecipientRefund,
+};
+use force_bridge_types::token_info::TokenInfoView;
 
 #[test]
 fn test_correct_tx() {
-    let case = get_correct_case();
+    let case = get_correct_case("bridge::test_correct_tx");
     case_runner::run_test(case);
 }
 
 #[test]
 fn test_tx_when_owner_not_in_inputs() {
-    let mut case = get_correct_case();
+    let mut case = get_correct_case("bridge::test_tx_when_owner_not_in_inputs");
     #[allow(irrefutable_let_patterns)]
     if let CustomCell::BridgeCustomCell(cell) = &mut case.script_cells.inputs[0] {
         cell.owner_cell_type_hash = [0u8; 32];
+        case.witnesses.clear();
         case.expect_return_error_info = "owner cell not found".to_string();
         case_runner::run_test(case);
     };
 }
 
-fn get_correct_case() -> TestCase {
+#[test]
+fn test_correct_tx_when_owner_cell_in_inputs() {
+    let mut case = get_correct_case("bridge::test_correct_tx_when_owner_cell_in_inputs");
+    // the owner updates its owner cell in the mint tx, instead of putting it in the cell deps.
+    let owner_cell = case.owner_cell.take().unwrap();
+    case.script_cells
+        .inputs
+        .push(CustomCell::OwnerCustomCell(OwnerTxCell {
+            capacity: 100 * CKB_UNITS,
+            index: 2,
+            cell: owner_cell.clone(),
+        }));
+    case.script_cells
+        .outputs
+        .push(CustomCell::OwnerCustomCell(OwnerTxCell {
+            capacity: 100 * CKB_UNITS,
+            index: 3,
+            cell: owner_cell,
+        }));
+    // the type id of the owner cell runs too, a system script of 1M cycles.
+    case.max_cycles = Some(3_000_000);
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_owner_cell_not_unique() {
+    let mut case = get_correct_case("bridge::test_tx_when_owner_cell_not_unique");
+    // a cell of the owner type the tx destroys, besides the owner cell in the cell deps.
+    let owner_cell = case.owner_cell.clone().unwrap();
+    case.script_cells
+        .inputs
+        .push(CustomCell::OwnerCustomCell(OwnerTxCell {
+            capacity: 100 * CKB_UNITS,
+            index: 2,
+            cell: owner_cell,
+        }));
+    case.expect_return_error_info = "owner cell is not unique".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_correct_tx_with_witnesses() {
+    let mut case = get_correct_case("bridge::test_correct_tx_with_witnesses");
+    case.witnesses.push(PlacedWitness::in_group(
+        ScriptGroup::Lock(ScriptView::default()),
+        Witness::Fields {
+            lock: Some(Bytes::from(vec![0u8; 65])),
+            input_type: None,
+            output_type: None,
+        },
+    ));
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_mint_records_not_match() {
+    let mut case = get_correct_case("bridge::test_tx_when_mint_records_not_match");
+    if let Witness::Mint(mint_witness) = &mut case.witnesses[0].witness {
+        // both records pay the same lock, which gets the sum of them.
+        mint_witness.records[0].amount -= 1;
+    }
+    case.expect_return_error_info = "minted sudt not match the mint records".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_correct_tx_with_legacy_mint_witness() {
+    // the relayer wrote the lock tx hashes of the mints before mint records.
+    let mut case = get_correct_case("bridge::test_correct_tx_with_legacy_mint_witness");
+    if let Witness::Mint(mint_witness) = &mut case.witnesses[0].witness {
+        mint_witness.version = MintWitnessVersion::V1;
+    }
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_mint_witness_missing() {
+    let mut case = get_correct_case("bridge::test_tx_when_mint_witness_missing");
+    case.witnesses.clear();
+    case.expect_return_error_info = "minted sudt not match the mint records".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_correct_tx_with_token_info() {
+    let mut case = get_token_info_case("bridge::test_correct_tx_with_token_info");
+    push_token_info_cell(&mut case, token_info_data());
+    // the token info typescript runs along the bridge lockscript
+    case.max_cycles = Some(5_000_000);
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_token_info_cell_missing() {
+    let mut case = get_token_info_case("bridge::test_tx_when_token_info_cell_missing");
+    case.expect_return_error_info = "token info cell not found".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_token_info_invalid() {
+    let mut case = get_token_info_case("bridge::test_tx_when_token_info_invalid");
+    push_token_info_cell(&mut case, vec![8, 0]);
+    case.expect_return_error_info = "token info is invalid".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_correct_tx_with_mint_fees() {
+    let case = get_mint_fee_case("bridge::test_correct_tx_with_mint_fees", 3);
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_mint_fees_not_collected() {
+    let mut case = get_mint_fee_case("bridge::test_tx_when_mint_fees_not_collected", 2);
+    case.expect_return_error_info = "mint fee not collected".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_correct_tx_with_refund() {
+    let case = get_refund_case("bridge::test_correct_tx_with_refund");
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_refund_not_recorded() {
+    let mut case = get_refund_case("bridge::test_tx_when_refund_not_recorded");
+    if let Witness::Mint(mint_witness) = &mut case.witnesses[0].witness {
+        mint_witness.version = MintWitnessVersion::V2;
+        mint_witness.refunds.clear();
+    }
+    case.expect_return_error_info = "minted sudt not match the mint records".to_string();
+    case_runner::run_test(case);
+}
+
+// A mint refunding a recipient cell nobody relayed: the owner consumes the cell, input 2, and
+// mints the 100 sUDT its burn took back to its refund lock, in the two sUDT outputs of the
+// correct case. Both the bridge lockscript and the recipient typescript run.
+fn get_refund_case(name: &str) -> TestCase {
+    let mut case = get_correct_case(name);
+    let recipient_typescript_bin = Loader::default().load_binary("recipient-typescript");
+    let bridge_lockscript_bin = Loader::default().load_binary("bridge-lockscript");
+    let owner_cell = case.owner_cell.as_mut().unwrap();
+    owner_cell.data = OwnerCellView {
+        recipient_typescript: Some(RecipientTypescriptConfig {
+            code_hash: CellOutput::calc_data_hash(&recipient_typescript_bin).unpack(),
+            hash_type: 0,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    let owner_cell_type_hash = owner_cell.typescript.calc_script_hash().unpack();
+
+    let refund_lockscript = case.sudt_cells.outputs[0]
+        .lockscript
+        .clone()
+        .as_builder()
+        .args(Bytes::from(vec![7u8]).pack())
+        .build();
+    for output in case.sudt_cells.outputs.iter_mut() {
+        output.lockscript = refund_lockscript.clone();
+    }
+    case.script_cells
+        .inputs
+        .push(CustomCell::RecipientCustomCell(RecipientCell {
+            capacity: 100 * CKB_UNITS,
+            data: RecipientDataView {
+                version: RecipientDataVersion::V2,
+                recipient_address: "5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+                chain: 1,
+                asset: "trx".to_string(),
+                bridge_lock_code_hash: CellOutput::calc_data_hash(&bridge_lockscript_bin).unpack(),
+                bridge_lock_hash_type: 0,
+                owner_cell_type_hash,
+                amount: 100,
+                // the case runner leaves the since of the inputs at 0.
+                refund: Some(RecipientRefund {
+                    lock_hash: refund_lockscript.calc_script_hash().unpack(),
+                    since: 0,
+                }),
+                memo: vec![],
+                evm_destination: None,
+            },
+            index: 2,
+        }));
+    if let Witness::Mint(mint_witness) = &mut case.witnesses[0].witness {
+        mint_witness.version = MintWitnessVersion::V4;
+        mint_witness.records.clear();
+        mint_witness.refunds = vec![RefundRecordView {
+            recipient_cell_index: 2,
+        }];
+    }
+    case.max_cycles = Some(5_000_000);
+    case
+}
+
+// The records of the correct case take fees of 2 and 1, and `collected` is minted to the fee
+// collector of the owner cell.
+fn get_mint_fee_case(name: &str, collected: u128) -> TestCase {
+    let mut case = get_correct_case(name);
+    let collector_lockscript = case.sudt_cells.outputs[0]
+        .lockscript
+        .clone()
+        .as_builder()
+        .args(Bytes::from(vec![9u8]).pack())
+        .build();
+    case.owner_cell.as_mut().unwrap().data = OwnerCellView {
+        mint_fee: Some(MintFeeConfig {
+            collector_lock_hash: collector_lockscript.calc_script_hash().unpack(),
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    if let Witness::Mint(mint_witness) = &mut case.witnesses[0].witness {
+        mint_witness.version = MintWitnessVersion::V3;
+        mint_witness.records[0].fee = 2;
+        mint_witness.records[1].fee = 1;
+    }
+    case.sudt_cells.outputs[0].amount -= 2;
+    case.sudt_cells.outputs[1].amount -= 1;
+    let collector_cell = SudtCell {
+        capacity: 100 * CKB_UNITS,
+        amount: collected,
+        lockscript: collector_lockscript,
+        owner_script: case.sudt_cells.outputs[0].owner_script.clone(),
+        index: 3,
+        sudt_extra_data: String::default(),
+    };
+    case.sudt_cells.outputs.push(collector_cell);
+    case
+}
+
+fn token_info_data() -> Vec<u8> {
+    TokenInfoView {
+        decimals: 8,
+        name: "Tron".to_string(),
+        symbol: "TRX".to_string(),
+    }
+    .as_bytes()
+}
+
+// A mint whose owner cell requires the token info cell of the minted sUDT.
+fn get_token_info_case(name: &str) -> TestCase {
+    let mut case = get_correct_case(name);
+    let token_info_bin = Loader::default().load_binary("token-info-typescript");
+    case.owner_cell.as_mut().unwrap().data = OwnerCellView {
+        token_info: Some(TokenInfoConfig {
+            code_hash: CellOutput::calc_data_hash(&token_info_bin).unpack(),
+            hash_type: 0,
+        }),
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec();
+    case
+}
+
+fn push_token_info_cell(case: &mut TestCase, data: Vec<u8>) {
+    let sudt_owner = case.sudt_cells.outputs[0].owner_script.clone();
+    case.script_cells
+        .outputs
+        .push(CustomCell::TokenInfoCustomCell(TokenInfoCell {
+            capacity: 100 * CKB_UNITS,
+            index: 3,
+            sudt_owner,
+            data,
+        }));
+}
+
+fn get_correct_case(name: &str) -> TestCase {
     let always_success_lockscript = Script::from_slice(&[
         53u8, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 230, 131, 176, 65, 57, 52, 71, 104,
         52, 132, 153, 194, 62, 177, 50, 109, 90, 82, 214, 219, 0, 108, 13, 47, 236, 224, 10, 131,
@@ -31,11 +316,34 @@ fn get_correct_case() -> TestCase {
 
     let owner_cell = OwnerCell {
         lockscript: always_success_lockscript.clone(),
-        typescript: always_success_lockscript.clone(),
+        typescript: type_id_script([2u8; 32]),
+        data: vec![],
     };
     let owner_cell_type_hash = owner_cell.typescript.calc_script_hash().unpack();
+    let recipient_lock_hash = always_success_lockscript.calc_script_hash().unpack();
+    let mint_witness = MintWitnessView {
+        version: MintWitnessVersion::V2,
+        records: vec![
+            MintRecordView {
+                lock_tx_hash: vec![1u8; 32],
+                log_index: 0,
+                amount: 92,
+                fee: 0,
+                recipient_lock_hash,
+            },
+            MintRecordView {
+                lock_tx_hash: vec![2u8; 32],
+                log_index: 0,
+                amount: 8,
+                fee: 0,
+                recipient_lock_hash,
+            },
+        ],
+        refunds: vec![],
+    };
 
     TestCase {
+        name: name.to_string(),
         cell_deps: vec![],
         owner_cell: Some(owner_cell),
         script_cells: CustomCells {
@@ -91,7 +399,15 @@ fn get_correct_case() -> TestCase {
             }],
             outputs: vec![],
         },
-        witnesses: vec![],
+        witnesses: vec![PlacedWitness::in_group(
+            ScriptGroup::Lock(ScriptView::build_sudt_owner(
+                1,
+                "trx".to_string(),
+                owner_cell_type_hash,
+            )),
+            Witness::Mint(mint_witness),
+        )],
         expect_return_error_info: String::default(),
+        max_cycles: Some(2_500_000),
     }
 }
@@ -0,0 +1,254 @@
+use crate::utils::case_builder::SIMPLE_UDT;
+use crate::{network_from_env, Loader, Network};
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_hash::blake2b_256;
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, ScriptHashType, TransactionBuilder, TransactionView},
+    packed::{CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+    H256,
+};
+use force_bridge_deployment::manifest::{
+    type_id_args, type_id_deploy_tx, type_id_script, Manifest, CONTRACTS, SUDT_DEP_KEY,
+};
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+fn first_input() -> CellInput {
+    let out_point = OutPoint::new_builder()
+        .tx_hash([7u8; 32].pack())
+        .index(3u32.pack())
+        .build();
+    CellInput::new(out_point, 0)
+}
+
+fn output(data: &Bytes) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(Capacity::bytes(data.len() + 200).unwrap().pack())
+        .build()
+}
+
+// outputs: [bridge-lockscript, recipient-typescript, token-info-typescript, sUDT], the contracts with their type ids.
+fn deploy_tx(loader: &Loader) -> TransactionView {
+    let binaries = CONTRACTS
+        .iter()
+        .map(|(name, _)| loader.load_binary(name))
+        .collect::<Vec<_>>();
+    let sudt = Bytes::from_static(SIMPLE_UDT);
+    type_id_deploy_tx(first_input(), &binaries, &Script::default())
+        .as_advanced_builder()
+        .output(output(&sudt))
+        .output_data(sudt.pack())
+        .build()
+}
+
+#[test]
+fn test_manifest_of_type_id_deployment() {
+    let loader = Loader::from_env();
+    let network = network_from_env();
+    let tx = deploy_tx(&loader);
+    let tx_hash: H256 = tx.hash().unpack();
+
+    let manifest = Manifest::generate(network, loader.path(), &tx, None).unwrap();
+
+    assert_eq!(manifest.network, network.name());
+    assert_eq!(manifest.deploy_tx_hash, tx_hash);
+    assert_eq!(manifest.types_config.feature, network.name());
+    assert_eq!(
+        manifest.types_config.sudt_code_hash,
+        network.sudt_code_hash().into()
+    );
+    assert_eq!(
+        manifest.types_config.sudt_hash_type,
+        network.sudt_hash_type()
+    );
+    for (index, (name, dep_key)) in CONTRACTS.iter().enumerate() {
+        let contract = &manifest.contracts[*name];
+        let type_id = type_id_script(type_id_args(&first_input(), index as u64));
+        let type_hash: H256 = type_id.calc_script_hash().unpack();
+        assert_eq!(contract.output_index, index as u32);
+        assert_eq!(
+            contract.data_hash,
+            blake2b_256(loader.load_binary(name)).into()
+        );
+        assert_eq!(contract.type_hash.as_ref(), Some(&type_hash));
+
+        let dep = &manifest.deps[*dep_key];
+        assert_eq!(dep.cell_dep.out_point.tx_hash, tx_hash);
+        assert_eq!(dep.cell_dep.out_point.index, format!("{:#x}", index));
+        assert_eq!(dep.script.code_hash, type_hash);
+        assert_eq!(dep.script.hash_type, "type");
+    }
+
+    let sudt = &manifest.deps[SUDT_DEP_KEY];
+    assert_eq!(sudt.script.code_hash, network.sudt_code_hash().into());
+    match network.sudt_out_point() {
+        Some((tx_hash, index)) => {
+            assert_eq!(sudt.cell_dep.out_point.tx_hash, tx_hash);
+            assert_eq!(sudt.cell_dep.out_point.index, format!("{:#x}", index));
+        }
+        None => {
+            assert_eq!(sudt.cell_dep.out_point.tx_hash, tx_hash);
+            assert_eq!(
+                sudt.cell_dep.out_point.index,
+                format!("{:#x}", CONTRACTS.len())
+            );
+            assert_eq!(sudt.script.hash_type, "data");
+        }
+    }
+}
+
+#[test]
+fn test_manifest_json_matches_offchain_config() {
+    let loader = Loader::from_env();
+    let tx = deploy_tx(&loader);
+    let manifest = Manifest::generate(network_from_env(), loader.path(), &tx, None).unwrap();
+
+    let json = serde_json::to_value(&manifest).unwrap();
+    let bridge_lock = &json["deps"]["bridgeLock"];
+    assert_eq!(bridge_lock["cellDep"]["depType"], "code");
+    assert_eq!(bridge_lock["cellDep"]["outPoint"]["index"], "0x0");
+    assert_eq!(bridge_lock["script"]["hashType"], "type");
+    assert!(bridge_lock["script"]["codeHash"]
+        .as_str()
+        .unwrap()
+        .starts_with("0x"));
+    assert_eq!(serde_json::from_value::<Manifest>(json).unwrap(), manifest);
+}
+
+#[test]
+fn test_manifest_code_hash_resolves_to_the_deployed_binary() {
+    let loader = Loader::from_env();
+    let tx = deploy_tx(&loader);
+    let manifest = Manifest::generate(network_from_env(), loader.path(), &tx, None).unwrap();
+
+    let mut context = Context::default();
+    for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+        context.create_cell_with_out_point(OutPoint::new(tx.hash(), index as u32), output, data);
+    }
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+
+    // destroying a recipient cell always passes, once the typescript is found by its type hash.
+    let recipient_type = &manifest.deps["recipientType"];
+    let recipient_typescript = Script::new_builder()
+        .code_hash(recipient_type.script.code_hash.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .build();
+    let recipient_cell = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(500u64.pack())
+            .lock(always_success_lock.clone())
+            .type_(Some(recipient_typescript).pack())
+            .build(),
+        Bytes::new(),
+    );
+    let tx = TransactionBuilder::default()
+        .input(CellInput::new(recipient_cell, 0))
+        .output(
+            CellOutput::new_builder()
+                .capacity(500u64.pack())
+                .lock(always_success_lock)
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(OutPoint::new(
+                    recipient_type.cell_dep.out_point.tx_hash.pack(),
+                    manifest.contracts["recipient-typescript"].output_index,
+                ))
+                .build(),
+        )
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_manifest_rejects_type_script_other_than_type_id() {
+    let loader = Loader::from_env();
+    let tx = deploy_tx(&loader);
+    let wrong_type_id = type_id_script(type_id_args(&first_input(), 5));
+    let outputs = tx
+        .outputs()
+        .into_iter()
+        .enumerate()
+        .map(|(index, output)| match index {
+            1 => output
+                .as_builder()
+                .type_(Some(wrong_type_id.clone()).pack())
+                .build(),
+            _ => output,
+        })
+        .collect::<Vec<_>>();
+    let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+
+    let err = Manifest::generate(network_from_env(), loader.path(), &tx, None).unwrap_err();
+    assert_eq!(
+        err,
+        "type script of recipient-typescript at output 1 is not its type id"
+    );
+}
+
+#[test]
+fn test_manifest_rejects_tx_missing_a_contract() {
+    let loader = Loader::from_env();
+    let tx = deploy_tx(&loader);
+    let outputs = tx.outputs().into_iter().take(1).collect::<Vec<_>>();
+    let outputs_data = tx.outputs_data().into_iter().take(1).collect::<Vec<_>>();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(outputs)
+        .set_outputs_data(outputs_data)
+        .build();
+
+    let err = Manifest::generate(network_from_env(), loader.path(), &tx, None).unwrap_err();
+    assert!(
+        err.ends_with("recipient-typescript is not deployed by the tx"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_manifest_of_data_hash_deployment_needs_the_devnet_sudt() {
+    let loader = Loader::from_env();
+    // outputs: [bridge-lockscript, recipient-typescript, token-info-typescript], referenced by
+    // data hash.
+    let mut builder = TransactionBuilder::default().input(first_input());
+    for (name, _) in CONTRACTS.iter() {
+        let binary = loader.load_binary(name);
+        builder = builder.output(output(&binary)).output_data(binary.pack());
+    }
+    let tx = builder.build();
+
+    let err = Manifest::generate(Network::Devnet, loader.path(), &tx, None).unwrap_err();
+    assert_eq!(
+        err,
+        "the sUDT is not deployed by the tx, pass its out point"
+    );
+
+    let sudt_out_point = ([9u8; 32].into(), 1);
+    let manifest =
+        Manifest::generate(Network::Devnet, loader.path(), &tx, Some(sudt_out_point)).unwrap();
+    for (name, dep_key) in CONTRACTS.iter() {
+        let contract = &manifest.contracts[*name];
+        assert_eq!(contract.type_id_args, None);
+        let dep = &manifest.deps[*dep_key];
+        assert_eq!(dep.script.code_hash, contract.data_hash);
+        assert_eq!(dep.script.hash_type, "data");
+    }
+    let sudt = &manifest.deps[SUDT_DEP_KEY];
+    assert_eq!(sudt.cell_dep.out_point.tx_hash, [9u8; 32].into());
+    assert_eq!(sudt.cell_dep.out_point.index, "0x1");
+}
@@ -0,0 +1,14 @@
+use crate::utils::{case_runner, fixture::Fixture};
+use std::path::Path;
+
+const CASES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/cases");
+
+#[test]
+fn test_fixture_cases() {
+    for path in Fixture::list(Path::new(CASES_DIR)) {
+        let fixture = Fixture::load(&path);
+        println!("run fixture {}: {}", path.display(), fixture.description);
+        let name = format!("fixtures::{}", path.file_stem().unwrap().to_string_lossy());
+        case_runner::run_test(fixture.into_test_case(name));
+    }
+}
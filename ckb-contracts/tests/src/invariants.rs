@@ -0,0 +1,44 @@
+use crate::utils::{case_runner, strategies::*};
+use proptest::prelude::*;
+use proptest::test_runner::Config;
+use std::env;
+
+// every case runs the contracts in ckb-vm, keep the default run short. `PROPTEST_CASES`
+// still sets the number of cases for a longer run.
+fn config() -> Config {
+    let mut config = Config::default();
+    if env::var_os("PROPTEST_CASES").is_none() {
+        config.cases = 16;
+    }
+    config
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn test_burn_passes_iff_burned_sum_equals_amount(tx in burn_tx()) {
+        let mut case = build_burn_case("invariants::burn", &tx);
+        case.expect_return_error_info = if tx.amount == 0 {
+            "burn amount should be greater than 0".to_string()
+        } else if tx.burned() != tx.amount {
+            "burned token amount not match data amount".to_string()
+        } else {
+            String::default()
+        };
+        case_runner::run_test(case);
+    }
+
+    #[test]
+    fn test_bridge_cell_never_unlocks_without_owner(tx in mint_tx()) {
+        let mut case = build_mint_case("invariants::mint", &tx);
+        case.expect_return_error_info = if !tx.owner_cell_type_hash_matches {
+            "owner cell not found".to_string()
+        } else if !tx.owner_in_inputs {
+            "not authorized to unlock the cell".to_string()
+        } else {
+            String::default()
+        };
+        case_runner::run_test(case);
+    }
+}
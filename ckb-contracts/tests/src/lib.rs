@@ -1,19 +1,45 @@
-use ckb_tool::ckb_types::bytes::Bytes;
+use ckb_tool::ckb_types::{bytes::Bytes, prelude::Unpack};
+use force_bridge_deployment::manifest::type_id_script;
+pub use force_bridge_deployment::Network;
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::Once;
 
 #[cfg(test)]
 #[cfg(not(tarpaulin))]
 mod bridge;
 #[cfg(test)]
 #[cfg(not(tarpaulin))]
+mod deployment;
+#[cfg(test)]
+#[cfg(not(tarpaulin))]
+mod fixtures;
+#[cfg(test)]
+#[cfg(not(tarpaulin))]
+mod invariants;
+#[cfg(test)]
+mod networks;
+#[cfg(test)]
+#[cfg(not(tarpaulin))]
 mod recipient_typescript;
 #[cfg(test)]
+#[cfg(not(tarpaulin))]
+mod upgrade;
+#[cfg(test)]
 mod utils;
 
 const TEST_ENV_VAR: &str = "CAPSULE_TEST_ENV";
+const TEST_NETWORK_VAR: &str = "FORCE_BRIDGE_TEST_NETWORK";
+
+// only the recipient and the token info typescripts check the sUDT code hash.
+const SUDT_CHECKING_BINARIES: [&str; 2] = ["recipient-typescript", "token-info-typescript"];
+
+// The type id args of the public sUDT cells derive from the inputs of their deploy txs, which
+// this repo does not record, so the test context deploys the sUDT of aggron and lina under a
+// type id of its own, see `context_sudt_code_hash`.
+const CONTEXT_SUDT_TYPE_ID_ARGS: [u8; 32] = [0x5d; 32];
 
 pub enum TestEnv {
     Debug,
@@ -32,31 +58,96 @@ impl FromStr for TestEnv {
     }
 }
 
+/// The network of the builds under test, devnet unless set by `FORCE_BRIDGE_TEST_NETWORK`.
+pub fn network_from_env() -> Network {
+    match env::var(TEST_NETWORK_VAR) {
+        Ok(val) => val.parse().expect("test network"),
+        Err(_) => Network::Devnet,
+    }
+}
+
+/// The type id args of the sUDT cell the test context deploys for `network`, none when the
+/// network references the sUDT by data hash.
+pub fn context_sudt_type_id_args(network: Network) -> Option<[u8; 32]> {
+    match network.sudt_hash_type() {
+        0 => None,
+        _ => Some(CONTEXT_SUDT_TYPE_ID_ARGS),
+    }
+}
+
+/// The sUDT code hash the contracts run by the tests reference: the one of `network`, or the
+/// type hash of the sUDT cell the test context deploys, which `Loader::default` relinks the
+/// builds of the network to.
+pub fn context_sudt_code_hash(network: Network) -> [u8; 32] {
+    match context_sudt_type_id_args(network) {
+        Some(args) => type_id_script(args).calc_script_hash().unpack(),
+        None => network.sudt_code_hash(),
+    }
+}
+
 pub struct Loader(PathBuf);
 
+// The build of `CAPSULE_TEST_ENV`, relinked to the sUDT of the test context.
 impl Default for Loader {
     fn default() -> Self {
+        let network = network_from_env();
+        let loader = Self::from_env();
+        if context_sudt_code_hash(network) == network.sudt_code_hash() {
+            return loader;
+        }
+        let relinked = Loader(env::temp_dir().join(format!("force-bridge-{}", network.name())));
+        static RELINK: Once = Once::new();
+        RELINK.call_once(|| loader.relink(network, relinked.path()));
+        relinked
+    }
+}
+
+impl Loader {
+    /// The build of `CAPSULE_TEST_ENV` as it is deployed, referencing the sUDT of its network.
+    pub fn from_env() -> Self {
         let test_env = match env::var(TEST_ENV_VAR) {
             Ok(val) => val.parse().expect("test env"),
             Err(_) => TestEnv::Debug,
         };
-        Self::with_test_env(test_env)
+        Self::with_test_env(test_env, network_from_env())
     }
-}
 
-impl Loader {
-    fn with_test_env(env: TestEnv) -> Self {
-        let load_prefix = match env {
-            TestEnv::Debug => "debug",
-            TestEnv::Release => "release",
+    /// Debug builds are always devnet builds. Release builds of each network are kept in
+    /// `build/release-<network>` by `make build-release`, a plain `capsule build --release`
+    /// writes to `build/release`.
+    pub fn with_test_env(env: TestEnv, network: Network) -> Self {
+        let build_path = Self::build_path();
+        let path = match env {
+            TestEnv::Debug => build_path.join("debug"),
+            TestEnv::Release => {
+                let network_path = build_path.join(format!("release-{}", network.name()));
+                if network_path.exists() || network != Network::Devnet {
+                    network_path
+                } else {
+                    build_path.join("release")
+                }
+            }
         };
+        Loader(path)
+    }
+
+    /// The devnet debug build of the contracts at `PREVIOUS_REF`, written by
+    /// `make build-previous`. None until it is built.
+    pub fn previous() -> Option<Self> {
+        let path = Self::build_path().join("previous").join("debug");
+        if !path.exists() {
+            return None;
+        }
+        Some(Loader(path))
+    }
+
+    fn build_path() -> PathBuf {
         let dir = env::current_dir().unwrap();
-        let mut base_path = PathBuf::new();
-        base_path.push(dir);
-        base_path.push("..");
-        base_path.push("build");
-        base_path.push(load_prefix);
-        Loader(base_path)
+        dir.join("..").join("build")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
     }
 
     pub fn load_binary(&self, name: &str) -> Bytes {
@@ -64,4 +155,35 @@ impl Loader {
         path.push(name);
         fs::read(path).expect("binary").into()
     }
+
+    // Copies the build to `dir`, with the sUDT code hash of the network replaced by the one of
+    // the test context.
+    fn relink(&self, network: Network, dir: &Path) {
+        let code_hash = network.sudt_code_hash();
+        let context_code_hash = context_sudt_code_hash(network);
+        fs::create_dir_all(dir).expect("relinked build dir");
+        for entry in fs::read_dir(&self.0).expect("build dir") {
+            let path = entry.expect("build dir").path();
+            let name = path.file_name().unwrap();
+            let mut binary = fs::read(&path).expect("binary");
+            let mut relinked = 0;
+            let mut start = 0;
+            while let Some(offset) = binary[start..]
+                .windows(code_hash.len())
+                .position(|window| window == code_hash)
+            {
+                start += offset;
+                binary[start..start + code_hash.len()].copy_from_slice(&context_code_hash);
+                start += code_hash.len();
+                relinked += 1;
+            }
+            assert!(
+                relinked > 0 || !SUDT_CHECKING_BINARIES.iter().any(|binary| name == *binary),
+                "{} does not reference the {} sUDT",
+                path.display(),
+                network.name()
+            );
+            fs::write(dir.join(name), binary).expect("relinked binary");
+        }
+    }
 }
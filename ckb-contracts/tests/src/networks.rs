@@ -0,0 +1,40 @@
+use crate::{Loader, Network, TestEnv, SUDT_CHECKING_BINARIES};
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+#[test]
+fn test_builds_embed_the_sudt_code_hash_of_their_network() {
+    let mut builds = vec![(
+        Network::Devnet,
+        Loader::with_test_env(TestEnv::Debug, Network::Devnet),
+    )];
+    for network in Network::ALL.iter() {
+        let loader = Loader::with_test_env(TestEnv::Release, *network);
+        if loader.path().exists() {
+            builds.push((*network, loader));
+        }
+    }
+
+    for (network, loader) in builds.iter() {
+        for name in SUDT_CHECKING_BINARIES.iter() {
+            let binary = loader.load_binary(name);
+            for other in Network::ALL.iter() {
+                let embedded = contains(&binary, &other.sudt_code_hash());
+                assert_eq!(
+                    embedded,
+                    other == network,
+                    "{} of the {} build at {} {} the {} sUDT code hash",
+                    name,
+                    network.name(),
+                    loader.path().display(),
+                    if embedded { "embeds" } else { "does not embed" },
+                    other.name()
+                );
+            }
+        }
+    }
+}
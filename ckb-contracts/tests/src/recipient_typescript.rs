@@ -1,26 +1,204 @@
 use crate::utils::{case_builder::*, case_runner};
 use crate::Loader;
+use ckb_tool::ckb_types::core::ScriptHashType;
 use ckb_tool::ckb_types::packed::CellOutput;
-use ckb_tool::ckb_types::packed::Script;
+use ckb_tool::ckb_types::packed::{Script, WitnessArgs};
+use ckb_tool::ckb_types::prelude::Builder;
+use ckb_tool::ckb_types::prelude::Unpack;
+use force_bridge_deployment::manifest::type_id_script;
 use force_bridge_types::config::CKB_UNITS;
-use force_bridge_types::recipient_cell::RecipientDataView;
-use molecule::prelude::Entity;
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::owner_cell::{AssetDecimalsView, BridgedAssetView, OwnerCellView};
+use force_bridge_types::recipient_cell::{RecipientDataVersion, RecipientDataView};
+use molecule::prelude::Entity as _;
 
 #[test]
 fn test_correct_tx() {
-    let case = get_correct_case();
+    let case = get_correct_case("recipient_typescript::test_correct_tx");
     case_runner::run_test(case);
 }
 
 #[test]
 fn test_tx_when_burned_amount_not_match_data_amount() {
-    let mut case = get_correct_case();
+    let mut case =
+        get_correct_case("recipient_typescript::test_tx_when_burned_amount_not_match_data_amount");
     case.sudt_cells.inputs[0].amount = 300;
     case.expect_return_error_info = "burned token amount not match data amount".to_string();
     case_runner::run_test(case);
 }
 
-fn get_correct_case() -> TestCase {
+#[test]
+fn test_correct_tx_with_output_type_witness() {
+    let mut case =
+        get_correct_case("recipient_typescript::test_correct_tx_with_output_type_witness");
+    let witness_args = WitnessArgs::new_builder()
+        .output_type(bytes_opt(Some(Bytes::from(vec![1u8; 8]))))
+        .build();
+    case.witnesses = vec![PlacedWitness::in_group(
+        ScriptGroup::Type(ScriptView {
+            outpoint_key: RECIPIENT_TYPESCRIPT_OUTPOINT_KEY,
+            args: Default::default(),
+        }),
+        Witness::WitnessArgs(witness_args),
+    )];
+    case_runner::run_test(case);
+}
+
+// trx has 18 decimals, its sUDT 8, so the 100 sUDT burned are 100 * 10^10 trx.
+fn owner_cell_data_with_decimals() -> Vec<u8> {
+    OwnerCellView {
+        asset_decimals: vec![AssetDecimalsView {
+            bridged_asset: BridgedAssetView {
+                chain: 1,
+                asset: b"trx".to_vec(),
+                evm_destination: None,
+            },
+            decimals: AssetDecimals {
+                xchain_decimals: 18,
+                sudt_decimals: 8,
+            },
+        }],
+        ..Default::default()
+    }
+    .as_molecule_data()
+    .to_vec()
+}
+
+fn get_decimals_case(name: &str, amount: u128) -> TestCase {
+    let mut case = get_correct_case(name);
+    case.owner_cell.as_mut().unwrap().data = owner_cell_data_with_decimals();
+    if let CustomCell::RecipientCustomCell(cell) = &mut case.script_cells.outputs[0] {
+        cell.data.version = RecipientDataVersion::V5;
+        cell.data.amount = amount;
+    }
+    case
+}
+
+#[test]
+fn test_correct_tx_with_decimals() {
+    let case = get_decimals_case(
+        "recipient_typescript::test_correct_tx_with_decimals",
+        100 * 10u128.pow(10),
+    );
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_burn_amount_loses_dust() {
+    let mut case = get_decimals_case(
+        "recipient_typescript::test_tx_when_burn_amount_loses_dust",
+        100 * 10u128.pow(10) + 1,
+    );
+    case.expect_return_error_info = "burn amount loses dust".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_owner_cell_not_in_cell_deps() {
+    // the amount of V1 data is the sUDT burned, a burn of it needs no owner cell.
+    let mut case =
+        get_correct_case("recipient_typescript::test_tx_when_owner_cell_not_in_cell_deps");
+    case.owner_cell = None;
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_in_xchain_units_when_owner_cell_not_in_cell_deps() {
+    let mut case = get_decimals_case(
+        "recipient_typescript::test_tx_in_xchain_units_when_owner_cell_not_in_cell_deps",
+        100 * 10u128.pow(10),
+    );
+    case.owner_cell = None;
+    case.expect_return_error_info = "owner cell not found".to_string();
+    case_runner::run_test(case);
+}
+
+// Burns 100 out of `cells` sUDT inputs of 10, giving back the rest in sUDT outputs of 10, with
+// as many sUDT cells of another bridge on each side. Every sUDT cell carries 1 KB of extra data
+// the recipient typescript has no use for.
+#[test]
+fn test_tx_when_owner_cell_not_unique() {
+    let mut case = get_correct_case("recipient_typescript::test_tx_when_owner_cell_not_unique");
+    // a cell of the owner type the tx destroys, besides the owner cell in the cell deps.
+    let owner_cell = case.owner_cell.clone().unwrap();
+    case.script_cells
+        .inputs
+        .push(CustomCell::OwnerCustomCell(OwnerTxCell {
+            capacity: 100 * CKB_UNITS,
+            index: 2,
+            cell: owner_cell,
+        }));
+    case.expect_return_error_info = "owner cell is not unique".to_string();
+    case_runner::run_test(case);
+}
+
+#[test]
+fn test_tx_when_owner_cell_not_type_id() {
+    let owner_typescript = type_id_script([2u8; 32])
+        .as_builder()
+        .hash_type(ScriptHashType::Data.into())
+        .build();
+    let mut case = get_case_with_owner_typescript(
+        "recipient_typescript::test_tx_when_owner_cell_not_type_id",
+        owner_typescript,
+    );
+    case.expect_return_error_info = "owner cell type is not a type id".to_string();
+    case_runner::run_test(case);
+}
+
+fn get_many_cells_case(name: &str, cells: usize) -> TestCase {
+    let mut case = get_correct_case(name);
+    let owner_cell_type_hash = case
+        .owner_cell
+        .as_ref()
+        .unwrap()
+        .typescript
+        .calc_script_hash();
+    let lockscript = case.sudt_cells.inputs[0].lockscript.clone();
+    let sudt_cell = |index: usize, asset: &str| SudtCell {
+        capacity: 2000 * CKB_UNITS,
+        amount: 10,
+        lockscript: lockscript.clone(),
+        owner_script: ScriptView::build_sudt_owner(
+            1,
+            asset.to_string(),
+            owner_cell_type_hash.unpack(),
+        ),
+        index,
+        sudt_extra_data: "x".repeat(1024),
+    };
+    case.sudt_cells.inputs = (0..cells)
+        .flat_map(|cell| {
+            vec![
+                sudt_cell(2 * cell + 1, "trx"),
+                sudt_cell(2 * cell + 2, "eth"),
+            ]
+        })
+        .collect();
+    case.sudt_cells.outputs = (0..cells)
+        .map(|cell| sudt_cell(cell + 1, "eth"))
+        .chain((0..cells - 10).map(|cell| sudt_cell(cells + cell + 1, "trx")))
+        .collect();
+    case
+}
+
+#[test]
+fn test_cycles_of_burn_with_many_sudt_cells() {
+    let mut case = get_many_cells_case(
+        "recipient_typescript::test_cycles_of_burn_with_many_sudt_cells",
+        50,
+    );
+    // about 3.1M, it took 67M when the typescript loaded the type script and the whole data of
+    // every cell.
+    case.max_cycles = Some(5_000_000);
+    case_runner::run_test(case);
+}
+
+fn get_correct_case(name: &str) -> TestCase {
+    get_case_with_owner_typescript(name, type_id_script([2u8; 32]))
+}
+
+fn get_case_with_owner_typescript(name: &str, owner_typescript: Script) -> TestCase {
     let data = Loader::default().load_binary("bridge-lockscript");
     let data_hash = CellOutput::calc_data_hash(&data);
     let mut lock_hash = [0u8; 32];
@@ -32,21 +210,32 @@ fn get_correct_case() -> TestCase {
         31, 54, 96, 215, 0, 0, 0, 0, 0,
     ])
     .unwrap();
+    let owner_cell = OwnerCell {
+        lockscript: always_success_lockscript.clone(),
+        typescript: owner_typescript,
+        data: vec![],
+    };
+    let owner_cell_type_hash = owner_cell.typescript.calc_script_hash().unpack();
     TestCase {
+        name: name.to_string(),
         cell_deps: vec![],
-        owner_cell: None,
+        owner_cell: Some(owner_cell),
         script_cells: CustomCells {
             inputs: vec![],
             outputs: vec![CustomCell::RecipientCustomCell(RecipientCell {
                 capacity: 100 * CKB_UNITS,
                 data: RecipientDataView {
+                    version: RecipientDataVersion::V1,
                     recipient_address: "5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
                     chain: 1,
                     asset: "trx".to_string(),
                     bridge_lock_code_hash: lock_hash,
                     bridge_lock_hash_type: 0,
-                    owner_cell_type_hash: [0u8; 32],
+                    owner_cell_type_hash,
                     amount: 100,
+                    refund: None,
+                    memo: vec![],
+                    evm_destination: None,
                 },
                 index: 0,
             })],
@@ -56,7 +245,11 @@ fn get_correct_case() -> TestCase {
                 capacity: 100 * CKB_UNITS,
                 amount: 200,
                 lockscript: always_success_lockscript.clone(),
-                owner_script: ScriptView::build_sudt_owner(1, "trx".to_string(), [0u8; 32]),
+                owner_script: ScriptView::build_sudt_owner(
+                    1,
+                    "trx".to_string(),
+                    owner_cell_type_hash,
+                ),
                 index: 1,
                 sudt_extra_data: Default::default(),
             }],
@@ -64,7 +257,11 @@ fn get_correct_case() -> TestCase {
                 capacity: 100 * CKB_UNITS,
                 amount: 100,
                 lockscript: always_success_lockscript,
-                owner_script: ScriptView::build_sudt_owner(1, "trx".to_string(), [0u8; 32]),
+                owner_script: ScriptView::build_sudt_owner(
+                    1,
+                    "trx".to_string(),
+                    owner_cell_type_hash,
+                ),
                 index: 1,
                 sudt_extra_data: Default::default(),
             }],
@@ -79,5 +276,6 @@ fn get_correct_case() -> TestCase {
         },
         witnesses: vec![],
         expect_return_error_info: String::default(),
+        max_cycles: Some(3_000_000),
     }
 }
@@ -0,0 +1,491 @@
+use crate::utils::case_builder::{build_force_bridge_lock_args, Witness};
+use crate::utils::case_runner::deploy_sudt;
+use crate::{Loader, Network, TestEnv};
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, TransactionBuilder, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use force_bridge_deployment::manifest::{
+    type_id_deploy_tx, type_id_script, type_id_upgrade_tx, Manifest, CONTRACTS,
+};
+use force_bridge_types::config::CKB_UNITS;
+use force_bridge_types::mint_witness::{MintRecordView, MintWitnessVersion, MintWitnessView};
+use force_bridge_types::recipient_cell::{RecipientDataVersion, RecipientDataView};
+
+const MAX_CYCLES: u64 = 10_000_000;
+
+// The upgrades deploy the contracts of `make build-previous`, which predate versioned data and the
+// token info typescript, and replace them with the current ones. The previous build is a devnet
+// debug build, so the current one is too, whatever the network under test.
+const NETWORK: Network = Network::Devnet;
+
+fn current_build() -> Loader {
+    Loader::with_test_env(TestEnv::Debug, NETWORK)
+}
+
+// the previous build, none and a note to build it when a plain `cargo test` runs without it.
+fn previous_build() -> Option<Loader> {
+    let previous = Loader::previous();
+    if previous.is_none() {
+        eprintln!("upgrade test skipped, run `make build-previous` first");
+    }
+    previous
+}
+
+/// The test context as a chain, a verified tx spends its inputs and creates its outputs.
+struct Chain {
+    context: Context,
+    always_success: Script,
+    always_success_dep: CellDep,
+    sudt_dep: CellDep,
+}
+
+impl Chain {
+    fn new() -> Self {
+        let mut context = Context::default();
+        context.set_capture_debug(true);
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let always_success = context
+            .build_script(&always_success_out_point, Default::default())
+            .unwrap();
+        let sudt_out_point = deploy_sudt(&mut context, NETWORK);
+        Chain {
+            context,
+            always_success,
+            always_success_dep: cell_dep(always_success_out_point),
+            sudt_dep: cell_dep(sudt_out_point),
+        }
+    }
+
+    /// A live cell no tx of the test created.
+    fn genesis_cell(&mut self, output: CellOutput, data: Bytes) -> CellInput {
+        CellInput::new(self.context.create_cell(output, data), 0)
+    }
+
+    fn with_always_success(&self, tx: TransactionView) -> TransactionView {
+        tx.as_advanced_builder()
+            .cell_dep(self.always_success_dep.clone())
+            .build()
+    }
+
+    fn assert_rejected(&mut self, tx: TransactionView, message: &str) {
+        let tx = self.with_always_success(tx);
+        assert!(self.context.verify_tx(&tx, MAX_CYCLES).is_err());
+        assert!(
+            self.context
+                .captured_messages()
+                .iter()
+                .any(|captured| captured.message.contains(message)),
+            "{:?} not in {:?}",
+            message,
+            self.context.captured_messages()
+        );
+    }
+
+    fn commit(&mut self, tx: TransactionView) -> TransactionView {
+        let tx = self.with_always_success(tx);
+        if let Err(err) = self.context.verify_tx(&tx, MAX_CYCLES) {
+            panic!(
+                "tx rejected, {:?}, messages {:?}",
+                err,
+                self.context.captured_messages()
+            );
+        }
+        for input in tx.inputs() {
+            self.context.cells.remove(&input.previous_output());
+        }
+        for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+            self.context.create_cell_with_out_point(
+                OutPoint::new(tx.hash(), index as u32),
+                output,
+                data,
+            );
+        }
+        tx
+    }
+}
+
+fn cell_dep(out_point: OutPoint) -> CellDep {
+    CellDep::new_builder().out_point(out_point).build()
+}
+
+fn manifest_dep(manifest: &Manifest, dep_key: &str) -> CellDep {
+    let out_point = &manifest.deps[dep_key].cell_dep.out_point;
+    let index = u32::from_str_radix(out_point.index.trim_start_matches("0x"), 16).unwrap();
+    cell_dep(OutPoint::new(out_point.tx_hash.pack(), index))
+}
+
+fn capacity_cell(lock: &Script) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity((200 * CKB_UNITS).pack())
+        .lock(lock.clone())
+        .build()
+}
+
+fn sudt_cell(lock: &Script, sudt_type: &Script, amount: u128) -> (CellOutput, Bytes) {
+    let output = CellOutput::new_builder()
+        .capacity((200 * CKB_UNITS).pack())
+        .lock(lock.clone())
+        .type_(Some(sudt_type.clone()).pack())
+        .build();
+    (output, amount.to_le_bytes().to_vec().into())
+}
+
+// the binaries of the contracts the build has, in the order of `CONTRACTS`.
+fn binaries(loader: &Loader) -> Vec<Bytes> {
+    CONTRACTS
+        .iter()
+        .filter(|(name, _)| loader.path().join(name).exists())
+        .map(|(name, _)| loader.load_binary(name))
+        .collect()
+}
+
+fn deploy(chain: &mut Chain, loader: &Loader) -> (TransactionView, Manifest) {
+    let binaries = binaries(loader);
+    let always_success = chain.always_success.clone();
+    let first_input = chain.genesis_cell(capacity_cell(&always_success), Bytes::new());
+    let deploy_tx = chain.commit(type_id_deploy_tx(first_input, &binaries, &always_success));
+    let sudt_out_point = chain.sudt_dep.out_point();
+    let manifest = Manifest::generate(
+        NETWORK,
+        loader.path(),
+        &deploy_tx,
+        Some((
+            sudt_out_point.tx_hash().unpack(),
+            sudt_out_point.index().unpack(),
+        )),
+    )
+    .unwrap();
+    (deploy_tx, manifest)
+}
+
+// outputs: [bridge-lockscript, recipient-typescript], the binaries of the current build replacing
+// the ones of the deploy tx.
+fn upgrade_tx(deploy_tx: &TransactionView, binaries: &[Bytes]) -> TransactionView {
+    let upgrades = (0..2)
+        .map(|index| {
+            (
+                OutPoint::new(deploy_tx.hash(), index as u32),
+                deploy_tx.outputs().get(index).unwrap(),
+                binaries[index].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    type_id_upgrade_tx(&upgrades)
+}
+
+/// The bridge of trx on chain 1 and the cells its scripts need, as deployed by a manifest.
+struct Bridge {
+    owner_cell_type_hash: [u8; 32],
+    owner_dep: CellDep,
+}
+
+impl Bridge {
+    fn new(chain: &mut Chain) -> Self {
+        let owner_typescript = type_id_script([4u8; 32]);
+        let owner_cell = CellOutput::new_builder()
+            .capacity((200 * CKB_UNITS).pack())
+            .lock(chain.always_success.clone())
+            .type_(Some(owner_typescript.clone()).pack())
+            .build();
+        let owner_out_point = chain.context.create_cell(owner_cell, Bytes::new());
+        Bridge {
+            owner_cell_type_hash: owner_typescript.calc_script_hash().unpack(),
+            owner_dep: cell_dep(owner_out_point),
+        }
+    }
+
+    fn bridge_lock(&self, manifest: &Manifest) -> Script {
+        let args = build_force_bridge_lock_args(1, "trx".to_string(), self.owner_cell_type_hash);
+        manifest.contracts["bridge-lockscript"].script(args)
+    }
+
+    fn sudt_type(&self, manifest: &Manifest) -> Script {
+        Script::new_builder()
+            .code_hash(NETWORK.sudt_code_hash().pack())
+            .hash_type(NETWORK.sudt_hash_type().into())
+            .args(
+                self.bridge_lock(manifest)
+                    .calc_script_hash()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build()
+    }
+
+    fn recipient_cell(
+        &self,
+        chain: &Chain,
+        manifest: &Manifest,
+        amount: u128,
+        memo: &[u8],
+    ) -> CellOutput {
+        let data = self.recipient_data(manifest, amount, memo);
+        CellOutput::new_builder()
+            .lock(chain.always_success.clone())
+            .type_(Some(manifest.contracts["recipient-typescript"].script(Bytes::new())).pack())
+            .build_exact_capacity(Capacity::bytes(data.len()).unwrap())
+            .unwrap()
+    }
+
+    // V1 data, the only version the previous recipient typescript reads, or V3 data with `memo`.
+    fn recipient_data(&self, manifest: &Manifest, amount: u128, memo: &[u8]) -> Bytes {
+        let bridge_lock = self.bridge_lock(manifest);
+        let mut bridge_lock_code_hash = [0u8; 32];
+        bridge_lock_code_hash.copy_from_slice(bridge_lock.code_hash().as_slice());
+        RecipientDataView {
+            version: match memo {
+                [] => RecipientDataVersion::V1,
+                _ => RecipientDataVersion::V3,
+            },
+            recipient_address: "5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+            chain: 1,
+            asset: "trx".to_string(),
+            bridge_lock_code_hash,
+            bridge_lock_hash_type: bridge_lock.hash_type().into(),
+            owner_cell_type_hash: self.owner_cell_type_hash,
+            amount,
+            refund: None,
+            memo: memo.to_vec(),
+            evm_destination: None,
+        }
+        .as_molecule_data()
+        .unwrap()
+    }
+
+    fn cell_deps(&self, chain: &Chain, manifest: &Manifest) -> Vec<CellDep> {
+        vec![
+            manifest_dep(manifest, "bridgeLock"),
+            manifest_dep(manifest, "recipientType"),
+            chain.sudt_dep.clone(),
+            self.owner_dep.clone(),
+        ]
+    }
+
+    // burns `amount` of the sUDT cell `sudt_input` holding `balance`, into a recipient cell at
+    // output 0 with `memo`.
+    fn burn_tx(
+        &self,
+        chain: &Chain,
+        manifest: &Manifest,
+        sudt_input: CellInput,
+        balance: u128,
+        amount: u128,
+        memo: &[u8],
+    ) -> TransactionView {
+        let (sudt_output, sudt_data) = sudt_cell(
+            &chain.always_success,
+            &self.sudt_type(manifest),
+            balance - amount,
+        );
+        TransactionBuilder::default()
+            .cell_deps(self.cell_deps(chain, manifest))
+            .input(sudt_input)
+            .output(self.recipient_cell(chain, manifest, amount, memo))
+            .output_data(self.recipient_data(manifest, amount, memo).pack())
+            .output(sudt_output)
+            .output_data(sudt_data.pack())
+            .build()
+    }
+
+    // unlocks `bridge_cell` with the owner, recreating it and minting 100 sUDT to the always
+    // success lock, with the legacy witness the relayer writes.
+    fn mint_tx(
+        &self,
+        chain: &mut Chain,
+        manifest: &Manifest,
+        bridge_cell: CellInput,
+    ) -> TransactionView {
+        let owner_input = chain.genesis_cell(capacity_cell(&chain.always_success), Bytes::new());
+        let (sudt_output, sudt_data) =
+            sudt_cell(&chain.always_success, &self.sudt_type(manifest), 100);
+        let mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V1,
+            records: vec![MintRecordView {
+                lock_tx_hash: vec![1u8; 32],
+                log_index: 0,
+                amount: 0,
+                fee: 0,
+                recipient_lock_hash: [0u8; 32],
+            }],
+            refunds: vec![],
+        };
+        TransactionBuilder::default()
+            .cell_deps(self.cell_deps(chain, manifest))
+            .input(bridge_cell)
+            .input(owner_input)
+            .output(capacity_cell(&self.bridge_lock(manifest)))
+            .output_data(Bytes::new().pack())
+            .output(sudt_output)
+            .output_data(sudt_data.pack())
+            .witness(Witness::Mint(mint_witness).as_bytes().pack())
+            .build()
+    }
+}
+
+#[test]
+fn test_upgrade_keeps_sudt_type_hashes_and_recipient_cells() {
+    let previous = match previous_build() {
+        Some(previous) => previous,
+        None => return,
+    };
+    let current = current_build();
+    let mut chain = Chain::new();
+    let (deploy_tx, manifest) = deploy(&mut chain, &previous);
+    assert!(!manifest.contracts.contains_key("token-info-typescript"));
+    let bridge = Bridge::new(&mut chain);
+    let sudt_type_hash = bridge.sudt_type(&manifest).calc_script_hash();
+
+    // before the upgrade: a mint, a burn leaving a recipient cell and 100 sUDT, and 50 sUDT the
+    // previous recipient typescript can not burn with a memo.
+    let bridge_lock = bridge.bridge_lock(&manifest);
+    let bridge_cell = chain.genesis_cell(capacity_cell(&bridge_lock), Bytes::new());
+    let tx = bridge.mint_tx(&mut chain, &manifest, bridge_cell);
+    let mint_tx = chain.commit(tx);
+    let (sudt_output, sudt_data) =
+        sudt_cell(&chain.always_success, &bridge.sudt_type(&manifest), 200);
+    let sudt_input = chain.genesis_cell(sudt_output, sudt_data);
+    let burn_tx = chain.commit(bridge.burn_tx(&chain, &manifest, sudt_input, 200, 100, &[]));
+    let (sudt_output, sudt_data) =
+        sudt_cell(&chain.always_success, &bridge.sudt_type(&manifest), 50);
+    let unburned_sudt_input = chain.genesis_cell(sudt_output, sudt_data);
+    let burn_with_memo = |chain: &Chain, manifest: &Manifest| {
+        bridge.burn_tx(
+            chain,
+            manifest,
+            unburned_sudt_input.clone(),
+            50,
+            20,
+            b"deposit id",
+        )
+    };
+    let tx = burn_with_memo(&chain, &manifest);
+    chain.assert_rejected(tx, "RecipientDataView coding error");
+
+    let upgrade_tx = chain.commit(upgrade_tx(&deploy_tx, &binaries(&current)));
+    let upgraded = manifest.upgrade(current.path(), &upgrade_tx).unwrap();
+
+    // the old binaries are gone, and every code hash is kept.
+    for index in 0..2 {
+        assert!(chain
+            .context
+            .get_cell(&OutPoint::new(deploy_tx.hash(), index))
+            .is_none());
+    }
+    for (name, contract) in manifest.contracts.iter() {
+        assert_eq!(upgraded.contracts[name].type_hash, contract.type_hash);
+    }
+    assert_eq!(
+        upgraded.deps.keys().collect::<Vec<_>>(),
+        manifest.deps.keys().collect::<Vec<_>>()
+    );
+    for (dep_key, dep) in manifest.deps.iter() {
+        assert_eq!(upgraded.deps[dep_key].script, dep.script);
+    }
+    assert_eq!(bridge.bridge_lock(&upgraded), bridge_lock);
+    assert_eq!(
+        bridge.sudt_type(&upgraded).calc_script_hash(),
+        sudt_type_hash
+    );
+
+    // after: the current recipient typescript burns the same sUDT with a memo.
+    let tx = burn_with_memo(&chain, &upgraded);
+    let burn_with_memo_tx = chain.commit(tx);
+    assert_eq!(
+        burn_with_memo_tx.outputs_data().get(0).unwrap().raw_data(),
+        bridge.recipient_data(&upgraded, 20, b"deposit id")
+    );
+
+    // the recipient cell of the burn before the upgrade is consumed by the new recipient
+    // typescript.
+    let recipient_cell = CellInput::new(OutPoint::new(burn_tx.hash(), 0), 0);
+    let capacity_output = capacity_cell(&chain.always_success);
+    chain.commit(
+        TransactionBuilder::default()
+            .cell_deps(bridge.cell_deps(&chain, &upgraded))
+            .input(recipient_cell)
+            .output(capacity_output)
+            .output_data(Bytes::new().pack())
+            .build(),
+    );
+
+    // the sUDT left by the burn before the upgrade is burned again.
+    let sudt_input = CellInput::new(OutPoint::new(burn_tx.hash(), 1), 0);
+    chain.commit(bridge.burn_tx(&chain, &upgraded, sudt_input, 100, 60, &[]));
+
+    // the bridge cell recreated by the mint before the upgrade is unlocked by the new bridge
+    // lockscript, to mint the same sUDT with the same legacy witness.
+    let bridge_cell = CellInput::new(OutPoint::new(mint_tx.hash(), 0), 0);
+    let tx = bridge.mint_tx(&mut chain, &upgraded, bridge_cell);
+    let mint_tx = chain.commit(tx);
+    let minted_type_hash: Byte32 = mint_tx
+        .outputs()
+        .get(1)
+        .unwrap()
+        .type_()
+        .to_opt()
+        .unwrap()
+        .calc_script_hash();
+    assert_eq!(minted_type_hash, sudt_type_hash);
+}
+
+#[test]
+fn test_manifest_upgrade_must_keep_the_type_id() {
+    let previous = match previous_build() {
+        Some(previous) => previous,
+        None => return,
+    };
+    let current = current_build();
+    let mut chain = Chain::new();
+    let (deploy_tx, manifest) = deploy(&mut chain, &previous);
+
+    // the recipient typescript is redeployed behind another type id.
+    let tx = upgrade_tx(&deploy_tx, &binaries(&current));
+    let outputs = tx
+        .outputs()
+        .into_iter()
+        .enumerate()
+        .map(|(index, output)| match index {
+            1 => output
+                .as_builder()
+                .type_(Some(type_id_script([5u8; 32])).pack())
+                .build(),
+            _ => output,
+        })
+        .collect::<Vec<_>>();
+    let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+    let err = manifest.upgrade(current.path(), &tx).unwrap_err();
+    assert_eq!(
+        err,
+        "recipient-typescript at output 1 does not keep its type id"
+    );
+
+    // a tx without the binaries of the current build upgrades nothing.
+    let err = manifest.upgrade(current.path(), &deploy_tx).unwrap_err();
+    assert_eq!(err, "no contract of the build is upgraded by the tx");
+}
+
+#[test]
+fn test_manifest_upgrade_of_data_hash_deployment() {
+    let previous = match previous_build() {
+        Some(previous) => previous,
+        None => return,
+    };
+    let current = current_build();
+    let mut chain = Chain::new();
+    let (deploy_tx, mut manifest) = deploy(&mut chain, &previous);
+    let bridge_lockscript = manifest.contracts.get_mut("bridge-lockscript").unwrap();
+    bridge_lockscript.type_id_args = None;
+    bridge_lockscript.type_hash = None;
+
+    let err = manifest
+        .upgrade(current.path(), &upgrade_tx(&deploy_tx, &binaries(&current)))
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "bridge-lockscript is referenced by data hash, it can not be upgraded"
+    );
+}
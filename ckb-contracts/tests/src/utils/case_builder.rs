@@ -1,12 +1,11 @@
 #![allow(clippy::all)]
 
+use crate::{context_sudt_code_hash, network_from_env};
 use ckb_testtool::context::Context;
 pub use ckb_tool::ckb_types::bytes::Bytes;
 use ckb_tool::ckb_types::{packed::*, prelude::*};
-use core::convert::TryInto;
 use force_bridge_types::{
-    generated::force_bridge_lockscript::ForceBridgeLockscriptArgs,
-    recipient_cell::RecipientDataView,
+    bridge_args::BridgeArgsRef, mint_witness::MintWitnessView, recipient_cell::RecipientDataView,
 };
 use std::collections::HashMap;
 use std::vec::Vec;
@@ -15,11 +14,15 @@ pub const BRIDGE_LOCKSCRIPT_OUTPOINT_KEY: &str = "bridge_lockscript_outpoint_key
 pub const RECIPIENT_TYPESCRIPT_OUTPOINT_KEY: &str = "recipient_typescript_outpoint_key";
 pub const SUDT_TYPESCRIPT_OUTPOINT_KEY: &str = "sudt_typescript_key";
 pub const ALWAYS_SUCCESS_OUTPOINT_KEY: &str = "always_success_outpoint_key";
+pub const TOKEN_INFO_TYPESCRIPT_OUTPOINT_KEY: &str = "token_info_typescript_outpoint_key";
 pub const FIRST_INPUT_OUTPOINT_KEY: &str = "cell_id_outpoint_key";
 
 pub const BRIDGE_INPUT_OUTPOINT: &str =
     "ce2af4461cc6062998febffea311866388e8c869af0cf89ce832dadcd3521f2700000000";
 
+/// The devnet sUDT binary, deployed by the case runner.
+pub const SIMPLE_UDT: &[u8] = include_bytes!("../../deps/simple_udt");
+
 pub type OutpointsContext = HashMap<&'static str, OutPoint>;
 
 pub trait CellBuilder {
@@ -39,19 +42,25 @@ pub trait CellBuilder {
 }
 
 pub struct TestCase {
+    /// identifies the case in mock tx files and the cycles report.
+    pub name: String,
     pub cell_deps: Vec<CellDep>,
     pub owner_cell: Option<OwnerCell>,
     pub script_cells: CustomCells,
     pub sudt_cells: SudtCells,
     pub capacity_cells: CapacityCells,
-    pub witnesses: Vec<Witness>,
+    pub witnesses: Vec<PlacedWitness>,
     pub expect_return_error_info: String,
+    /// fail the case when a passing tx consumes more cycles than this.
+    pub max_cycles: Option<u64>,
 }
 
 #[derive(Clone)]
 pub struct OwnerCell {
     pub lockscript: Script,
     pub typescript: Script,
+    /// an `OwnerCellData`, or empty for a bridge without mint rules.
+    pub data: Vec<u8>,
 }
 
 pub struct CustomCells {
@@ -62,6 +71,8 @@ pub struct CustomCells {
 pub enum CustomCell {
     RecipientCustomCell(RecipientCell),
     BridgeCustomCell(BridgeCell),
+    TokenInfoCustomCell(TokenInfoCell),
+    OwnerCustomCell(OwnerTxCell),
 }
 
 impl CellBuilder for CustomCell {
@@ -97,6 +108,10 @@ impl CellBuilder for CustomCell {
             CustomCell::BridgeCustomCell(bridge_cell) => {
                 bridge_cell.build_output_cell(context, outpoints)
             }
+            CustomCell::TokenInfoCustomCell(token_info_cell) => {
+                token_info_cell.build_output_cell(context, outpoints)
+            }
+            CustomCell::OwnerCustomCell(owner_cell) => owner_cell.build_output_cell(),
         }
     }
 
@@ -104,6 +119,8 @@ impl CellBuilder for CustomCell {
         match self {
             CustomCell::RecipientCustomCell(recipient_cell) => recipient_cell.index,
             CustomCell::BridgeCustomCell(bridge_cell) => bridge_cell.index,
+            CustomCell::TokenInfoCustomCell(token_info_cell) => token_info_cell.index,
+            CustomCell::OwnerCustomCell(owner_cell) => owner_cell.index,
         }
     }
 }
@@ -145,6 +162,62 @@ impl RecipientCell {
     }
 }
 
+/// A token info cell of the sUDT owned by `sudt_owner`, locked by always success.
+pub struct TokenInfoCell {
+    pub capacity: u64,
+    pub index: usize,
+    pub sudt_owner: ScriptView,
+    pub data: Vec<u8>,
+}
+
+impl TokenInfoCell {
+    fn build_output_cell(
+        &self,
+        context: &mut Context,
+        outpoints: &OutpointsContext,
+    ) -> (Bytes, CellOutput) {
+        let sudt_typescript = SudtCell {
+            owner_script: self.sudt_owner.clone(),
+            ..Default::default()
+        }
+        .build_typescript(context, outpoints);
+        let sudt_type_hash: [u8; 32] = sudt_typescript.calc_script_hash().unpack();
+        let typescript = context
+            .build_script(
+                &outpoints[TOKEN_INFO_TYPESCRIPT_OUTPOINT_KEY],
+                sudt_type_hash.to_vec().into(),
+            )
+            .expect("build token info typescript");
+        let lockscript = context
+            .build_script(&outpoints[ALWAYS_SUCCESS_OUTPOINT_KEY], Default::default())
+            .expect("build token info lockscript");
+        let output_cell = CellOutput::new_builder()
+            .capacity(self.capacity.pack())
+            .type_(Some(typescript).pack())
+            .lock(lockscript)
+            .build();
+        (self.data.clone().into(), output_cell)
+    }
+}
+
+/// An owner cell the tx spends or creates, rather than one in the cell deps.
+pub struct OwnerTxCell {
+    pub capacity: u64,
+    pub index: usize,
+    pub cell: OwnerCell,
+}
+
+impl OwnerTxCell {
+    fn build_output_cell(&self) -> (Bytes, CellOutput) {
+        let output_cell = CellOutput::new_builder()
+            .capacity(self.capacity.pack())
+            .type_(Some(self.cell.typescript.clone()).pack())
+            .lock(self.cell.lockscript.clone())
+            .build();
+        (self.cell.data.clone().into(), output_cell)
+    }
+}
+
 pub struct BridgeCell {
     pub capacity: u64,
     pub index: usize,
@@ -190,25 +263,18 @@ impl BridgeCell {
     }
 
     fn build_lockscript(&self, context: &mut Context, outpoints: &OutpointsContext) -> Script {
-        let force_bridge_lock_args = ForceBridgeLockscriptArgs::new_builder()
-            .asset(self.asset.clone().into())
-            .chain(self.chain.into())
-            .owner_cell_type_hash(
-                self.owner_cell_type_hash
-                    .to_vec()
-                    .try_into()
-                    .expect("owner_cell_type_hash convert fail"),
-            )
-            .build();
+        let force_bridge_lock_args =
+            build_force_bridge_lock_args(self.chain, self.asset.clone(), self.owner_cell_type_hash);
         context
             .build_script(
                 &outpoints[BRIDGE_LOCKSCRIPT_OUTPOINT_KEY],
-                force_bridge_lock_args.as_bytes(),
+                force_bridge_lock_args,
             )
             .expect("build bridge lockscript")
     }
 }
 
+#[derive(Clone)]
 pub struct ScriptView {
     pub outpoint_key: &'static str,
     pub args: Bytes,
@@ -231,17 +297,7 @@ impl ScriptView {
     }
 
     pub fn build_sudt_owner(chain: u8, asset: String, owner_cell_type_hash: [u8; 32]) -> Self {
-        let args = ForceBridgeLockscriptArgs::new_builder()
-            .asset(asset.into())
-            .chain(chain.into())
-            .owner_cell_type_hash(
-                owner_cell_type_hash
-                    .to_vec()
-                    .try_into()
-                    .expect("owner_lock_hash convert fail"),
-            )
-            .build()
-            .as_bytes();
+        let args = build_force_bridge_lock_args(chain, asset, owner_cell_type_hash);
         Self {
             outpoint_key: BRIDGE_LOCKSCRIPT_OUTPOINT_KEY,
             args,
@@ -249,6 +305,20 @@ impl ScriptView {
     }
 }
 
+pub fn build_force_bridge_lock_args(
+    chain: u8,
+    asset: String,
+    owner_cell_type_hash: [u8; 32],
+) -> Bytes {
+    BridgeArgsRef {
+        owner_cell_type_hash: &owner_cell_type_hash,
+        chain,
+        asset: asset.as_bytes(),
+        evm_destination: None,
+    }
+    .as_bytes()
+}
+
 #[derive(Default)]
 pub struct SudtCells {
     pub inputs: Vec<SudtCell>,
@@ -275,9 +345,12 @@ impl SudtCell {
             .expect("build owner script");
         let args: [u8; 32] = owner_script.calc_script_hash().unpack();
         let args: Bytes = args.to_vec().into();
-        context
-            .build_script(&outpoints[SUDT_TYPESCRIPT_OUTPOINT_KEY], args)
-            .expect("build sudt typescript fail")
+        let network = network_from_env();
+        Script::new_builder()
+            .code_hash(context_sudt_code_hash(network).pack())
+            .hash_type(Byte::new(network.sudt_hash_type()))
+            .args(args.pack())
+            .build()
     }
 }
 
@@ -361,11 +434,86 @@ impl CellBuilder for CapacityCell {
 }
 
 #[derive(Clone)]
-#[allow(dead_code)]
-pub enum Witness {}
+pub enum Witness {
+    Raw(Bytes),
+    WitnessArgs(WitnessArgs),
+    Fields {
+        lock: Option<Bytes>,
+        input_type: Option<Bytes>,
+        output_type: Option<Bytes>,
+    },
+    /// a `VersionedMintWitness` in the `input_type` field, where the mint tx puts it for every
+    /// bridge cell.
+    Mint(MintWitnessView),
+}
 
 impl Witness {
     pub fn as_bytes(&self) -> Bytes {
-        Bytes::default()
+        match self {
+            Witness::Raw(bytes) => bytes.clone(),
+            Witness::WitnessArgs(witness_args) => witness_args.as_bytes(),
+            Witness::Fields {
+                lock,
+                input_type,
+                output_type,
+            } => WitnessArgs::new_builder()
+                .lock(bytes_opt(lock.clone()))
+                .input_type(bytes_opt(input_type.clone()))
+                .output_type(bytes_opt(output_type.clone()))
+                .build()
+                .as_bytes(),
+            Witness::Mint(mint_witness) => {
+                let mint_witness = mint_witness
+                    .as_molecule_data()
+                    .expect("encode mint witness");
+                WitnessArgs::new_builder()
+                    .input_type(bytes_opt(Some(mint_witness)))
+                    .build()
+                    .as_bytes()
+            }
+        }
+    }
+}
+
+pub fn bytes_opt(bytes: Option<Bytes>) -> BytesOpt {
+    BytesOpt::new_builder()
+        .set(bytes.map(|bytes| bytes.pack()))
+        .build()
+}
+
+/// The script group a witness belongs to, identified by the script of its cells.
+#[derive(Clone)]
+pub enum ScriptGroup {
+    Lock(ScriptView),
+    Type(ScriptView),
+}
+
+#[derive(Clone)]
+pub enum WitnessPlacement {
+    Index(usize),
+    /// the index of the first input of the group, or of its first output for type script
+    /// groups which only have outputs, as `Source::GroupInput` and `Source::GroupOutput` do.
+    Group(ScriptGroup),
+}
+
+#[derive(Clone)]
+pub struct PlacedWitness {
+    pub placement: WitnessPlacement,
+    pub witness: Witness,
+}
+
+impl PlacedWitness {
+    pub fn at(index: usize, witness: Witness) -> Self {
+        Self {
+            placement: WitnessPlacement::Index(index),
+            witness,
+        }
+    }
+
+    pub fn in_group(group: ScriptGroup, witness: Witness) -> Self {
+        Self {
+            placement: WitnessPlacement::Group(group),
+            witness,
+        }
     }
 }
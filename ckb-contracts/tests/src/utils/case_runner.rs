@@ -1,17 +1,21 @@
 #![allow(clippy::all)]
 
 use super::case_builder::{
-    CellBuilder, OutpointsContext, TestCase, ALWAYS_SUCCESS_OUTPOINT_KEY,
-    BRIDGE_LOCKSCRIPT_OUTPOINT_KEY, FIRST_INPUT_OUTPOINT_KEY, RECIPIENT_TYPESCRIPT_OUTPOINT_KEY,
-    SUDT_TYPESCRIPT_OUTPOINT_KEY,
+    CellBuilder, OutpointsContext, PlacedWitness, ScriptGroup, TestCase, WitnessPlacement,
+    ALWAYS_SUCCESS_OUTPOINT_KEY, BRIDGE_LOCKSCRIPT_OUTPOINT_KEY, FIRST_INPUT_OUTPOINT_KEY,
+    RECIPIENT_TYPESCRIPT_OUTPOINT_KEY, SIMPLE_UDT, SUDT_TYPESCRIPT_OUTPOINT_KEY,
+    TOKEN_INFO_TYPESCRIPT_OUTPOINT_KEY,
 };
+use super::cycle_report::record_cycles;
+use super::mock_tx::{mock_tx_path, should_dump_mock_tx, MockTransaction};
 use crate::*;
 use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use ckb_tool::ckb_types::{
-    core::TransactionBuilder,
-    packed::{CellDep, CellInput, CellOutput},
+    core::{Capacity, TransactionBuilder},
+    packed::{self, CellDep, CellInput, CellOutput, OutPoint},
     prelude::*,
 };
+use force_bridge_deployment::manifest::type_id_script;
 use std::mem::replace;
 
 pub const MAX_CYCLES: u64 = 100_000_000_000;
@@ -41,7 +45,8 @@ pub fn run_test(case: TestCase) {
             .lock(owner_cell.lockscript.clone())
             .type_(Some(owner_cell.typescript.clone()).pack())
             .build();
-        let owner_cell_outpoint = context.create_cell(owner_cell_output, Bytes::default());
+        let owner_cell_outpoint =
+            context.create_cell(owner_cell_output, Bytes::from(owner_cell.data));
         let owner_cell_dep = CellDep::new_builder()
             .out_point(owner_cell_outpoint)
             .build();
@@ -101,10 +106,13 @@ pub fn run_test(case: TestCase) {
     );
 
     // Witnesses
-    let mut witnesses = vec![];
-    for witness in case.witnesses {
-        witnesses.push(witness.as_bytes().pack());
-    }
+    let witnesses = place_witnesses(
+        case.witnesses,
+        &mut context,
+        &outpoints_context,
+        &inputs,
+        &outputs,
+    );
 
     // Build tx
     let tx = TransactionBuilder::default()
@@ -114,19 +122,49 @@ pub fn run_test(case: TestCase) {
         .outputs_data(outputs_data.pack())
         .witnesses(witnesses)
         .build();
-    let tx = context.complete_tx(tx);
+    // every deployed contract is already a cell dep, and `Context::complete_tx` would panic on
+    // the scripts referenced by type hash, like the type id of an owner cell.
 
     // Test tx
     let res = context.verify_tx(&tx, MAX_CYCLES);
     dbg!(&res);
     dbg!(context.captured_messages());
 
-    match res {
+    let passed = match &res {
         Ok(cycles) => {
             dbg!("cycles used {}", cycles);
-            assert_eq!(case.expect_return_error_info, String::default())
+            case.expect_return_error_info == String::default()
+                && case
+                    .max_cycles
+                    .map_or(true, |max_cycles| *cycles <= max_cycles)
         }
         Err(_err) => {
+            case.expect_return_error_info != String::default()
+                && check_err(&context, case.expect_return_error_info.clone())
+        }
+    };
+    // replay with `ckb-debugger --tx-file <path> --script-group-type <lock|type> ...`
+    if !passed || should_dump_mock_tx() {
+        let path = mock_tx_path(&case.name, &tx);
+        MockTransaction::from_context(&context, &tx).write_to(&path);
+        println!("mock tx written to {}", path.display());
+    }
+
+    match res {
+        Ok(cycles) => {
+            assert_eq!(case.expect_return_error_info, String::default());
+            if let Some(max_cycles) = case.max_cycles {
+                assert!(
+                    cycles <= max_cycles,
+                    "case {} used {} cycles, over its budget of {}",
+                    case.name,
+                    cycles,
+                    max_cycles
+                );
+            }
+            record_cycles(&case.name, cycles);
+        }
+        Err(_) => {
             assert_ne!(case.expect_return_error_info, String::default());
             assert!(check_err(&context, case.expect_return_error_info));
         }
@@ -140,8 +178,10 @@ fn deploy_scripts(context: &mut Context, outpoints_context: &mut OutpointsContex
     let recipient_typescript_bin: Bytes = Loader::default().load_binary("recipient-typescript");
     let recipient_typescript_point = context.deploy_cell(recipient_typescript_bin);
 
-    let sudt_typescript_bin = include_bytes!("../../deps/simple_udt");
-    let sudt_typescript_out_point = context.deploy_cell(Bytes::from(sudt_typescript_bin.as_ref()));
+    let token_info_typescript_bin: Bytes = Loader::default().load_binary("token-info-typescript");
+    let token_info_typescript_point = context.deploy_cell(token_info_typescript_bin);
+
+    let sudt_typescript_out_point = deploy_sudt(context, network_from_env());
 
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
 
@@ -153,6 +193,10 @@ fn deploy_scripts(context: &mut Context, outpoints_context: &mut OutpointsContex
         RECIPIENT_TYPESCRIPT_OUTPOINT_KEY,
         recipient_typescript_point.clone(),
     );
+    outpoints_context.insert(
+        TOKEN_INFO_TYPESCRIPT_OUTPOINT_KEY,
+        token_info_typescript_point.clone(),
+    );
     outpoints_context.insert(
         SUDT_TYPESCRIPT_OUTPOINT_KEY,
         sudt_typescript_out_point.clone(),
@@ -163,6 +207,34 @@ fn deploy_scripts(context: &mut Context, outpoints_context: &mut OutpointsContex
     );
 }
 
+// sUDT cells refer to the sUDT by the code hash of the network the contracts were built for,
+// the type hash of its type id on the networks deploying it at a public out point.
+pub fn deploy_sudt(context: &mut Context, network: Network) -> OutPoint {
+    let sudt_typescript_bin = Bytes::from(SIMPLE_UDT);
+    if let Some(type_id_args) = context_sudt_type_id_args(network) {
+        let (tx_hash, index) = network.sudt_out_point().expect("sudt out point");
+        let out_point = OutPoint::new(tx_hash.pack(), index);
+        let sudt_cell = CellOutput::new_builder()
+            .type_(
+                packed::ScriptOpt::new_builder()
+                    .set(Some(type_id_script(type_id_args)))
+                    .build(),
+            )
+            .build_exact_capacity(Capacity::bytes(sudt_typescript_bin.len()).unwrap())
+            .unwrap();
+        context.create_cell_with_out_point(out_point.clone(), sudt_cell, sudt_typescript_bin);
+        return out_point;
+    }
+    let data_hash: [u8; 32] = CellOutput::calc_data_hash(&sudt_typescript_bin).unpack();
+    assert_eq!(
+        data_hash,
+        network.sudt_code_hash(),
+        "deps/simple_udt is not the {} sUDT",
+        network.name()
+    );
+    context.deploy_cell(sudt_typescript_bin)
+}
+
 fn build_input_cell<I, B>(
     iterator: I,
     context: &mut Context,
@@ -200,6 +272,61 @@ fn build_output_cell<I, B>(
     }
 }
 
+fn place_witnesses(
+    witnesses: Vec<PlacedWitness>,
+    context: &mut Context,
+    outpoints_context: &OutpointsContext,
+    inputs: &[CellInput],
+    outputs: &[CellOutput],
+) -> Vec<packed::Bytes> {
+    let input_cells = inputs
+        .iter()
+        .map(|input| {
+            context
+                .get_cell(&input.previous_output())
+                .expect("input cell")
+                .0
+        })
+        .collect::<Vec<_>>();
+
+    let mut placed: Vec<Option<Bytes>> = vec![];
+    for PlacedWitness { placement, witness } in witnesses {
+        let index = match placement {
+            WitnessPlacement::Index(index) => index,
+            WitnessPlacement::Group(ScriptGroup::Lock(script_view)) => {
+                let script = script_view.build_script(context, outpoints_context);
+                input_cells
+                    .iter()
+                    .position(|cell| cell.lock() == script)
+                    .expect("lock script group has no input")
+            }
+            WitnessPlacement::Group(ScriptGroup::Type(script_view)) => {
+                let script = Some(script_view.build_script(context, outpoints_context));
+                input_cells
+                    .iter()
+                    .position(|cell| cell.type_().to_opt() == script)
+                    .or_else(|| {
+                        outputs
+                            .iter()
+                            .position(|cell| cell.type_().to_opt() == script)
+                    })
+                    .expect("type script group has no cell")
+            }
+        };
+        if placed.len() <= index {
+            placed.resize(index + 1, None);
+        }
+        if placed[index].is_some() {
+            panic!("more than one witness placed at index {}", index);
+        }
+        placed[index] = Some(witness.as_bytes());
+    }
+    placed
+        .into_iter()
+        .map(|witness| witness.unwrap_or_default().pack())
+        .collect()
+}
+
 fn check_err(context: &Context, info: String) -> bool {
     for message in context.captured_messages() {
         if message.message.contains("panic occurred:") && message.message.contains(&info.clone()) {
@@ -0,0 +1,35 @@
+use lazy_static::lazy_static;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Set to a file path to record the cycles of every passing case into it.
+///
+/// Entries are merged into the file keyed by case name, so a report can be kept across runs
+/// and diffed between commits.
+pub const CYCLES_REPORT_ENV: &str = "FORCE_BRIDGE_CYCLES_REPORT";
+
+lazy_static! {
+    // cases of one test binary may run in parallel, serialize the read-modify-write.
+    static ref REPORT_LOCK: Mutex<()> = Mutex::new(());
+}
+
+pub fn record_cycles(case_name: &str, cycles: u64) {
+    let path = match env::var_os(CYCLES_REPORT_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => return,
+    };
+    let _guard = REPORT_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+    let mut report: BTreeMap<String, u64> = match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).expect("parse cycles report"),
+        Err(_) => BTreeMap::new(),
+    };
+    report.insert(case_name.to_string(), cycles);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).expect("create cycles report dir");
+    }
+    let json = serde_json::to_string_pretty(&report).expect("serialize cycles report");
+    fs::write(&path, json).expect("write cycles report");
+}
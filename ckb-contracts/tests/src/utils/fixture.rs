@@ -0,0 +1,839 @@
+#![allow(clippy::all)]
+
+use super::case_builder::*;
+use crate::Loader;
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
+use ckb_tool::ckb_types::{
+    core::ScriptHashType,
+    packed::{CellOutput, Script},
+    prelude::*,
+};
+use force_bridge_deployment::manifest::type_id_script;
+use force_bridge_types::decimals::AssetDecimals;
+use force_bridge_types::evm::EvmDestination;
+use force_bridge_types::mint_witness::{
+    MintRecordView, MintWitnessVersion, MintWitnessView, RefundRecordView,
+};
+use force_bridge_types::owner_cell::{
+    AssetDecimalsView, BridgedAssetView, LargeMintConfig, MintFeeConfig, MintThresholdView,
+    OwnerCellView, RecipientTypescriptConfig, TokenInfoConfig, TwoPhaseMintConfig,
+};
+use force_bridge_types::recipient_cell::{RecipientDataVersion, RecipientDataView};
+use force_bridge_types::token_info::TokenInfoView;
+use serde::{de, Deserialize, Deserializer};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A `TestCase` described in JSON or TOML.
+///
+/// Cells are listed in transaction order, so there are no `index` fields to keep in sync, and
+/// scripts refer to the contracts deployed by the case runner by name. Every field but
+/// `inputs` and `outputs` may be omitted:
+///
+/// ```json
+/// {
+///   "description": "burn 100 trx",
+///   "inputs": [
+///     { "kind": "capacity", "capacity": 20000000000 },
+///     { "kind": "sudt", "capacity": 10000000000, "amount": 200, "chain": 1, "asset": "trx" }
+///   ],
+///   "outputs": [
+///     { "kind": "recipient", "capacity": 10000000000, "recipient_address": "0x00",
+///       "chain": 1, "asset": "trx", "amount": 100 },
+///     { "kind": "sudt", "capacity": 10000000000, "amount": 100, "chain": 1, "asset": "trx" }
+///   ],
+///   "expect_error": "burned token amount not match data amount"
+/// }
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Fixture {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub owner_cell: Option<FixtureOwnerCell>,
+    pub inputs: Vec<FixtureCell>,
+    pub outputs: Vec<FixtureCell>,
+    /// the witness of every input, in input order, unless placed by script group.
+    #[serde(default)]
+    pub witnesses: Vec<FixtureWitness>,
+    #[serde(default)]
+    pub expect_error: Option<String>,
+    #[serde(default)]
+    pub max_cycles: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureOwnerCell {
+    #[serde(default)]
+    pub lock: FixtureScript,
+    /// args of the type id type script of the owner cell.
+    #[serde(default)]
+    pub type_id_args: Hash,
+    /// the mint rules of the owner cell, an owner cell without data sets none.
+    #[serde(default)]
+    pub data: Option<FixtureOwnerData>,
+}
+
+/// An `OwnerCellData`, every rule of which may be omitted:
+///
+/// ```json
+/// {
+///   "large_mint": { "high_value_signer_lock": { "args": "0x02" },
+///     "thresholds": [{ "chain": 1, "asset": "trx", "threshold": 1000 }] },
+///   "asset_decimals": [{ "chain": 1, "asset": "trx", "xchain_decimals": 18, "sudt_decimals": 8 }],
+///   "token_info": true
+/// }
+/// ```
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureOwnerData {
+    #[serde(default)]
+    pub two_phase_mint: Option<FixtureTwoPhaseMint>,
+    #[serde(default)]
+    pub large_mint: Option<FixtureLargeMint>,
+    #[serde(default)]
+    pub asset_decimals: Vec<FixtureAssetDecimals>,
+    /// mints need the token info cell of the deployed token info typescript.
+    #[serde(default)]
+    pub token_info: bool,
+    #[serde(default)]
+    pub mint_fee: Option<FixtureMintFee>,
+    /// the owner refunds the cells of the deployed recipient typescript.
+    #[serde(default)]
+    pub refunds: bool,
+    #[serde(default)]
+    pub evm_destinations: Vec<FixtureEvmDestination>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureTwoPhaseMint {
+    #[serde(default)]
+    pub guardian_lock: FixtureScript,
+    /// in epochs.
+    pub challenge_period: u64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureLargeMint {
+    #[serde(default)]
+    pub high_value_signer_lock: FixtureScript,
+    #[serde(default)]
+    pub thresholds: Vec<FixtureMintThreshold>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureMintThreshold {
+    pub chain: u8,
+    pub asset: String,
+    #[serde(default)]
+    pub evm_destination: Option<FixtureEvmDestination>,
+    #[serde(deserialize_with = "deserialize_u128")]
+    pub threshold: u128,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureAssetDecimals {
+    pub chain: u8,
+    pub asset: String,
+    #[serde(default)]
+    pub evm_destination: Option<FixtureEvmDestination>,
+    pub xchain_decimals: u8,
+    pub sudt_decimals: u8,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureMintFee {
+    #[serde(default)]
+    pub collector_lock: FixtureScript,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureEvmDestination {
+    pub chain_id: u64,
+    pub bridge_contract_address: HexBytes,
+}
+
+impl FixtureEvmDestination {
+    fn build(&self) -> EvmDestination {
+        let address = &self.bridge_contract_address.0;
+        if address.len() != 20 {
+            panic!("bridge contract address of {} bytes", address.len());
+        }
+        let mut bridge_contract_address = [0u8; 20];
+        bridge_contract_address.copy_from_slice(address);
+        EvmDestination {
+            chain_id: self.chain_id,
+            bridge_contract_address,
+        }
+    }
+}
+
+fn bridged_asset(
+    chain: u8,
+    asset: String,
+    evm_destination: Option<FixtureEvmDestination>,
+) -> BridgedAssetView {
+    BridgedAssetView {
+        chain,
+        asset: asset.into_bytes(),
+        evm_destination: evm_destination.map(|evm_destination| evm_destination.build()),
+    }
+}
+
+fn lock_hash(script: &FixtureScript) -> [u8; 32] {
+    script.build().calc_script_hash().unpack()
+}
+
+impl FixtureOwnerData {
+    fn build(self) -> OwnerCellView {
+        OwnerCellView {
+            two_phase_mint: self
+                .two_phase_mint
+                .map(|two_phase_mint| TwoPhaseMintConfig {
+                    guardian_lock_hash: lock_hash(&two_phase_mint.guardian_lock),
+                    challenge_period: two_phase_mint.challenge_period,
+                }),
+            large_mint: self.large_mint.map(|large_mint| LargeMintConfig {
+                signer_lock_hash: lock_hash(&large_mint.high_value_signer_lock),
+                thresholds: large_mint
+                    .thresholds
+                    .into_iter()
+                    .map(|threshold| MintThresholdView {
+                        bridged_asset: bridged_asset(
+                            threshold.chain,
+                            threshold.asset,
+                            threshold.evm_destination,
+                        ),
+                        threshold: threshold.threshold,
+                    })
+                    .collect(),
+            }),
+            asset_decimals: self
+                .asset_decimals
+                .into_iter()
+                .map(|decimals| AssetDecimalsView {
+                    bridged_asset: bridged_asset(
+                        decimals.chain,
+                        decimals.asset,
+                        decimals.evm_destination,
+                    ),
+                    decimals: AssetDecimals {
+                        xchain_decimals: decimals.xchain_decimals,
+                        sudt_decimals: decimals.sudt_decimals,
+                    },
+                })
+                .collect(),
+            token_info: if self.token_info {
+                Some(TokenInfoConfig {
+                    code_hash: Deployment::TokenInfoTypescript.data_hash(),
+                    hash_type: ScriptHashType::Data.into(),
+                })
+            } else {
+                None
+            },
+            mint_fee: self.mint_fee.map(|mint_fee| MintFeeConfig {
+                collector_lock_hash: lock_hash(&mint_fee.collector_lock),
+            }),
+            recipient_typescript: if self.refunds {
+                Some(RecipientTypescriptConfig {
+                    code_hash: Deployment::RecipientTypescript.data_hash(),
+                    hash_type: ScriptHashType::Data.into(),
+                })
+            } else {
+                None
+            },
+            evm_destinations: self
+                .evm_destinations
+                .iter()
+                .map(FixtureEvmDestination::build)
+                .collect(),
+        }
+    }
+}
+
+/// Contracts deployed by the case runner, see `case_runner::deploy_scripts`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Deployment {
+    AlwaysSuccess,
+    BridgeLockscript,
+    RecipientTypescript,
+    TokenInfoTypescript,
+    Sudt,
+}
+
+impl Default for Deployment {
+    fn default() -> Self {
+        Deployment::AlwaysSuccess
+    }
+}
+
+impl Deployment {
+    pub fn outpoint_key(self) -> &'static str {
+        match self {
+            Deployment::AlwaysSuccess => ALWAYS_SUCCESS_OUTPOINT_KEY,
+            Deployment::BridgeLockscript => BRIDGE_LOCKSCRIPT_OUTPOINT_KEY,
+            Deployment::RecipientTypescript => RECIPIENT_TYPESCRIPT_OUTPOINT_KEY,
+            Deployment::TokenInfoTypescript => TOKEN_INFO_TYPESCRIPT_OUTPOINT_KEY,
+            Deployment::Sudt => SUDT_TYPESCRIPT_OUTPOINT_KEY,
+        }
+    }
+
+    fn binary(self) -> Bytes {
+        match self {
+            Deployment::AlwaysSuccess => ALWAYS_SUCCESS.clone(),
+            Deployment::BridgeLockscript => Loader::default().load_binary("bridge-lockscript"),
+            Deployment::RecipientTypescript => {
+                Loader::default().load_binary("recipient-typescript")
+            }
+            Deployment::TokenInfoTypescript => {
+                Loader::default().load_binary("token-info-typescript")
+            }
+            Deployment::Sudt => Bytes::from(SIMPLE_UDT),
+        }
+    }
+
+    pub fn data_hash(self) -> [u8; 32] {
+        CellOutput::calc_data_hash(&self.binary()).unpack()
+    }
+}
+
+/// A script running a deployed contract, the always-success one when omitted.
+#[derive(Deserialize, Debug, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureScript {
+    #[serde(default)]
+    pub deployment: Deployment,
+    #[serde(default)]
+    pub args: HexBytes,
+}
+
+impl FixtureScript {
+    // same as `Context::build_script`, which references contracts by data hash.
+    pub fn build(&self) -> Script {
+        Script::new_builder()
+            .code_hash(self.deployment.data_hash().pack())
+            .hash_type(ScriptHashType::Data.into())
+            .args(self.args.0.pack())
+            .build()
+    }
+
+    pub fn view(&self) -> ScriptView {
+        ScriptView {
+            outpoint_key: self.deployment.outpoint_key(),
+            args: self.args.0.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FixtureCell {
+    Capacity {
+        capacity: u64,
+        #[serde(default)]
+        lock: FixtureScript,
+    },
+    Sudt {
+        capacity: u64,
+        #[serde(deserialize_with = "deserialize_u128")]
+        amount: u128,
+        #[serde(default)]
+        lock: FixtureScript,
+        /// the owner script of the sUDT is a bridge lockscript with these args.
+        chain: u8,
+        asset: String,
+        /// defaults to the type hash of the owner cell.
+        #[serde(default)]
+        owner_cell_type_hash: Option<Hash>,
+        /// overrides the bridge lockscript owner.
+        #[serde(default)]
+        owner_script: Option<FixtureScript>,
+        #[serde(default)]
+        extra_data: String,
+    },
+    Bridge {
+        capacity: u64,
+        chain: u8,
+        asset: String,
+        /// defaults to the type hash of the owner cell.
+        #[serde(default)]
+        owner_cell_type_hash: Option<Hash>,
+    },
+    Recipient {
+        capacity: u64,
+        recipient_address: String,
+        chain: u8,
+        asset: String,
+        /// defaults to the data hash of the deployed bridge lockscript.
+        #[serde(default)]
+        bridge_lock_code_hash: Option<Hash>,
+        #[serde(default)]
+        bridge_lock_hash_type: u8,
+        /// defaults to the type hash of the owner cell.
+        #[serde(default)]
+        owner_cell_type_hash: Option<Hash>,
+        #[serde(deserialize_with = "deserialize_u128")]
+        amount: u128,
+    },
+    /// the token info cell of the sUDT of a bridge, typed by the deployed token info typescript.
+    TokenInfo {
+        capacity: u64,
+        chain: u8,
+        asset: String,
+        /// defaults to the type hash of the owner cell.
+        #[serde(default)]
+        owner_cell_type_hash: Option<Hash>,
+        decimals: u8,
+        name: String,
+        symbol: String,
+    },
+}
+
+impl Fixture {
+    pub fn load(path: &Path) -> Self {
+        let content = fs::read_to_string(path).expect("read fixture");
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&content).expect("parse json fixture"),
+            Some("toml") => toml::from_str(&content).expect("parse toml fixture"),
+            _ => panic!("unknown fixture format: {}", path.display()),
+        }
+    }
+
+    /// Every `.json` and `.toml` fixture in `dir`, sorted by file name.
+    pub fn list(dir: &Path) -> Vec<PathBuf> {
+        let mut paths = fs::read_dir(dir)
+            .expect("read fixtures dir")
+            .map(|entry| entry.expect("read fixtures dir").path())
+            .filter(|path| {
+                matches!(
+                    path.extension().and_then(|ext| ext.to_str()),
+                    Some("json") | Some("toml")
+                )
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths
+    }
+
+    pub fn into_test_case(self, name: String) -> TestCase {
+        let owner_cell = self.owner_cell.map(|owner_cell| OwnerCell {
+            lockscript: owner_cell.lock.build(),
+            typescript: type_id_script(owner_cell.type_id_args.0),
+            data: owner_cell
+                .data
+                .map_or_else(Vec::new, |data| data.build().as_molecule_data().to_vec()),
+        });
+        let owner_cell_type_hash: [u8; 32] = owner_cell
+            .as_ref()
+            .map(|owner_cell| owner_cell.typescript.calc_script_hash().unpack())
+            .unwrap_or_default();
+
+        let mut case = TestCase {
+            name,
+            cell_deps: vec![],
+            owner_cell,
+            script_cells: CustomCells {
+                inputs: vec![],
+                outputs: vec![],
+            },
+            sudt_cells: Default::default(),
+            capacity_cells: Default::default(),
+            witnesses: self
+                .witnesses
+                .into_iter()
+                .enumerate()
+                .map(|(index, witness)| witness.build(index, owner_cell_type_hash))
+                .collect(),
+            expect_return_error_info: self.expect_error.unwrap_or_default(),
+            max_cycles: self.max_cycles,
+        };
+        for (index, cell) in self.inputs.into_iter().enumerate() {
+            match cell.build(index, owner_cell_type_hash) {
+                BuiltCell::Custom(cell) => case.script_cells.inputs.push(cell),
+                BuiltCell::Sudt(cell) => case.sudt_cells.inputs.push(cell),
+                BuiltCell::Capacity(cell) => case.capacity_cells.inputs.push(cell),
+            }
+        }
+        for (index, cell) in self.outputs.into_iter().enumerate() {
+            match cell.build(index, owner_cell_type_hash) {
+                BuiltCell::Custom(cell) => case.script_cells.outputs.push(cell),
+                BuiltCell::Sudt(cell) => case.sudt_cells.outputs.push(cell),
+                BuiltCell::Capacity(cell) => case.capacity_cells.outputs.push(cell),
+            }
+        }
+        case
+    }
+}
+
+enum BuiltCell {
+    Custom(CustomCell),
+    Sudt(SudtCell),
+    Capacity(CapacityCell),
+}
+
+impl FixtureCell {
+    fn build(self, index: usize, default_owner_cell_type_hash: [u8; 32]) -> BuiltCell {
+        match self {
+            FixtureCell::Capacity { capacity, lock } => BuiltCell::Capacity(CapacityCell {
+                capacity,
+                lockscript: lock.view(),
+                index,
+            }),
+            FixtureCell::Sudt {
+                capacity,
+                amount,
+                lock,
+                chain,
+                asset,
+                owner_cell_type_hash,
+                owner_script,
+                extra_data,
+            } => {
+                let owner_script = owner_script.map(|script| script.view()).unwrap_or_else(|| {
+                    ScriptView::build_sudt_owner(
+                        chain,
+                        asset,
+                        owner_cell_type_hash.map_or(default_owner_cell_type_hash, |hash| hash.0),
+                    )
+                });
+                BuiltCell::Sudt(SudtCell {
+                    capacity,
+                    amount,
+                    lockscript: lock.build(),
+                    owner_script,
+                    index,
+                    sudt_extra_data: extra_data,
+                })
+            }
+            FixtureCell::Bridge {
+                capacity,
+                chain,
+                asset,
+                owner_cell_type_hash,
+            } => BuiltCell::Custom(CustomCell::BridgeCustomCell(BridgeCell {
+                capacity,
+                index,
+                asset,
+                chain,
+                owner_cell_type_hash: owner_cell_type_hash
+                    .map_or(default_owner_cell_type_hash, |hash| hash.0),
+            })),
+            FixtureCell::Recipient {
+                capacity,
+                recipient_address,
+                chain,
+                asset,
+                bridge_lock_code_hash,
+                bridge_lock_hash_type,
+                owner_cell_type_hash,
+                amount,
+            } => BuiltCell::Custom(CustomCell::RecipientCustomCell(RecipientCell {
+                capacity,
+                data: RecipientDataView {
+                    version: RecipientDataVersion::V1,
+                    recipient_address,
+                    chain,
+                    asset,
+                    bridge_lock_code_hash: bridge_lock_code_hash
+                        .map_or_else(|| Deployment::BridgeLockscript.data_hash(), |hash| hash.0),
+                    bridge_lock_hash_type,
+                    owner_cell_type_hash: owner_cell_type_hash
+                        .map_or(default_owner_cell_type_hash, |hash| hash.0),
+                    amount,
+                    refund: None,
+                    memo: vec![],
+                    evm_destination: None,
+                },
+                index,
+            })),
+            FixtureCell::TokenInfo {
+                capacity,
+                chain,
+                asset,
+                owner_cell_type_hash,
+                decimals,
+                name,
+                symbol,
+            } => BuiltCell::Custom(CustomCell::TokenInfoCustomCell(TokenInfoCell {
+                capacity,
+                index,
+                sudt_owner: ScriptView::build_sudt_owner(
+                    chain,
+                    asset,
+                    owner_cell_type_hash.map_or(default_owner_cell_type_hash, |hash| hash.0),
+                ),
+                data: TokenInfoView {
+                    decimals,
+                    name,
+                    symbol,
+                }
+                .as_bytes(),
+            })),
+        }
+    }
+}
+
+/// A witness at its index in the list, or one placed at the witness index of a script group:
+///
+/// ```json
+/// { "group": { "kind": "bridge", "chain": 1, "asset": "trx" },
+///   "witness": { "kind": "mint", "records": [
+///     { "lock_tx_hash": "0x01", "amount": 92, "recipient_lock": {} }
+///   ] } }
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum FixtureWitness {
+    InGroup(GroupWitness),
+    At(WitnessForm),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct GroupWitness {
+    pub group: FixtureScriptGroup,
+    pub witness: WitnessForm,
+}
+
+/// The script group of a witness, at the index of its first input, or of its first output for
+/// a type script group which only has outputs.
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FixtureScriptGroup {
+    /// the bridge lockscript of the bridge cells of the asset.
+    Bridge {
+        chain: u8,
+        asset: String,
+        /// defaults to the type hash of the owner cell.
+        #[serde(default)]
+        owner_cell_type_hash: Option<Hash>,
+    },
+    /// the deployed recipient typescript.
+    Recipient,
+    Lock(FixtureScript),
+    Type(FixtureScript),
+}
+
+impl FixtureScriptGroup {
+    fn build(self, default_owner_cell_type_hash: [u8; 32]) -> ScriptGroup {
+        match self {
+            FixtureScriptGroup::Bridge {
+                chain,
+                asset,
+                owner_cell_type_hash,
+            } => ScriptGroup::Lock(ScriptView::build_sudt_owner(
+                chain,
+                asset,
+                owner_cell_type_hash.map_or(default_owner_cell_type_hash, |hash| hash.0),
+            )),
+            FixtureScriptGroup::Recipient => ScriptGroup::Type(
+                FixtureScript {
+                    deployment: Deployment::RecipientTypescript,
+                    args: HexBytes::default(),
+                }
+                .view(),
+            ),
+            FixtureScriptGroup::Lock(script) => ScriptGroup::Lock(script.view()),
+            FixtureScriptGroup::Type(script) => ScriptGroup::Type(script.view()),
+        }
+    }
+}
+
+/// A witness as raw hex bytes, or one of the structured forms:
+///
+/// ```json
+/// { "kind": "witness_args", "lock": "0x01" }
+/// ```
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum WitnessForm {
+    Raw(HexBytes),
+    Structured(StructuredWitness),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum StructuredWitness {
+    /// a `WitnessArgs` of raw fields, none when omitted.
+    WitnessArgs {
+        #[serde(default)]
+        lock: Option<HexBytes>,
+        #[serde(default)]
+        input_type: Option<HexBytes>,
+        #[serde(default)]
+        output_type: Option<HexBytes>,
+    },
+    /// a `VersionedMintWitness` in the `input_type` field.
+    Mint {
+        #[serde(default)]
+        version: FixtureMintWitnessVersion,
+        #[serde(default)]
+        records: Vec<FixtureMintRecord>,
+        /// the input index of every recipient cell refunded, for a v4 witness.
+        #[serde(default)]
+        refunds: Vec<u32>,
+    },
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum FixtureMintWitnessVersion {
+    V2,
+    V3,
+    V4,
+}
+
+impl Default for FixtureMintWitnessVersion {
+    fn default() -> Self {
+        FixtureMintWitnessVersion::V2
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct FixtureMintRecord {
+    pub lock_tx_hash: HexBytes,
+    /// defaults to the index of the record.
+    #[serde(default)]
+    pub log_index: Option<u64>,
+    #[serde(deserialize_with = "deserialize_u128")]
+    pub amount: u128,
+    #[serde(default, deserialize_with = "deserialize_u128")]
+    pub fee: u128,
+    /// the lock of the minted sUDT cell.
+    #[serde(default)]
+    pub recipient_lock: FixtureScript,
+}
+
+impl FixtureWitness {
+    fn build(self, index: usize, default_owner_cell_type_hash: [u8; 32]) -> PlacedWitness {
+        match self {
+            FixtureWitness::InGroup(GroupWitness { group, witness }) => {
+                PlacedWitness::in_group(group.build(default_owner_cell_type_hash), witness.build())
+            }
+            FixtureWitness::At(witness) => PlacedWitness::at(index, witness.build()),
+        }
+    }
+}
+
+impl WitnessForm {
+    fn build(self) -> Witness {
+        match self {
+            WitnessForm::Raw(bytes) => Witness::Raw(bytes.0),
+            WitnessForm::Structured(StructuredWitness::WitnessArgs {
+                lock,
+                input_type,
+                output_type,
+            }) => Witness::Fields {
+                lock: lock.map(|bytes| bytes.0),
+                input_type: input_type.map(|bytes| bytes.0),
+                output_type: output_type.map(|bytes| bytes.0),
+            },
+            WitnessForm::Structured(StructuredWitness::Mint {
+                version,
+                records,
+                refunds,
+            }) => {
+                let version = match version {
+                    FixtureMintWitnessVersion::V2 => MintWitnessVersion::V2,
+                    FixtureMintWitnessVersion::V3 => MintWitnessVersion::V3,
+                    FixtureMintWitnessVersion::V4 => MintWitnessVersion::V4,
+                };
+                let records = records
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, record)| MintRecordView {
+                        lock_tx_hash: record.lock_tx_hash.0.to_vec(),
+                        log_index: record.log_index.unwrap_or(index as u64),
+                        amount: record.amount,
+                        fee: record.fee,
+                        recipient_lock_hash: record
+                            .recipient_lock
+                            .build()
+                            .calc_script_hash()
+                            .unpack(),
+                    })
+                    .collect();
+                let refunds = refunds
+                    .into_iter()
+                    .map(|recipient_cell_index| RefundRecordView {
+                        recipient_cell_index,
+                    })
+                    .collect();
+                Witness::Mint(MintWitnessView {
+                    version,
+                    records,
+                    refunds,
+                })
+            }
+        }
+    }
+}
+
+/// Bytes written as a hex string, with or without `0x`.
+#[derive(Debug, Default, Clone)]
+pub struct HexBytes(pub Bytes);
+
+impl<'de> Deserialize<'de> for HexBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        let bytes = hex::decode(s.trim_start_matches("0x")).map_err(de::Error::custom)?;
+        Ok(HexBytes(bytes.into()))
+    }
+}
+
+/// A 32 bytes hash written as a hex string, with or without `0x`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Hash(pub [u8; 32]);
+
+impl<'de> Deserialize<'de> for Hash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = HexBytes::deserialize(deserializer)?.0;
+        if bytes.len() != 32 {
+            return Err(de::Error::invalid_length(bytes.len(), &"32 bytes"));
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&bytes);
+        Ok(Hash(hash))
+    }
+}
+
+// TOML integers are i64, so amounts beyond that range are written as decimal strings.
+fn deserialize_u128<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+    struct U128Visitor;
+
+    impl<'de> de::Visitor<'de> for U128Visitor {
+        type Value = u128;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an unsigned integer or a decimal string")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<u128, E> {
+            Ok(v.into())
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<u128, E> {
+            if v < 0 {
+                return Err(E::invalid_value(de::Unexpected::Signed(v), &self));
+            }
+            Ok(v as u128)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<u128, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_any(U128Visitor)
+}
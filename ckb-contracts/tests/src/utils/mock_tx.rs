@@ -0,0 +1,142 @@
+use ckb_testtool::context::Context;
+use ckb_tool::ckb_jsonrpc_types as json;
+use ckb_tool::ckb_types::{
+    core::TransactionView,
+    packed::{Byte32, OutPoint},
+    prelude::*,
+    H256,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Set to dump the mock tx of every case, not only the failed ones.
+pub const DUMP_MOCK_TX_ENV: &str = "FORCE_BRIDGE_DUMP_MOCK_TX";
+/// Overrides the directory mock txs are written to, `build/mock-txs` by default.
+pub const MOCK_TX_DIR_ENV: &str = "FORCE_BRIDGE_MOCK_TX_DIR";
+
+// The mock transaction format read by `ckb-debugger --tx-file`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockInput {
+    pub input: json::CellInput,
+    pub output: json::CellOutput,
+    pub data: json::JsonBytes,
+    pub header: Option<H256>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockCellDep {
+    pub cell_dep: json::CellDep,
+    pub output: json::CellOutput,
+    pub data: json::JsonBytes,
+    pub header: Option<H256>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockInfo {
+    pub inputs: Vec<MockInput>,
+    pub cell_deps: Vec<MockCellDep>,
+    pub header_deps: Vec<json::HeaderView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockTransaction {
+    pub mock_info: MockInfo,
+    pub tx: json::Transaction,
+}
+
+impl MockTransaction {
+    /// Resolve every input, cell dep and header dep of a completed tx from the context.
+    pub fn from_context(context: &Context, tx: &TransactionView) -> Self {
+        let inputs = tx
+            .inputs()
+            .into_iter()
+            .map(|input| {
+                let out_point = input.previous_output();
+                let (output, data) = context
+                    .cells
+                    .get(&out_point)
+                    .expect("input cell not found in context");
+                MockInput {
+                    input: input.into(),
+                    output: output.clone().into(),
+                    data: json::JsonBytes::from_bytes(data.clone()),
+                    header: block_hash_of(context, &out_point),
+                }
+            })
+            .collect();
+        let cell_deps = tx
+            .cell_deps()
+            .into_iter()
+            .map(|cell_dep| {
+                let out_point = cell_dep.out_point();
+                let (output, data) = context
+                    .cells
+                    .get(&out_point)
+                    .expect("cell dep not found in context");
+                MockCellDep {
+                    cell_dep: cell_dep.into(),
+                    output: output.clone().into(),
+                    data: json::JsonBytes::from_bytes(data.clone()),
+                    header: block_hash_of(context, &out_point),
+                }
+            })
+            .collect();
+        let header_deps = tx
+            .header_deps()
+            .into_iter()
+            .map(|block_hash: Byte32| {
+                context
+                    .headers
+                    .get(&block_hash)
+                    .expect("header dep not found in context")
+                    .clone()
+                    .into()
+            })
+            .collect();
+
+        Self {
+            mock_info: MockInfo {
+                inputs,
+                cell_deps,
+                header_deps,
+            },
+            tx: tx.data().into(),
+        }
+    }
+
+    pub fn write_to(&self, path: &Path) {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).expect("create mock tx dir");
+        }
+        let json = serde_json::to_string_pretty(self).expect("serialize mock tx");
+        fs::write(path, json).expect("write mock tx");
+    }
+}
+
+fn block_hash_of(context: &Context, out_point: &OutPoint) -> Option<H256> {
+    context
+        .transaction_infos
+        .get(out_point)
+        .map(|info| info.block_hash.unpack())
+}
+
+pub fn should_dump_mock_tx() -> bool {
+    env::var_os(DUMP_MOCK_TX_ENV).is_some()
+}
+
+/// Mock txs are named after their case, or after the tx hash for unnamed cases.
+pub fn mock_tx_path(case_name: &str, tx: &TransactionView) -> PathBuf {
+    let dir = match env::var_os(MOCK_TX_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../build/mock-txs"),
+    };
+    let name = if case_name.is_empty() {
+        hex::encode(tx.hash().raw_data())
+    } else {
+        case_name.replace("::", "-")
+    };
+    dir.join(format!("{}.json", name))
+}
@@ -1,2 +1,6 @@
 pub mod case_builder;
 pub mod case_runner;
+pub mod cycle_report;
+pub mod fixture;
+pub mod mock_tx;
+pub mod strategies;
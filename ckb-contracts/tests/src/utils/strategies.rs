@@ -0,0 +1,350 @@
+use super::case_builder::*;
+use super::fixture::Deployment;
+use ckb_tool::ckb_types::{packed::Script, prelude::*};
+use force_bridge_deployment::manifest::type_id_script;
+use force_bridge_types::config::CKB_UNITS;
+use force_bridge_types::mint_witness::{MintRecordView, MintWitnessVersion, MintWitnessView};
+use force_bridge_types::recipient_cell::{RecipientDataVersion, RecipientDataView};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+// small enough that sums of a few cells never overflow in the sUDT script.
+const MAX_SUDT_AMOUNT: u128 = 1_000_000_000_000;
+
+#[derive(Debug, Clone)]
+pub struct Asset {
+    pub chain: u8,
+    pub asset: String,
+}
+
+pub fn asset() -> impl Strategy<Value = Asset> {
+    (any::<u8>(), "[a-zA-Z0-9]{1,16}").prop_map(|(chain, asset)| Asset { chain, asset })
+}
+
+#[derive(Debug, Clone)]
+pub struct SudtAmount {
+    pub amount: u128,
+    pub extra_data: String,
+}
+
+pub fn sudt_amount() -> impl Strategy<Value = SudtAmount> {
+    (0..=MAX_SUDT_AMOUNT, "[a-z]{0,32}")
+        .prop_map(|(amount, extra_data)| SudtAmount { amount, extra_data })
+}
+
+/// sUDT cells of an asset the tx does not bridge, spending at least what they create.
+#[derive(Debug, Clone)]
+pub struct Decoy {
+    pub asset: Asset,
+    /// type hash of the owner cell of another bridge, `None` for the owner cell of the bridged
+    /// asset.
+    pub owner_cell_type_hash: Option<[u8; 32]>,
+    pub inputs: Vec<SudtAmount>,
+    pub outputs: Vec<SudtAmount>,
+}
+
+pub fn decoy() -> impl Strategy<Value = Decoy> {
+    (
+        asset(),
+        prop_oneof![Just(None), any::<[u8; 32]>().prop_map(Some)],
+        vec(sudt_amount(), 1..3),
+        vec(sudt_amount(), 0..3),
+    )
+        .prop_map(|(asset, owner_cell_type_hash, inputs, outputs)| {
+            let mut decoy = Decoy {
+                asset,
+                owner_cell_type_hash,
+                inputs,
+                outputs,
+            };
+            // keep the decoy sUDT script happy, only the bridged asset is under test.
+            let input_sum: u128 = decoy.inputs.iter().map(|cell| cell.amount).sum();
+            let output_sum: u128 = decoy.outputs.iter().map(|cell| cell.amount).sum();
+            if output_sum > input_sum {
+                decoy.inputs[0].amount += output_sum - input_sum;
+            }
+            decoy
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct BurnTx {
+    pub asset: Asset,
+    pub recipient_address: String,
+    /// args of the owner cell typescript, so every burn has its own bridge.
+    pub owner_cell_args: [u8; 32],
+    pub inputs: Vec<SudtAmount>,
+    pub outputs: Vec<SudtAmount>,
+    pub amount: u128,
+    pub decoys: Vec<Decoy>,
+}
+
+impl BurnTx {
+    pub fn burned(&self) -> u128 {
+        let input_sum: u128 = self.inputs.iter().map(|cell| cell.amount).sum();
+        let output_sum: u128 = self.outputs.iter().map(|cell| cell.amount).sum();
+        input_sum - output_sum
+    }
+}
+
+/// A burn of a bridged asset into a recipient cell. The recipient amount equals the burned
+/// amount about half of the time.
+pub fn burn_tx() -> impl Strategy<Value = BurnTx> {
+    (
+        asset(),
+        "[0-9a-f]{40}",
+        any::<[u8; 32]>(),
+        vec(sudt_amount(), 1..4),
+        vec(sudt_amount(), 0..3),
+        prop_oneof![Just(None), (0..=MAX_SUDT_AMOUNT).prop_map(Some)],
+        vec(decoy(), 0..3),
+    )
+        .prop_map(
+            |(asset, recipient_address, owner_cell_args, inputs, outputs, amount, decoys)| {
+                let mut tx = BurnTx {
+                    asset,
+                    recipient_address,
+                    owner_cell_args,
+                    inputs,
+                    outputs,
+                    amount: 0,
+                    decoys,
+                };
+                // never create more than is burned, the sUDT script would reject the tx first.
+                let input_sum: u128 = tx.inputs.iter().map(|cell| cell.amount).sum();
+                let output_sum: u128 = tx.outputs.iter().map(|cell| cell.amount).sum();
+                if output_sum > input_sum {
+                    tx.inputs[0].amount += output_sum - input_sum;
+                }
+                tx.amount = amount.unwrap_or_else(|| tx.burned());
+                tx
+            },
+        )
+        // a decoy of the bridge must differ in asset, or it is not a decoy. One of another bridge
+        // may share the asset.
+        .prop_filter("decoy shares the bridged asset", |tx| {
+            tx.decoys.iter().all(|decoy| {
+                decoy.owner_cell_type_hash.is_some()
+                    || decoy.asset.chain != tx.asset.chain
+                    || decoy.asset.asset != tx.asset.asset
+            })
+        })
+}
+
+fn sudt_cell(owner_script: ScriptView, cell: &SudtAmount, index: usize) -> SudtCell {
+    SudtCell {
+        capacity: 200 * CKB_UNITS,
+        amount: cell.amount,
+        lockscript: always_success_lockscript(),
+        owner_script,
+        index,
+        sudt_extra_data: cell.extra_data.clone(),
+    }
+}
+
+fn always_success_lockscript() -> Script {
+    Script::new_builder()
+        .code_hash(Deployment::AlwaysSuccess.data_hash().pack())
+        .build()
+}
+
+/// Lays out `[capacity, sudt.., decoy sudt..] -> [recipient, sudt.., decoy sudt..]`.
+pub fn build_burn_case(name: &str, tx: &BurnTx) -> TestCase {
+    let owner_cell = OwnerCell {
+        lockscript: always_success_lockscript(),
+        typescript: type_id_script(tx.owner_cell_args),
+        data: vec![],
+    };
+    let owner_cell_type_hash: [u8; 32] = owner_cell.typescript.calc_script_hash().unpack();
+    let owner_script = || {
+        ScriptView::build_sudt_owner(tx.asset.chain, tx.asset.asset.clone(), owner_cell_type_hash)
+    };
+    let mut sudt_inputs = vec![];
+    let mut sudt_outputs = vec![];
+    for cell in tx.inputs.iter() {
+        sudt_inputs.push(sudt_cell(owner_script(), cell, sudt_inputs.len() + 1));
+    }
+    for cell in tx.outputs.iter() {
+        sudt_outputs.push(sudt_cell(owner_script(), cell, sudt_outputs.len() + 1));
+    }
+    for decoy in tx.decoys.iter() {
+        let decoy_owner = || {
+            ScriptView::build_sudt_owner(
+                decoy.asset.chain,
+                decoy.asset.asset.clone(),
+                decoy.owner_cell_type_hash.unwrap_or(owner_cell_type_hash),
+            )
+        };
+        for cell in decoy.inputs.iter() {
+            sudt_inputs.push(sudt_cell(decoy_owner(), cell, sudt_inputs.len() + 1));
+        }
+        for cell in decoy.outputs.iter() {
+            sudt_outputs.push(sudt_cell(decoy_owner(), cell, sudt_outputs.len() + 1));
+        }
+    }
+
+    TestCase {
+        name: name.to_string(),
+        cell_deps: vec![],
+        owner_cell: Some(owner_cell),
+        script_cells: CustomCells {
+            inputs: vec![],
+            outputs: vec![CustomCell::RecipientCustomCell(RecipientCell {
+                capacity: 200 * CKB_UNITS,
+                data: RecipientDataView {
+                    version: RecipientDataVersion::V1,
+                    recipient_address: tx.recipient_address.clone(),
+                    chain: tx.asset.chain,
+                    asset: tx.asset.asset.clone(),
+                    bridge_lock_code_hash: Deployment::BridgeLockscript.data_hash(),
+                    bridge_lock_hash_type: 0,
+                    owner_cell_type_hash,
+                    amount: tx.amount,
+                    refund: None,
+                    memo: vec![],
+                    evm_destination: None,
+                },
+                index: 0,
+            })],
+        },
+        sudt_cells: SudtCells {
+            inputs: sudt_inputs,
+            outputs: sudt_outputs,
+        },
+        capacity_cells: CapacityCells {
+            inputs: vec![CapacityCell {
+                capacity: 1000 * CKB_UNITS,
+                lockscript: Default::default(),
+                index: 0,
+            }],
+            outputs: vec![],
+        },
+        witnesses: vec![],
+        expect_return_error_info: String::default(),
+        max_cycles: None,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MintTx {
+    pub asset: Asset,
+    pub outputs: Vec<SudtAmount>,
+    pub owner_in_inputs: bool,
+    pub owner_cell_type_hash_matches: bool,
+}
+
+pub fn mint_tx() -> impl Strategy<Value = MintTx> {
+    (
+        asset(),
+        vec(sudt_amount(), 0..4),
+        any::<bool>(),
+        any::<bool>(),
+    )
+        .prop_map(
+            |(asset, outputs, owner_in_inputs, owner_cell_type_hash_matches)| MintTx {
+                asset,
+                outputs,
+                owner_in_inputs,
+                owner_cell_type_hash_matches,
+            },
+        )
+}
+
+/// Lays out `[bridge, capacity] -> [bridge, sudt..]` with the owner cell in the cell deps,
+/// the capacity cell being locked by the owner lock when `owner_in_inputs`, and a mint record
+/// for every sUDT cell in the witness of the bridge cell.
+pub fn build_mint_case(name: &str, tx: &MintTx) -> TestCase {
+    let owner_cell = OwnerCell {
+        lockscript: always_success_lockscript(),
+        typescript: type_id_script([2u8; 32]),
+        data: vec![],
+    };
+    let owner_cell_type_hash: [u8; 32] = if tx.owner_cell_type_hash_matches {
+        owner_cell.typescript.calc_script_hash().unpack()
+    } else {
+        [0u8; 32]
+    };
+    let bridge_cell = |index| {
+        CustomCell::BridgeCustomCell(BridgeCell {
+            capacity: 200 * CKB_UNITS,
+            index,
+            asset: tx.asset.asset.clone(),
+            chain: tx.asset.chain,
+            owner_cell_type_hash,
+        })
+    };
+    let capacity_lock = if tx.owner_in_inputs {
+        ScriptView::default()
+    } else {
+        ScriptView {
+            args: vec![1u8].into(),
+            ..Default::default()
+        }
+    };
+    let sudt_outputs = tx
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| {
+            sudt_cell(
+                ScriptView::build_sudt_owner(
+                    tx.asset.chain,
+                    tx.asset.asset.clone(),
+                    owner_cell_type_hash,
+                ),
+                cell,
+                index + 1,
+            )
+        })
+        .collect();
+    let recipient_lock_hash = always_success_lockscript().calc_script_hash().unpack();
+    let records = tx
+        .outputs
+        .iter()
+        .enumerate()
+        .map(|(index, cell)| MintRecordView {
+            lock_tx_hash: vec![1u8; 32],
+            log_index: index as u64,
+            amount: cell.amount,
+            fee: 0,
+            recipient_lock_hash,
+        })
+        .collect();
+    let mint_witness = PlacedWitness::in_group(
+        ScriptGroup::Lock(ScriptView::build_sudt_owner(
+            tx.asset.chain,
+            tx.asset.asset.clone(),
+            owner_cell_type_hash,
+        )),
+        Witness::Mint(MintWitnessView {
+            version: MintWitnessVersion::V2,
+            records,
+            refunds: vec![],
+        }),
+    );
+
+    TestCase {
+        name: name.to_string(),
+        cell_deps: vec![],
+        owner_cell: Some(owner_cell),
+        script_cells: CustomCells {
+            inputs: vec![bridge_cell(0)],
+            outputs: vec![bridge_cell(0)],
+        },
+        sudt_cells: SudtCells {
+            inputs: vec![],
+            outputs: sudt_outputs,
+        },
+        capacity_cells: CapacityCells {
+            inputs: vec![CapacityCell {
+                capacity: 1000 * CKB_UNITS,
+                lockscript: capacity_lock,
+                index: 1,
+            }],
+            outputs: vec![],
+        },
+        witnesses: vec![mint_witness],
+        expect_return_error_info: String::default(),
+        max_cycles: None,
+    }
+}
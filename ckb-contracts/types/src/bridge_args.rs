@@ -0,0 +1,127 @@
+use crate::evm::EvmDestination;
+use crate::generated::basic::Byte32;
+use crate::generated::force_bridge_lockscript::{
+    ForceBridgeLockscriptArgsV1, ForceBridgeLockscriptArgsV1Reader, ForceBridgeLockscriptArgsV2,
+    ForceBridgeLockscriptArgsV2Reader,
+};
+use crate::versioned;
+use core::convert::TryInto;
+use molecule::{
+    bytes::Bytes,
+    error::VerificationError,
+    prelude::{Builder, Entity, Reader},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::string::ToString;
+
+/// The args of a bridge lockscript of either version, borrowed from the script args. V1 args
+/// are the args of every bridge created before the args were versioned, V2 ones bridge an asset
+/// to an EVM network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeArgsRef<'a> {
+    pub owner_cell_type_hash: &'a [u8],
+    pub chain: u8,
+    pub asset: &'a [u8],
+    /// Only carried by V2 args.
+    pub evm_destination: Option<EvmDestination>,
+}
+
+impl<'a> BridgeArgsRef<'a> {
+    pub fn new(args: &'a [u8]) -> Result<BridgeArgsRef<'a>, VerificationError> {
+        match versioned::split(args) {
+            Some((versioned::V1, table)) => {
+                let reader = ForceBridgeLockscriptArgsV1Reader::from_slice(table)?;
+                Ok(BridgeArgsRef {
+                    owner_cell_type_hash: reader.owner_cell_type_hash().raw_data(),
+                    chain: reader.chain().into(),
+                    asset: reader.asset().raw_data(),
+                    evm_destination: None,
+                })
+            }
+            Some((2, table)) => {
+                let reader = ForceBridgeLockscriptArgsV2Reader::from_slice(table)?;
+                Ok(BridgeArgsRef {
+                    owner_cell_type_hash: reader.owner_cell_type_hash().raw_data(),
+                    chain: reader.chain().into(),
+                    asset: reader.asset().raw_data(),
+                    evm_destination: Some(EvmDestination::from_reader(reader.evm_destination())),
+                })
+            }
+            version => Err(VerificationError::UnknownItem(
+                "ForceBridgeLockscriptArgs".to_string(),
+                2,
+                version.map_or(0, |(version, _)| version.into()),
+            )),
+        }
+    }
+
+    /// The script args, V2 ones if the bridge has an EVM destination.
+    pub fn as_bytes(&self) -> Bytes {
+        let owner_cell_type_hash: Byte32 = self
+            .owner_cell_type_hash
+            .to_vec()
+            .try_into()
+            .expect("owner_cell_type_hash convert fail");
+        match self.evm_destination {
+            None => ForceBridgeLockscriptArgsV1::new_builder()
+                .owner_cell_type_hash(owner_cell_type_hash)
+                .chain(self.chain.into())
+                .asset(self.asset.to_vec().into())
+                .build()
+                .as_bytes(),
+            Some(evm_destination) => {
+                let args = ForceBridgeLockscriptArgsV2::new_builder()
+                    .owner_cell_type_hash(owner_cell_type_hash)
+                    .chain(self.chain.into())
+                    .asset(self.asset.to_vec().into())
+                    .evm_destination(evm_destination.as_molecule())
+                    .build();
+                versioned::join(2, args.as_slice())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BridgeArgsRef;
+    use crate::evm::EvmDestination;
+
+    fn args(evm_destination: Option<EvmDestination>) -> BridgeArgsRef<'static> {
+        BridgeArgsRef {
+            owner_cell_type_hash: &[100u8; 32],
+            chain: 1,
+            asset: b"trx",
+            evm_destination,
+        }
+    }
+
+    #[test]
+    fn test_v1_args_are_the_unversioned_table() {
+        let bytes = args(None).as_bytes();
+        // the total size of the table, as written before the args were versioned.
+        assert_eq!(&bytes[..4], &(bytes.len() as u32).to_le_bytes());
+        assert_eq!(BridgeArgsRef::new(&bytes).unwrap(), args(None));
+    }
+
+    #[test]
+    fn test_v2_args() {
+        let bsc = Some(EvmDestination {
+            chain_id: 56,
+            bridge_contract_address: [1u8; 20],
+        });
+        let bytes = args(bsc).as_bytes();
+        assert_eq!(bytes[0], 2);
+        assert_eq!(BridgeArgsRef::new(&bytes).unwrap(), args(bsc));
+    }
+
+    #[test]
+    fn test_unknown_args_version() {
+        let mut bytes = args(None).as_bytes().to_vec();
+        bytes.insert(0, 0xff);
+        assert!(BridgeArgsRef::new(&bytes).is_err());
+        bytes[0] = 1;
+        assert!(BridgeArgsRef::new(&bytes).is_err());
+    }
+}
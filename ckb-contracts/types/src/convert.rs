@@ -5,7 +5,7 @@ use alloc::string::String;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 
-use crate::generated::basic::{Byte32, Bytes, Uint128};
+use crate::generated::basic::{Byte32, Bytes, Uint128, Uint32, Uint64};
 use core::convert::TryFrom;
 use molecule::{
     error::VerificationError,
@@ -51,6 +51,26 @@ impl From<u128> for Uint128 {
     }
 }
 
+impl From<u64> for Uint64 {
+    fn from(v: u64) -> Self {
+        let mut inner = [Byte::new(0); 8];
+        for (byte, v) in inner.iter_mut().zip(v.to_le_bytes().iter()) {
+            *byte = Byte::new(*v);
+        }
+        Self::new_builder().set(inner).build()
+    }
+}
+
+impl From<u32> for Uint32 {
+    fn from(v: u32) -> Self {
+        let mut inner = [Byte::new(0); 4];
+        for (byte, v) in inner.iter_mut().zip(v.to_le_bytes().iter()) {
+            *byte = Byte::new(*v);
+        }
+        Self::new_builder().set(inner).build()
+    }
+}
+
 impl From<String> for Bytes {
     fn from(s: String) -> Self {
         Bytes::new_builder()
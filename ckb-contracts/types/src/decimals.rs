@@ -0,0 +1,106 @@
+/// Why an amount can not be scaled between the xchain and the sUDT units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleError {
+    /// Scaling down would drop the digits below the smallest unit of the target.
+    Dust,
+    Overflow,
+}
+
+/// The decimals of an xchain asset and of the sUDT it is bridged to, like 18 for an ERC20
+/// bridged to an sUDT of 8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetDecimals {
+    pub xchain_decimals: u8,
+    pub sudt_decimals: u8,
+}
+
+impl AssetDecimals {
+    /// The sUDT amount of `xchain_amount`, exact or an error.
+    pub fn to_sudt_amount(&self, xchain_amount: u128) -> Result<u128, ScaleError> {
+        scale(xchain_amount, self.xchain_decimals, self.sudt_decimals)
+    }
+
+    /// The xchain amount of `sudt_amount`, exact or an error.
+    pub fn to_xchain_amount(&self, sudt_amount: u128) -> Result<u128, ScaleError> {
+        scale(sudt_amount, self.sudt_decimals, self.xchain_decimals)
+    }
+}
+
+fn scale(amount: u128, from: u8, to: u8) -> Result<u128, ScaleError> {
+    if from <= to {
+        let factor = pow10(to - from)?;
+        amount.checked_mul(factor).ok_or(ScaleError::Overflow)
+    } else {
+        let factor = match pow10(from - to) {
+            Ok(factor) => factor,
+            // a factor above u128::MAX leaves nothing but dust of a non zero amount
+            Err(_) if amount == 0 => return Ok(0),
+            Err(_) => return Err(ScaleError::Dust),
+        };
+        if amount % factor != 0 {
+            return Err(ScaleError::Dust);
+        }
+        Ok(amount / factor)
+    }
+}
+
+fn pow10(exp: u8) -> Result<u128, ScaleError> {
+    10u128
+        .checked_pow(u32::from(exp))
+        .ok_or(ScaleError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetDecimals, ScaleError};
+
+    #[test]
+    fn test_scale_down() {
+        let decimals = AssetDecimals {
+            xchain_decimals: 18,
+            sudt_decimals: 8,
+        };
+        assert_eq!(decimals.to_sudt_amount(3 * 10u128.pow(10)), Ok(3));
+        assert_eq!(decimals.to_sudt_amount(0), Ok(0));
+        assert_eq!(
+            decimals.to_sudt_amount(3 * 10u128.pow(10) + 1),
+            Err(ScaleError::Dust)
+        );
+        assert_eq!(decimals.to_xchain_amount(3), Ok(3 * 10u128.pow(10)));
+        assert_eq!(
+            decimals.to_xchain_amount(u128::MAX),
+            Err(ScaleError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_scale_up() {
+        let decimals = AssetDecimals {
+            xchain_decimals: 6,
+            sudt_decimals: 8,
+        };
+        assert_eq!(decimals.to_sudt_amount(5), Ok(500));
+        assert_eq!(
+            decimals.to_sudt_amount(u128::MAX),
+            Err(ScaleError::Overflow)
+        );
+        assert_eq!(decimals.to_xchain_amount(501), Err(ScaleError::Dust));
+    }
+
+    #[test]
+    fn test_scale_extreme() {
+        let same = AssetDecimals {
+            xchain_decimals: 8,
+            sudt_decimals: 8,
+        };
+        assert_eq!(same.to_sudt_amount(u128::MAX), Ok(u128::MAX));
+
+        let far = AssetDecimals {
+            xchain_decimals: 255,
+            sudt_decimals: 0,
+        };
+        assert_eq!(far.to_sudt_amount(0), Ok(0));
+        assert_eq!(far.to_sudt_amount(1), Err(ScaleError::Dust));
+        assert_eq!(far.to_xchain_amount(1), Err(ScaleError::Overflow));
+    }
+}
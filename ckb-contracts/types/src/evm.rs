@@ -0,0 +1,65 @@
+use crate::generated::basic::Byte20;
+use crate::generated::evm::{EvmDestination as EvmDestinationMol, EvmDestinationReader};
+use molecule::prelude::{Builder, Byte, Entity};
+
+/// The EVM network, by its EIP-155 chain id, and the bridge contract on it an asset is
+/// bridged to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvmDestination {
+    pub chain_id: u64,
+    pub bridge_contract_address: [u8; 20],
+}
+
+impl EvmDestination {
+    pub fn from_reader(reader: EvmDestinationReader) -> EvmDestination {
+        let mut chain_id = [0u8; 8];
+        chain_id.copy_from_slice(reader.chain_id().raw_data());
+        let mut bridge_contract_address = [0u8; 20];
+        bridge_contract_address.copy_from_slice(reader.bridge_contract_address().raw_data());
+        EvmDestination {
+            chain_id: u64::from_le_bytes(chain_id),
+            bridge_contract_address,
+        }
+    }
+
+    pub fn as_molecule(&self) -> EvmDestinationMol {
+        let mut address = [Byte::new(0); 20];
+        for (byte, v) in address.iter_mut().zip(self.bridge_contract_address.iter()) {
+            *byte = Byte::new(*v);
+        }
+        EvmDestinationMol::new_builder()
+            .chain_id(self.chain_id.into())
+            .bridge_contract_address(Byte20::new_builder().set(address).build())
+            .build()
+    }
+
+    /// Both the chain id and the contract address are set, no EVM network has chain id 0.
+    pub fn is_set(&self) -> bool {
+        self.chain_id != 0 && self.bridge_contract_address != [0u8; 20]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvmDestination;
+    use crate::generated::evm::EvmDestinationReader;
+    use molecule::prelude::{Entity, Reader};
+
+    #[test]
+    fn test_evm_destination() {
+        let bsc = EvmDestination {
+            chain_id: 56,
+            bridge_contract_address: [1u8; 20],
+        };
+        let mol = bsc.as_molecule();
+        let reader = EvmDestinationReader::from_slice(mol.as_slice()).unwrap();
+        assert_eq!(EvmDestination::from_reader(reader), bsc);
+        assert!(bsc.is_set());
+        assert!(!EvmDestination { chain_id: 0, ..bsc }.is_set());
+        assert!(!EvmDestination {
+            bridge_contract_address: [0u8; 20],
+            ..bsc
+        }
+        .is_set());
+    }
+}
@@ -0,0 +1,342 @@
+// Generated by Molecule 0.7.1
+
+use super::basic::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct EvmDestination(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EvmDestination {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EvmDestination {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EvmDestination {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_contract_address",
+            self.bridge_contract_address()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for EvmDestination {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        EvmDestination::new_unchecked(v.into())
+    }
+}
+impl EvmDestination {
+    pub const TOTAL_SIZE: usize = 28;
+    pub const FIELD_SIZES: [usize; 2] = [8, 20];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn chain_id(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(0..8))
+    }
+    pub fn bridge_contract_address(&self) -> Byte20 {
+        Byte20::new_unchecked(self.0.slice(8..28))
+    }
+    pub fn as_reader<'r>(&'r self) -> EvmDestinationReader<'r> {
+        EvmDestinationReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EvmDestination {
+    type Builder = EvmDestinationBuilder;
+    const NAME: &'static str = "EvmDestination";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EvmDestination(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EvmDestinationReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EvmDestinationReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .chain_id(self.chain_id())
+            .bridge_contract_address(self.bridge_contract_address())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EvmDestinationReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EvmDestinationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EvmDestinationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EvmDestinationReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "chain_id", self.chain_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_contract_address",
+            self.bridge_contract_address()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> EvmDestinationReader<'r> {
+    pub const TOTAL_SIZE: usize = 28;
+    pub const FIELD_SIZES: [usize; 2] = [8, 20];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn chain_id(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[0..8])
+    }
+    pub fn bridge_contract_address(&self) -> Byte20Reader<'r> {
+        Byte20Reader::new_unchecked(&self.as_slice()[8..28])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EvmDestinationReader<'r> {
+    type Entity = EvmDestination;
+    const NAME: &'static str = "EvmDestinationReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EvmDestinationReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct EvmDestinationBuilder {
+    pub(crate) chain_id: Uint64,
+    pub(crate) bridge_contract_address: Byte20,
+}
+impl EvmDestinationBuilder {
+    pub const TOTAL_SIZE: usize = 28;
+    pub const FIELD_SIZES: [usize; 2] = [8, 20];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn chain_id(mut self, v: Uint64) -> Self {
+        self.chain_id = v;
+        self
+    }
+    pub fn bridge_contract_address(mut self, v: Byte20) -> Self {
+        self.bridge_contract_address = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for EvmDestinationBuilder {
+    type Entity = EvmDestination;
+    const NAME: &'static str = "EvmDestinationBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.chain_id.as_slice())?;
+        writer.write_all(self.bridge_contract_address.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EvmDestination::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct EvmDestinationOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EvmDestinationOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EvmDestinationOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EvmDestinationOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for EvmDestinationOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        EvmDestinationOpt::new_unchecked(v.into())
+    }
+}
+impl EvmDestinationOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<EvmDestination> {
+        if self.is_none() {
+            None
+        } else {
+            Some(EvmDestination::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> EvmDestinationOptReader<'r> {
+        EvmDestinationOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EvmDestinationOpt {
+    type Builder = EvmDestinationOptBuilder;
+    const NAME: &'static str = "EvmDestinationOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EvmDestinationOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EvmDestinationOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EvmDestinationOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EvmDestinationOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EvmDestinationOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EvmDestinationOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EvmDestinationOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> EvmDestinationOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<EvmDestinationReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(EvmDestinationReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EvmDestinationOptReader<'r> {
+    type Entity = EvmDestinationOpt;
+    const NAME: &'static str = "EvmDestinationOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EvmDestinationOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            EvmDestinationReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct EvmDestinationOptBuilder(pub(crate) Option<EvmDestination>);
+impl EvmDestinationOptBuilder {
+    pub fn set(mut self, v: Option<EvmDestination>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for EvmDestinationOptBuilder {
+    type Entity = EvmDestinationOpt;
+    const NAME: &'static str = "EvmDestinationOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EvmDestinationOpt::new_unchecked(inner.into())
+    }
+}
@@ -1,10 +1,11 @@
 // Generated by Molecule 0.7.1
 
 use super::basic::*;
+use super::evm::*;
 use molecule::prelude::*;
 #[derive(Clone)]
-pub struct ForceBridgeLockscriptArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ForceBridgeLockscriptArgs {
+pub struct ForceBridgeLockscriptArgsV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ForceBridgeLockscriptArgsV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13,12 +14,12 @@ impl ::core::fmt::LowerHex for ForceBridgeLockscriptArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ForceBridgeLockscriptArgs {
+impl ::core::fmt::Debug for ForceBridgeLockscriptArgsV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ForceBridgeLockscriptArgs {
+impl ::core::fmt::Display for ForceBridgeLockscriptArgsV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(
@@ -36,16 +37,16 @@ impl ::core::fmt::Display for ForceBridgeLockscriptArgs {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ForceBridgeLockscriptArgs {
+impl ::core::default::Default for ForceBridgeLockscriptArgsV1 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
             53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        ForceBridgeLockscriptArgs::new_unchecked(v.into())
+        ForceBridgeLockscriptArgsV1::new_unchecked(v.into())
     }
 }
-impl ForceBridgeLockscriptArgs {
+impl ForceBridgeLockscriptArgsV1 {
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -85,15 +86,15 @@ impl ForceBridgeLockscriptArgs {
             Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> ForceBridgeLockscriptArgsReader<'r> {
-        ForceBridgeLockscriptArgsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ForceBridgeLockscriptArgsV1Reader<'r> {
+        ForceBridgeLockscriptArgsV1Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ForceBridgeLockscriptArgs {
-    type Builder = ForceBridgeLockscriptArgsBuilder;
-    const NAME: &'static str = "ForceBridgeLockscriptArgs";
+impl molecule::prelude::Entity for ForceBridgeLockscriptArgsV1 {
+    type Builder = ForceBridgeLockscriptArgsV1Builder;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV1";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ForceBridgeLockscriptArgs(data)
+        ForceBridgeLockscriptArgsV1(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -102,10 +103,10 @@ impl molecule::prelude::Entity for ForceBridgeLockscriptArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ForceBridgeLockscriptArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        ForceBridgeLockscriptArgsV1Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ForceBridgeLockscriptArgsReader::from_compatible_slice(slice)
+        ForceBridgeLockscriptArgsV1Reader::from_compatible_slice(slice)
             .map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
@@ -119,8 +120,8 @@ impl molecule::prelude::Entity for ForceBridgeLockscriptArgs {
     }
 }
 #[derive(Clone, Copy)]
-pub struct ForceBridgeLockscriptArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ForceBridgeLockscriptArgsReader<'r> {
+pub struct ForceBridgeLockscriptArgsV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ForceBridgeLockscriptArgsV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -129,12 +130,12 @@ impl<'r> ::core::fmt::LowerHex for ForceBridgeLockscriptArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ForceBridgeLockscriptArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for ForceBridgeLockscriptArgsV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ForceBridgeLockscriptArgsReader<'r> {
+impl<'r> ::core::fmt::Display for ForceBridgeLockscriptArgsV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(
@@ -152,7 +153,7 @@ impl<'r> ::core::fmt::Display for ForceBridgeLockscriptArgsReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> ForceBridgeLockscriptArgsReader<'r> {
+impl<'r> ForceBridgeLockscriptArgsV1Reader<'r> {
     pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -193,14 +194,14 @@ impl<'r> ForceBridgeLockscriptArgsReader<'r> {
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ForceBridgeLockscriptArgsReader<'r> {
-    type Entity = ForceBridgeLockscriptArgs;
-    const NAME: &'static str = "ForceBridgeLockscriptArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for ForceBridgeLockscriptArgsV1Reader<'r> {
+    type Entity = ForceBridgeLockscriptArgsV1;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV1Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ForceBridgeLockscriptArgsReader(slice)
+        ForceBridgeLockscriptArgsV1Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -249,12 +250,12 @@ impl<'r> molecule::prelude::Reader<'r> for ForceBridgeLockscriptArgsReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct ForceBridgeLockscriptArgsBuilder {
+pub struct ForceBridgeLockscriptArgsV1Builder {
     pub(crate) owner_cell_type_hash: Byte32,
     pub(crate) chain: Byte,
     pub(crate) asset: Bytes,
 }
-impl ForceBridgeLockscriptArgsBuilder {
+impl ForceBridgeLockscriptArgsV1Builder {
     pub const FIELD_COUNT: usize = 3;
     pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
         self.owner_cell_type_hash = v;
@@ -269,9 +270,9 @@ impl ForceBridgeLockscriptArgsBuilder {
         self
     }
 }
-impl molecule::prelude::Builder for ForceBridgeLockscriptArgsBuilder {
-    type Entity = ForceBridgeLockscriptArgs;
-    const NAME: &'static str = "ForceBridgeLockscriptArgsBuilder";
+impl molecule::prelude::Builder for ForceBridgeLockscriptArgsV1Builder {
+    type Entity = ForceBridgeLockscriptArgsV1;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV1Builder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.owner_cell_type_hash.as_slice().len()
@@ -300,6 +301,333 @@ impl molecule::prelude::Builder for ForceBridgeLockscriptArgsBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ForceBridgeLockscriptArgs::new_unchecked(inner.into())
+        ForceBridgeLockscriptArgsV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ForceBridgeLockscriptArgsV2(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ForceBridgeLockscriptArgsV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ForceBridgeLockscriptArgsV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ForceBridgeLockscriptArgsV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ForceBridgeLockscriptArgsV2 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            85, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 53, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ForceBridgeLockscriptArgsV2::new_unchecked(v.into())
+    }
+}
+impl ForceBridgeLockscriptArgsV2 {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn asset(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn evm_destination(&self) -> EvmDestination {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            EvmDestination::new_unchecked(self.0.slice(start..end))
+        } else {
+            EvmDestination::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ForceBridgeLockscriptArgsV2Reader<'r> {
+        ForceBridgeLockscriptArgsV2Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ForceBridgeLockscriptArgsV2 {
+    type Builder = ForceBridgeLockscriptArgsV2Builder;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV2";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ForceBridgeLockscriptArgsV2(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ForceBridgeLockscriptArgsV2Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ForceBridgeLockscriptArgsV2Reader::from_compatible_slice(slice)
+            .map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .owner_cell_type_hash(self.owner_cell_type_hash())
+            .chain(self.chain())
+            .asset(self.asset())
+            .evm_destination(self.evm_destination())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ForceBridgeLockscriptArgsV2Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ForceBridgeLockscriptArgsV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ForceBridgeLockscriptArgsV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ForceBridgeLockscriptArgsV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ForceBridgeLockscriptArgsV2Reader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn asset(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn evm_destination(&self) -> EvmDestinationReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            EvmDestinationReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            EvmDestinationReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ForceBridgeLockscriptArgsV2Reader<'r> {
+    type Entity = ForceBridgeLockscriptArgsV2;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV2Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ForceBridgeLockscriptArgsV2Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        EvmDestinationReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ForceBridgeLockscriptArgsV2Builder {
+    pub(crate) owner_cell_type_hash: Byte32,
+    pub(crate) chain: Byte,
+    pub(crate) asset: Bytes,
+    pub(crate) evm_destination: EvmDestination,
+}
+impl ForceBridgeLockscriptArgsV2Builder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
+        self.owner_cell_type_hash = v;
+        self
+    }
+    pub fn chain(mut self, v: Byte) -> Self {
+        self.chain = v;
+        self
+    }
+    pub fn asset(mut self, v: Bytes) -> Self {
+        self.asset = v;
+        self
+    }
+    pub fn evm_destination(mut self, v: EvmDestination) -> Self {
+        self.evm_destination = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ForceBridgeLockscriptArgsV2Builder {
+    type Entity = ForceBridgeLockscriptArgsV2;
+    const NAME: &'static str = "ForceBridgeLockscriptArgsV2Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.owner_cell_type_hash.as_slice().len()
+            + self.chain.as_slice().len()
+            + self.asset.as_slice().len()
+            + self.evm_destination.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.owner_cell_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.asset.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.evm_destination.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.owner_cell_type_hash.as_slice())?;
+        writer.write_all(self.chain.as_slice())?;
+        writer.write_all(self.asset.as_slice())?;
+        writer.write_all(self.evm_destination.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ForceBridgeLockscriptArgsV2::new_unchecked(inner.into())
     }
 }
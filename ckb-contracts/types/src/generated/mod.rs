@@ -1,6 +1,14 @@
 #[allow(clippy::all)]
 pub mod basic;
 #[allow(clippy::all)]
+pub mod evm;
+#[allow(clippy::all)]
 pub mod force_bridge_lockscript;
 #[allow(clippy::all)]
+pub mod mint_witness;
+#[allow(clippy::all)]
+pub mod owner_cell;
+#[allow(clippy::all)]
+pub mod pending_mint;
+#[allow(clippy::all)]
 pub mod recipient_typescript;
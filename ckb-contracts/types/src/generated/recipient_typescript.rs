@@ -1,10 +1,11 @@
 // Generated by Molecule 0.7.1
 
 use super::basic::*;
+use super::evm::*;
 use molecule::prelude::*;
 #[derive(Clone)]
-pub struct RecipientCellData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for RecipientCellData {
+pub struct RecipientCellDataV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientCellDataV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -13,12 +14,12 @@ impl ::core::fmt::LowerHex for RecipientCellData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for RecipientCellData {
+impl ::core::fmt::Debug for RecipientCellDataV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for RecipientCellData {
+impl ::core::fmt::Display for RecipientCellDataV1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
@@ -50,7 +51,7 @@ impl ::core::fmt::Display for RecipientCellData {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for RecipientCellData {
+impl ::core::default::Default for RecipientCellDataV1 {
     fn default() -> Self {
         let v: Vec<u8> = vec![
             122, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 37, 0, 0, 0, 41, 0, 0, 0, 73, 0, 0, 0, 74, 0,
@@ -59,10 +60,10 @@ impl ::core::default::Default for RecipientCellData {
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        RecipientCellData::new_unchecked(v.into())
+        RecipientCellDataV1::new_unchecked(v.into())
     }
 }
-impl RecipientCellData {
+impl RecipientCellDataV1 {
     pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -126,15 +127,15 @@ impl RecipientCellData {
             Uint128::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> RecipientCellDataReader<'r> {
-        RecipientCellDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RecipientCellDataV1Reader<'r> {
+        RecipientCellDataV1Reader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for RecipientCellData {
-    type Builder = RecipientCellDataBuilder;
-    const NAME: &'static str = "RecipientCellData";
+impl molecule::prelude::Entity for RecipientCellDataV1 {
+    type Builder = RecipientCellDataV1Builder;
+    const NAME: &'static str = "RecipientCellDataV1";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        RecipientCellData(data)
+        RecipientCellDataV1(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -143,10 +144,10 @@ impl molecule::prelude::Entity for RecipientCellData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RecipientCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        RecipientCellDataV1Reader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        RecipientCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RecipientCellDataV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
@@ -163,8 +164,8 @@ impl molecule::prelude::Entity for RecipientCellData {
     }
 }
 #[derive(Clone, Copy)]
-pub struct RecipientCellDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for RecipientCellDataReader<'r> {
+pub struct RecipientCellDataV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientCellDataV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -173,12 +174,12 @@ impl<'r> ::core::fmt::LowerHex for RecipientCellDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for RecipientCellDataReader<'r> {
+impl<'r> ::core::fmt::Debug for RecipientCellDataV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for RecipientCellDataReader<'r> {
+impl<'r> ::core::fmt::Display for RecipientCellDataV1Reader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
@@ -210,7 +211,7 @@ impl<'r> ::core::fmt::Display for RecipientCellDataReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> RecipientCellDataReader<'r> {
+impl<'r> RecipientCellDataV1Reader<'r> {
     pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -275,14 +276,14 @@ impl<'r> RecipientCellDataReader<'r> {
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataReader<'r> {
-    type Entity = RecipientCellData;
-    const NAME: &'static str = "RecipientCellDataReader";
+impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataV1Reader<'r> {
+    type Entity = RecipientCellDataV1;
+    const NAME: &'static str = "RecipientCellDataV1Reader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        RecipientCellDataReader(slice)
+        RecipientCellDataV1Reader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -335,7 +336,7 @@ impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataReader<'r> {
     }
 }
 #[derive(Debug, Default)]
-pub struct RecipientCellDataBuilder {
+pub struct RecipientCellDataV1Builder {
     pub(crate) recipient_address: Bytes,
     pub(crate) chain: Byte,
     pub(crate) asset: Bytes,
@@ -344,7 +345,7 @@ pub struct RecipientCellDataBuilder {
     pub(crate) owner_cell_type_hash: Byte32,
     pub(crate) amount: Uint128,
 }
-impl RecipientCellDataBuilder {
+impl RecipientCellDataV1Builder {
     pub const FIELD_COUNT: usize = 7;
     pub fn recipient_address(mut self, v: Bytes) -> Self {
         self.recipient_address = v;
@@ -375,9 +376,9 @@ impl RecipientCellDataBuilder {
         self
     }
 }
-impl molecule::prelude::Builder for RecipientCellDataBuilder {
-    type Entity = RecipientCellData;
-    const NAME: &'static str = "RecipientCellDataBuilder";
+impl molecule::prelude::Builder for RecipientCellDataV1Builder {
+    type Entity = RecipientCellDataV1;
+    const NAME: &'static str = "RecipientCellDataV1Builder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.recipient_address.as_slice().len()
@@ -422,6 +423,2379 @@ impl molecule::prelude::Builder for RecipientCellDataBuilder {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        RecipientCellData::new_unchecked(inner.into())
+        RecipientCellDataV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientCellDataV2(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientCellDataV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientCellDataV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientCellDataV2 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund_lock_hash", self.refund_lock_hash())?;
+        write!(f, ", {}: {}", "refund_since", self.refund_since())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RecipientCellDataV2 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            170, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 45, 0, 0, 0, 49, 0, 0, 0, 81, 0, 0, 0, 82, 0,
+            0, 0, 114, 0, 0, 0, 130, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0,
+        ];
+        RecipientCellDataV2::new_unchecked(v.into())
+    }
+}
+impl RecipientCellDataV2 {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn asset(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn refund_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn refund_since(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientCellDataV2Reader<'r> {
+        RecipientCellDataV2Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientCellDataV2 {
+    type Builder = RecipientCellDataV2Builder;
+    const NAME: &'static str = "RecipientCellDataV2";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientCellDataV2(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV2Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV2Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .recipient_address(self.recipient_address())
+            .chain(self.chain())
+            .asset(self.asset())
+            .bridge_lock_code_hash(self.bridge_lock_code_hash())
+            .bridge_lock_hash_type(self.bridge_lock_hash_type())
+            .owner_cell_type_hash(self.owner_cell_type_hash())
+            .amount(self.amount())
+            .refund_lock_hash(self.refund_lock_hash())
+            .refund_since(self.refund_since())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientCellDataV2Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientCellDataV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientCellDataV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientCellDataV2Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund_lock_hash", self.refund_lock_hash())?;
+        write!(f, ", {}: {}", "refund_since", self.refund_since())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RecipientCellDataV2Reader<'r> {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn asset(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn refund_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn refund_since(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataV2Reader<'r> {
+    type Entity = RecipientCellDataV2;
+    const NAME: &'static str = "RecipientCellDataV2Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientCellDataV2Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientCellDataV2Builder {
+    pub(crate) recipient_address: Bytes,
+    pub(crate) chain: Byte,
+    pub(crate) asset: Bytes,
+    pub(crate) bridge_lock_code_hash: Byte32,
+    pub(crate) bridge_lock_hash_type: Byte,
+    pub(crate) owner_cell_type_hash: Byte32,
+    pub(crate) amount: Uint128,
+    pub(crate) refund_lock_hash: Byte32,
+    pub(crate) refund_since: Uint64,
+}
+impl RecipientCellDataV2Builder {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn recipient_address(mut self, v: Bytes) -> Self {
+        self.recipient_address = v;
+        self
+    }
+    pub fn chain(mut self, v: Byte) -> Self {
+        self.chain = v;
+        self
+    }
+    pub fn asset(mut self, v: Bytes) -> Self {
+        self.asset = v;
+        self
+    }
+    pub fn bridge_lock_code_hash(mut self, v: Byte32) -> Self {
+        self.bridge_lock_code_hash = v;
+        self
+    }
+    pub fn bridge_lock_hash_type(mut self, v: Byte) -> Self {
+        self.bridge_lock_hash_type = v;
+        self
+    }
+    pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
+        self.owner_cell_type_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn refund_lock_hash(mut self, v: Byte32) -> Self {
+        self.refund_lock_hash = v;
+        self
+    }
+    pub fn refund_since(mut self, v: Uint64) -> Self {
+        self.refund_since = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientCellDataV2Builder {
+    type Entity = RecipientCellDataV2;
+    const NAME: &'static str = "RecipientCellDataV2Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.recipient_address.as_slice().len()
+            + self.chain.as_slice().len()
+            + self.asset.as_slice().len()
+            + self.bridge_lock_code_hash.as_slice().len()
+            + self.bridge_lock_hash_type.as_slice().len()
+            + self.owner_cell_type_hash.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.refund_lock_hash.as_slice().len()
+            + self.refund_since.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.recipient_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.asset.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.owner_cell_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.refund_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.refund_since.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.recipient_address.as_slice())?;
+        writer.write_all(self.chain.as_slice())?;
+        writer.write_all(self.asset.as_slice())?;
+        writer.write_all(self.bridge_lock_code_hash.as_slice())?;
+        writer.write_all(self.bridge_lock_hash_type.as_slice())?;
+        writer.write_all(self.owner_cell_type_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.refund_lock_hash.as_slice())?;
+        writer.write_all(self.refund_since.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientCellDataV2::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientRefund(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientRefund {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "since", self.since())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RecipientRefund {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            52, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RecipientRefund::new_unchecked(v.into())
+    }
+}
+impl RecipientRefund {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn since(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientRefundReader<'r> {
+        RecipientRefundReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientRefund {
+    type Builder = RecipientRefundBuilder;
+    const NAME: &'static str = "RecipientRefund";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientRefund(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientRefundReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientRefundReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .lock_hash(self.lock_hash())
+            .since(self.since())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientRefundReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientRefundReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "since", self.since())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RecipientRefundReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn since(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientRefundReader<'r> {
+    type Entity = RecipientRefund;
+    const NAME: &'static str = "RecipientRefundReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientRefundReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientRefundBuilder {
+    pub(crate) lock_hash: Byte32,
+    pub(crate) since: Uint64,
+}
+impl RecipientRefundBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(mut self, v: Byte32) -> Self {
+        self.lock_hash = v;
+        self
+    }
+    pub fn since(mut self, v: Uint64) -> Self {
+        self.since = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientRefundBuilder {
+    type Entity = RecipientRefund;
+    const NAME: &'static str = "RecipientRefundBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.lock_hash.as_slice().len()
+            + self.since.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.since.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.lock_hash.as_slice())?;
+        writer.write_all(self.since.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientRefund::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientRefundOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientRefundOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientRefundOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientRefundOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for RecipientRefundOpt {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![];
+        RecipientRefundOpt::new_unchecked(v.into())
+    }
+}
+impl RecipientRefundOpt {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<RecipientRefund> {
+        if self.is_none() {
+            None
+        } else {
+            Some(RecipientRefund::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientRefundOptReader<'r> {
+        RecipientRefundOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientRefundOpt {
+    type Builder = RecipientRefundOptBuilder;
+    const NAME: &'static str = "RecipientRefundOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientRefundOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientRefundOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientRefundOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientRefundOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientRefundOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientRefundOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientRefundOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> RecipientRefundOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<RecipientRefundReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(RecipientRefundReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientRefundOptReader<'r> {
+    type Entity = RecipientRefundOpt;
+    const NAME: &'static str = "RecipientRefundOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientRefundOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            RecipientRefundReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientRefundOptBuilder(pub(crate) Option<RecipientRefund>);
+impl RecipientRefundOptBuilder {
+    pub fn set(mut self, v: Option<RecipientRefund>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientRefundOptBuilder {
+    type Entity = RecipientRefundOpt;
+    const NAME: &'static str = "RecipientRefundOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientRefundOpt::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientCellDataV3(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientCellDataV3 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientCellDataV3 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientCellDataV3 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RecipientCellDataV3 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            134, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 45, 0, 0, 0, 49, 0, 0, 0, 81, 0, 0, 0, 82, 0,
+            0, 0, 114, 0, 0, 0, 130, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RecipientCellDataV3::new_unchecked(v.into())
+    }
+}
+impl RecipientCellDataV3 {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn asset(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn refund(&self) -> RecipientRefundOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn memo(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientCellDataV3Reader<'r> {
+        RecipientCellDataV3Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientCellDataV3 {
+    type Builder = RecipientCellDataV3Builder;
+    const NAME: &'static str = "RecipientCellDataV3";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientCellDataV3(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV3Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV3Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .recipient_address(self.recipient_address())
+            .chain(self.chain())
+            .asset(self.asset())
+            .bridge_lock_code_hash(self.bridge_lock_code_hash())
+            .bridge_lock_hash_type(self.bridge_lock_hash_type())
+            .owner_cell_type_hash(self.owner_cell_type_hash())
+            .amount(self.amount())
+            .refund(self.refund())
+            .memo(self.memo())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientCellDataV3Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientCellDataV3Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientCellDataV3Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientCellDataV3Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RecipientCellDataV3Reader<'r> {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn asset(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn refund(&self) -> RecipientRefundOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn memo(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataV3Reader<'r> {
+    type Entity = RecipientCellDataV3;
+    const NAME: &'static str = "RecipientCellDataV3Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientCellDataV3Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        RecipientRefundOptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        BytesReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientCellDataV3Builder {
+    pub(crate) recipient_address: Bytes,
+    pub(crate) chain: Byte,
+    pub(crate) asset: Bytes,
+    pub(crate) bridge_lock_code_hash: Byte32,
+    pub(crate) bridge_lock_hash_type: Byte,
+    pub(crate) owner_cell_type_hash: Byte32,
+    pub(crate) amount: Uint128,
+    pub(crate) refund: RecipientRefundOpt,
+    pub(crate) memo: Bytes,
+}
+impl RecipientCellDataV3Builder {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn recipient_address(mut self, v: Bytes) -> Self {
+        self.recipient_address = v;
+        self
+    }
+    pub fn chain(mut self, v: Byte) -> Self {
+        self.chain = v;
+        self
+    }
+    pub fn asset(mut self, v: Bytes) -> Self {
+        self.asset = v;
+        self
+    }
+    pub fn bridge_lock_code_hash(mut self, v: Byte32) -> Self {
+        self.bridge_lock_code_hash = v;
+        self
+    }
+    pub fn bridge_lock_hash_type(mut self, v: Byte) -> Self {
+        self.bridge_lock_hash_type = v;
+        self
+    }
+    pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
+        self.owner_cell_type_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn refund(mut self, v: RecipientRefundOpt) -> Self {
+        self.refund = v;
+        self
+    }
+    pub fn memo(mut self, v: Bytes) -> Self {
+        self.memo = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientCellDataV3Builder {
+    type Entity = RecipientCellDataV3;
+    const NAME: &'static str = "RecipientCellDataV3Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.recipient_address.as_slice().len()
+            + self.chain.as_slice().len()
+            + self.asset.as_slice().len()
+            + self.bridge_lock_code_hash.as_slice().len()
+            + self.bridge_lock_hash_type.as_slice().len()
+            + self.owner_cell_type_hash.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.refund.as_slice().len()
+            + self.memo.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.recipient_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.asset.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.owner_cell_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.refund.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.memo.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.recipient_address.as_slice())?;
+        writer.write_all(self.chain.as_slice())?;
+        writer.write_all(self.asset.as_slice())?;
+        writer.write_all(self.bridge_lock_code_hash.as_slice())?;
+        writer.write_all(self.bridge_lock_hash_type.as_slice())?;
+        writer.write_all(self.owner_cell_type_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.refund.as_slice())?;
+        writer.write_all(self.memo.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientCellDataV3::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientCellDataV4(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientCellDataV4 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientCellDataV4 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientCellDataV4 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RecipientCellDataV4 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            166, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 53, 0, 0, 0, 85, 0, 0, 0, 86, 0,
+            0, 0, 118, 0, 0, 0, 134, 0, 0, 0, 134, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RecipientCellDataV4::new_unchecked(v.into())
+    }
+}
+impl RecipientCellDataV4 {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn asset(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn refund(&self) -> RecipientRefundOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn memo(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn evm_destination(&self) -> EvmDestination {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            EvmDestination::new_unchecked(self.0.slice(start..end))
+        } else {
+            EvmDestination::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientCellDataV4Reader<'r> {
+        RecipientCellDataV4Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientCellDataV4 {
+    type Builder = RecipientCellDataV4Builder;
+    const NAME: &'static str = "RecipientCellDataV4";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientCellDataV4(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV4Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV4Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .recipient_address(self.recipient_address())
+            .chain(self.chain())
+            .asset(self.asset())
+            .bridge_lock_code_hash(self.bridge_lock_code_hash())
+            .bridge_lock_hash_type(self.bridge_lock_hash_type())
+            .owner_cell_type_hash(self.owner_cell_type_hash())
+            .amount(self.amount())
+            .refund(self.refund())
+            .memo(self.memo())
+            .evm_destination(self.evm_destination())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientCellDataV4Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientCellDataV4Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientCellDataV4Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientCellDataV4Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RecipientCellDataV4Reader<'r> {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn asset(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn refund(&self) -> RecipientRefundOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn memo(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn evm_destination(&self) -> EvmDestinationReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            EvmDestinationReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            EvmDestinationReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataV4Reader<'r> {
+    type Entity = RecipientCellDataV4;
+    const NAME: &'static str = "RecipientCellDataV4Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientCellDataV4Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        RecipientRefundOptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        BytesReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        EvmDestinationReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientCellDataV4Builder {
+    pub(crate) recipient_address: Bytes,
+    pub(crate) chain: Byte,
+    pub(crate) asset: Bytes,
+    pub(crate) bridge_lock_code_hash: Byte32,
+    pub(crate) bridge_lock_hash_type: Byte,
+    pub(crate) owner_cell_type_hash: Byte32,
+    pub(crate) amount: Uint128,
+    pub(crate) refund: RecipientRefundOpt,
+    pub(crate) memo: Bytes,
+    pub(crate) evm_destination: EvmDestination,
+}
+impl RecipientCellDataV4Builder {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn recipient_address(mut self, v: Bytes) -> Self {
+        self.recipient_address = v;
+        self
+    }
+    pub fn chain(mut self, v: Byte) -> Self {
+        self.chain = v;
+        self
+    }
+    pub fn asset(mut self, v: Bytes) -> Self {
+        self.asset = v;
+        self
+    }
+    pub fn bridge_lock_code_hash(mut self, v: Byte32) -> Self {
+        self.bridge_lock_code_hash = v;
+        self
+    }
+    pub fn bridge_lock_hash_type(mut self, v: Byte) -> Self {
+        self.bridge_lock_hash_type = v;
+        self
+    }
+    pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
+        self.owner_cell_type_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn refund(mut self, v: RecipientRefundOpt) -> Self {
+        self.refund = v;
+        self
+    }
+    pub fn memo(mut self, v: Bytes) -> Self {
+        self.memo = v;
+        self
+    }
+    pub fn evm_destination(mut self, v: EvmDestination) -> Self {
+        self.evm_destination = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientCellDataV4Builder {
+    type Entity = RecipientCellDataV4;
+    const NAME: &'static str = "RecipientCellDataV4Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.recipient_address.as_slice().len()
+            + self.chain.as_slice().len()
+            + self.asset.as_slice().len()
+            + self.bridge_lock_code_hash.as_slice().len()
+            + self.bridge_lock_hash_type.as_slice().len()
+            + self.owner_cell_type_hash.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.refund.as_slice().len()
+            + self.memo.as_slice().len()
+            + self.evm_destination.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.recipient_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.asset.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.owner_cell_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.refund.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.memo.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.evm_destination.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.recipient_address.as_slice())?;
+        writer.write_all(self.chain.as_slice())?;
+        writer.write_all(self.asset.as_slice())?;
+        writer.write_all(self.bridge_lock_code_hash.as_slice())?;
+        writer.write_all(self.bridge_lock_hash_type.as_slice())?;
+        writer.write_all(self.owner_cell_type_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.refund.as_slice())?;
+        writer.write_all(self.memo.as_slice())?;
+        writer.write_all(self.evm_destination.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientCellDataV4::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct RecipientCellDataV5(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RecipientCellDataV5 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RecipientCellDataV5 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RecipientCellDataV5 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RecipientCellDataV5 {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            138, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0, 0, 53, 0, 0, 0, 85, 0, 0, 0, 86, 0,
+            0, 0, 118, 0, 0, 0, 134, 0, 0, 0, 134, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RecipientCellDataV5::new_unchecked(v.into())
+    }
+}
+impl RecipientCellDataV5 {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn chain(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn asset(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn bridge_lock_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn refund(&self) -> RecipientRefundOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn memo(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn evm_destination(&self) -> EvmDestinationOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            EvmDestinationOpt::new_unchecked(self.0.slice(start..end))
+        } else {
+            EvmDestinationOpt::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RecipientCellDataV5Reader<'r> {
+        RecipientCellDataV5Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RecipientCellDataV5 {
+    type Builder = RecipientCellDataV5Builder;
+    const NAME: &'static str = "RecipientCellDataV5";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RecipientCellDataV5(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV5Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RecipientCellDataV5Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .recipient_address(self.recipient_address())
+            .chain(self.chain())
+            .asset(self.asset())
+            .bridge_lock_code_hash(self.bridge_lock_code_hash())
+            .bridge_lock_hash_type(self.bridge_lock_hash_type())
+            .owner_cell_type_hash(self.owner_cell_type_hash())
+            .amount(self.amount())
+            .refund(self.refund())
+            .memo(self.memo())
+            .evm_destination(self.evm_destination())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RecipientCellDataV5Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RecipientCellDataV5Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RecipientCellDataV5Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RecipientCellDataV5Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "recipient_address", self.recipient_address())?;
+        write!(f, ", {}: {}", "chain", self.chain())?;
+        write!(f, ", {}: {}", "asset", self.asset())?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_code_hash",
+            self.bridge_lock_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "bridge_lock_hash_type",
+            self.bridge_lock_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "owner_cell_type_hash",
+            self.owner_cell_type_hash()
+        )?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "refund", self.refund())?;
+        write!(f, ", {}: {}", "memo", self.memo())?;
+        write!(f, ", {}: {}", "evm_destination", self.evm_destination())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RecipientCellDataV5Reader<'r> {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn recipient_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn chain(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn asset(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn bridge_lock_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn owner_cell_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn refund(&self) -> RecipientRefundOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        RecipientRefundOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn memo(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn evm_destination(&self) -> EvmDestinationOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            EvmDestinationOptReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            EvmDestinationOptReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RecipientCellDataV5Reader<'r> {
+    type Entity = RecipientCellDataV5;
+    const NAME: &'static str = "RecipientCellDataV5Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RecipientCellDataV5Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        RecipientRefundOptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        BytesReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        EvmDestinationOptReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RecipientCellDataV5Builder {
+    pub(crate) recipient_address: Bytes,
+    pub(crate) chain: Byte,
+    pub(crate) asset: Bytes,
+    pub(crate) bridge_lock_code_hash: Byte32,
+    pub(crate) bridge_lock_hash_type: Byte,
+    pub(crate) owner_cell_type_hash: Byte32,
+    pub(crate) amount: Uint128,
+    pub(crate) refund: RecipientRefundOpt,
+    pub(crate) memo: Bytes,
+    pub(crate) evm_destination: EvmDestinationOpt,
+}
+impl RecipientCellDataV5Builder {
+    pub const FIELD_COUNT: usize = 10;
+    pub fn recipient_address(mut self, v: Bytes) -> Self {
+        self.recipient_address = v;
+        self
+    }
+    pub fn chain(mut self, v: Byte) -> Self {
+        self.chain = v;
+        self
+    }
+    pub fn asset(mut self, v: Bytes) -> Self {
+        self.asset = v;
+        self
+    }
+    pub fn bridge_lock_code_hash(mut self, v: Byte32) -> Self {
+        self.bridge_lock_code_hash = v;
+        self
+    }
+    pub fn bridge_lock_hash_type(mut self, v: Byte) -> Self {
+        self.bridge_lock_hash_type = v;
+        self
+    }
+    pub fn owner_cell_type_hash(mut self, v: Byte32) -> Self {
+        self.owner_cell_type_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn refund(mut self, v: RecipientRefundOpt) -> Self {
+        self.refund = v;
+        self
+    }
+    pub fn memo(mut self, v: Bytes) -> Self {
+        self.memo = v;
+        self
+    }
+    pub fn evm_destination(mut self, v: EvmDestinationOpt) -> Self {
+        self.evm_destination = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RecipientCellDataV5Builder {
+    type Entity = RecipientCellDataV5;
+    const NAME: &'static str = "RecipientCellDataV5Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.recipient_address.as_slice().len()
+            + self.chain.as_slice().len()
+            + self.asset.as_slice().len()
+            + self.bridge_lock_code_hash.as_slice().len()
+            + self.bridge_lock_hash_type.as_slice().len()
+            + self.owner_cell_type_hash.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.refund.as_slice().len()
+            + self.memo.as_slice().len()
+            + self.evm_destination.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.recipient_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.chain.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.asset.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.bridge_lock_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.owner_cell_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.refund.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.memo.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.evm_destination.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.recipient_address.as_slice())?;
+        writer.write_all(self.chain.as_slice())?;
+        writer.write_all(self.asset.as_slice())?;
+        writer.write_all(self.bridge_lock_code_hash.as_slice())?;
+        writer.write_all(self.bridge_lock_hash_type.as_slice())?;
+        writer.write_all(self.owner_cell_type_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.refund.as_slice())?;
+        writer.write_all(self.memo.as_slice())?;
+        writer.write_all(self.evm_destination.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RecipientCellDataV5::new_unchecked(inner.into())
     }
 }
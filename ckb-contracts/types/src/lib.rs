@@ -3,8 +3,16 @@
 extern crate alloc;
 extern crate no_std_compat as std;
 
+pub mod bridge_args;
 pub mod config;
 pub mod convert;
+pub mod decimals;
+pub mod evm;
 pub mod generated;
 pub mod hasher;
+pub mod mint_witness;
+pub mod owner_cell;
+pub mod pending_mint;
 pub mod recipient_cell;
+pub mod token_info;
+pub mod versioned;
@@ -0,0 +1,351 @@
+use crate::generated::basic::Byte32;
+use crate::generated::mint_witness::{
+    BytesVec, MintRecord, MintRecordV2, MintRecordV2Reader, MintRecordV2Vec, MintRecordVec,
+    MintWitness, MintWitnessReader, MintWitnessV2, MintWitnessV3, MintWitnessV4, RefundRecord,
+    RefundRecordVec, VersionedMintWitness, VersionedMintWitnessReader,
+    VersionedMintWitnessUnionReader,
+};
+use core::convert::TryInto;
+use core::result::Result;
+use molecule::{
+    bytes::Bytes,
+    error::VerificationError,
+    prelude::{Builder, Entity, Reader},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec::Vec};
+
+/// Version of the `VersionedMintWitness` union item a bridge cell witness carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MintWitnessVersion {
+    /// The `MintWitness` table written before the witness was versioned, whose records only
+    /// carry the lock tx hash. A table starts with its size, never a union item id.
+    V1,
+    V2,
+    /// V2 with the fee of every record.
+    V3,
+    /// V3 with refunds.
+    V4,
+}
+
+/// A lock event in xchain and the sUDT it mints: `amount - fee` to the recipient, and `fee` to
+/// the fee collector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintRecordView {
+    pub lock_tx_hash: Vec<u8>,
+    pub log_index: u64,
+    pub amount: u128,
+    /// Always 0 in a V2 witness.
+    pub fee: u128,
+    pub recipient_lock_hash: [u8; 32],
+}
+
+/// A recipient cell nobody relayed the tx refunds, by minting the sUDT its burn took back to its
+/// refund lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RefundRecordView {
+    /// Index of the recipient cell in the inputs.
+    pub recipient_cell_index: u32,
+}
+
+/// The `VersionedMintWitness` of a bridge cell in a mint tx.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintWitnessView {
+    pub version: MintWitnessVersion,
+    pub records: Vec<MintRecordView>,
+    /// Only carried by V4 witnesses.
+    pub refunds: Vec<RefundRecordView>,
+}
+
+// The record of a reader of any version, with the fee it carries.
+macro_rules! record_from_reader {
+    ($reader:expr, $fee:expr) => {{
+        let reader = $reader;
+        let mut log_index = [0u8; 8];
+        log_index.copy_from_slice(reader.log_index().raw_data());
+
+        let mut amount = [0u8; 16];
+        amount.copy_from_slice(reader.amount().raw_data());
+
+        let mut recipient_lock_hash = [0u8; 32];
+        recipient_lock_hash.copy_from_slice(reader.recipient_lock_hash().raw_data());
+
+        MintRecordView {
+            lock_tx_hash: reader.lock_tx_hash().raw_data().to_vec(),
+            log_index: u64::from_le_bytes(log_index),
+            amount: u128::from_le_bytes(amount),
+            fee: $fee,
+            recipient_lock_hash,
+        }
+    }};
+}
+
+impl MintWitnessView {
+    pub fn new(data: &[u8]) -> Result<MintWitnessView, VerificationError> {
+        if let Err(err) = VersionedMintWitnessReader::verify(data, false) {
+            return match MintWitnessReader::verify(data, false) {
+                Ok(()) => Ok(Self::from_v1(MintWitnessReader::new_unchecked(data))),
+                Err(_) => Err(err),
+            };
+        }
+        let witness = match VersionedMintWitnessReader::new_unchecked(data).to_enum() {
+            VersionedMintWitnessUnionReader::MintWitnessV2(reader) => MintWitnessView {
+                version: MintWitnessVersion::V2,
+                records: reader
+                    .records()
+                    .iter()
+                    .map(|reader| record_from_reader!(reader, 0))
+                    .collect(),
+                refunds: Vec::new(),
+            },
+            VersionedMintWitnessUnionReader::MintWitnessV3(reader) => MintWitnessView {
+                version: MintWitnessVersion::V3,
+                records: reader.records().iter().map(record_with_fee).collect(),
+                refunds: Vec::new(),
+            },
+            VersionedMintWitnessUnionReader::MintWitnessV4(reader) => MintWitnessView {
+                version: MintWitnessVersion::V4,
+                records: reader.records().iter().map(record_with_fee).collect(),
+                refunds: reader
+                    .refunds()
+                    .iter()
+                    .map(|reader| {
+                        let mut index = [0u8; 4];
+                        index.copy_from_slice(reader.recipient_cell_index().raw_data());
+                        RefundRecordView {
+                            recipient_cell_index: u32::from_le_bytes(index),
+                        }
+                    })
+                    .collect(),
+            },
+        };
+        Ok(witness)
+    }
+
+    fn from_v1(reader: MintWitnessReader) -> MintWitnessView {
+        MintWitnessView {
+            version: MintWitnessVersion::V1,
+            records: reader
+                .lock_tx_hashes()
+                .iter()
+                .map(|lock_tx_hash| MintRecordView {
+                    lock_tx_hash: lock_tx_hash.raw_data().to_vec(),
+                    log_index: 0,
+                    amount: 0,
+                    fee: 0,
+                    recipient_lock_hash: [0u8; 32],
+                })
+                .collect(),
+            refunds: Vec::new(),
+        }
+    }
+
+    pub fn as_molecule_data(&self) -> Result<Bytes, VerificationError> {
+        if self.version != MintWitnessVersion::V4 && !self.refunds.is_empty() {
+            return Err(VerificationError::FieldCountNotMatch(
+                "MintWitnessV3".to_string(),
+                1,
+                2,
+            ));
+        }
+        let witness = match self.version {
+            MintWitnessVersion::V1 => return Ok(self.as_molecule_data_v1().as_bytes()),
+            MintWitnessVersion::V2 => self.as_molecule_data_v2()?,
+            MintWitnessVersion::V3 => VersionedMintWitness::new_builder()
+                .set(
+                    MintWitnessV3::new_builder()
+                        .records(self.records_v2())
+                        .build(),
+                )
+                .build(),
+            MintWitnessVersion::V4 => self.as_molecule_data_v4(),
+        };
+        Ok(witness.as_bytes())
+    }
+
+    // only the lock tx hashes of the records are written.
+    fn as_molecule_data_v1(&self) -> MintWitness {
+        let lock_tx_hashes = self
+            .records
+            .iter()
+            .map(|record| record.lock_tx_hash.clone().into())
+            .collect::<Vec<_>>();
+        MintWitness::new_builder()
+            .lock_tx_hashes(BytesVec::new_builder().set(lock_tx_hashes).build())
+            .build()
+    }
+
+    fn as_molecule_data_v2(&self) -> Result<VersionedMintWitness, VerificationError> {
+        if self.records.iter().any(|record| record.fee != 0) {
+            return Err(VerificationError::FieldCountNotMatch(
+                "MintRecord".to_string(),
+                4,
+                5,
+            ));
+        }
+        let records = self
+            .records
+            .iter()
+            .map(|record| {
+                MintRecord::new_builder()
+                    .lock_tx_hash(record.lock_tx_hash.clone().into())
+                    .log_index(record.log_index.into())
+                    .amount(record.amount.into())
+                    .recipient_lock_hash(record.recipient_lock_hash_molecule())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let v2 = MintWitnessV2::new_builder()
+            .records(MintRecordVec::new_builder().set(records).build())
+            .build();
+        Ok(VersionedMintWitness::new_builder().set(v2).build())
+    }
+
+    fn as_molecule_data_v4(&self) -> VersionedMintWitness {
+        let refunds = self
+            .refunds
+            .iter()
+            .map(|refund| {
+                RefundRecord::new_builder()
+                    .recipient_cell_index(refund.recipient_cell_index.into())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let v4 = MintWitnessV4::new_builder()
+            .records(self.records_v2())
+            .refunds(RefundRecordVec::new_builder().set(refunds).build())
+            .build();
+        VersionedMintWitness::new_builder().set(v4).build()
+    }
+
+    fn records_v2(&self) -> MintRecordV2Vec {
+        let records = self
+            .records
+            .iter()
+            .map(|record| {
+                MintRecordV2::new_builder()
+                    .lock_tx_hash(record.lock_tx_hash.clone().into())
+                    .log_index(record.log_index.into())
+                    .amount(record.amount.into())
+                    .fee(record.fee.into())
+                    .recipient_lock_hash(record.recipient_lock_hash_molecule())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        MintRecordV2Vec::new_builder().set(records).build()
+    }
+}
+
+fn record_with_fee(reader: MintRecordV2Reader) -> MintRecordView {
+    let mut fee = [0u8; 16];
+    fee.copy_from_slice(reader.fee().raw_data());
+    record_from_reader!(reader, u128::from_le_bytes(fee))
+}
+
+impl MintRecordView {
+    fn recipient_lock_hash_molecule(&self) -> Byte32 {
+        self.recipient_lock_hash
+            .to_vec()
+            .try_into()
+            .expect("recipient_lock_hash convert fail")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MintRecordView, MintWitnessVersion, MintWitnessView, RefundRecordView};
+
+    fn record(log_index: u64, amount: u128, fee: u128) -> MintRecordView {
+        MintRecordView {
+            lock_tx_hash: vec![1u8; 32],
+            log_index,
+            amount,
+            fee,
+            recipient_lock_hash: [3u8; 32],
+        }
+    }
+
+    #[test]
+    fn test_mint_witness() {
+        let mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V2,
+            records: vec![record(2, 100, 0), record(5, u128::MAX, 0)],
+            refunds: vec![],
+        };
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+        assert!(MintWitnessView::new(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_mint_witness_with_fees() {
+        let mut mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V3,
+            records: vec![record(2, 100, 0)],
+            refunds: vec![],
+        };
+        // a V3 witness stays a V3 one without fees.
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+
+        mint_witness.records[0].fee = 3;
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+        assert!(MintWitnessView::new(&data[..data.len() - 1]).is_err());
+
+        mint_witness.version = MintWitnessVersion::V2;
+        assert!(mint_witness.as_molecule_data().is_err());
+    }
+
+    #[test]
+    fn test_legacy_mint_witness() {
+        // the records of a legacy witness only carry the lock tx hash.
+        let lock_tx_record = |lock_tx_hash: u8| MintRecordView {
+            lock_tx_hash: vec![lock_tx_hash; 32],
+            recipient_lock_hash: [0u8; 32],
+            ..record(0, 0, 0)
+        };
+        let mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V1,
+            records: vec![lock_tx_record(1), lock_tx_record(2)],
+            refunds: vec![],
+        };
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+        assert!(MintWitnessView::new(&data[..data.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_unknown_mint_witness_version() {
+        let mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V2,
+            records: vec![record(2, 100, 0)],
+            refunds: vec![],
+        };
+        let mut data = mint_witness.as_molecule_data().unwrap().to_vec();
+        data[0] = 0xff;
+        assert!(MintWitnessView::new(&data).is_err());
+    }
+
+    #[test]
+    fn test_mint_witness_with_refunds() {
+        let mut mint_witness = MintWitnessView {
+            version: MintWitnessVersion::V4,
+            records: vec![record(2, 100, 3)],
+            refunds: vec![RefundRecordView {
+                recipient_cell_index: 2,
+            }],
+        };
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+
+        // a refund alone mints nothing to a lock event.
+        mint_witness.records.clear();
+        let data = mint_witness.as_molecule_data().unwrap();
+        assert_eq!(MintWitnessView::new(&data).unwrap(), mint_witness);
+
+        mint_witness.version = MintWitnessVersion::V3;
+        assert!(mint_witness.as_molecule_data().is_err());
+    }
+}
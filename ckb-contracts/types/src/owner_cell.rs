@@ -0,0 +1,516 @@
+use crate::bridge_args::BridgeArgsRef;
+use crate::decimals::AssetDecimals;
+use crate::evm::EvmDestination;
+use crate::generated::evm::EvmDestinationOpt;
+use crate::generated::owner_cell::{
+    AssetDecimals as AssetDecimalsMol, AssetDecimalsVec, AssetDecimalsVecReader, BridgedAsset,
+    BridgedAssetReader, EvmDestinationVec, EvmDestinationVecReader, LargeMint, LargeMintOpt,
+    LargeMintOptReader, MintFee, MintFeeOpt, MintFeeOptReader, MintThreshold, MintThresholdVec,
+    MintThresholdVecReader, OwnerCellData, OwnerCellDataReader,
+    RecipientTypescriptConfig as RecipientTypescriptConfigMol, RecipientTypescriptConfigOpt,
+    RecipientTypescriptConfigOptReader, TokenInfoConfig as TokenInfoConfigMol, TokenInfoConfigOpt,
+    TokenInfoConfigOptReader, TwoPhaseMint, TwoPhaseMintOpt, TwoPhaseMintOptReader,
+};
+use core::convert::TryInto;
+use core::result::Result;
+use molecule::{
+    bytes::Bytes,
+    error::VerificationError,
+    prelude::{Builder, Entity, Reader},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// An asset the owner bridges, keyed as in the bridge lockscript args.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgedAssetView {
+    pub chain: u8,
+    /// Raw bytes of the asset, as in the bridge lockscript args.
+    pub asset: Vec<u8>,
+    /// Only set for the bridge of the asset to an EVM network.
+    pub evm_destination: Option<EvmDestination>,
+}
+
+impl BridgedAssetView {
+    /// Whether the bridge of `args` bridges this asset.
+    pub fn is_bridged_by(&self, args: &BridgeArgsRef) -> bool {
+        self.chain == args.chain
+            && self.asset == args.asset
+            && self.evm_destination == args.evm_destination
+    }
+}
+
+/// Mints stay pending for at least `challenge_period` epochs, the guardian can cancel them until
+/// they are finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TwoPhaseMintConfig {
+    pub guardian_lock_hash: [u8; 32],
+    pub challenge_period: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintThresholdView {
+    pub bridged_asset: BridgedAssetView,
+    pub threshold: u128,
+}
+
+/// The typescript of the token info cells. Mints of a bridge need the token info cell of its
+/// sUDT, created by the first mint and referenced by the later ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenInfoConfig {
+    pub code_hash: [u8; 32],
+    pub hash_type: u8,
+}
+
+/// The fees of the mint records are minted to the collector lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MintFeeConfig {
+    pub collector_lock_hash: [u8; 32],
+}
+
+/// The recipient typescript. The owner refunds a recipient cell it types by minting the sUDT its
+/// burn took back to its refund lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecipientTypescriptConfig {
+    pub code_hash: [u8; 32],
+    pub hash_type: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetDecimalsView {
+    pub bridged_asset: BridgedAssetView,
+    pub decimals: AssetDecimals,
+}
+
+/// Mints of an asset above its threshold need the high value signer as well as the owner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LargeMintConfig {
+    pub signer_lock_hash: [u8; 32],
+    pub thresholds: Vec<MintThresholdView>,
+}
+
+impl LargeMintConfig {
+    /// The threshold of the asset the bridge of `args` bridges, `None` if it has none.
+    pub fn threshold(&self, args: &BridgeArgsRef) -> Option<u128> {
+        self.thresholds
+            .iter()
+            .find(|threshold| threshold.bridged_asset.is_bridged_by(args))
+            .map(|threshold| threshold.threshold)
+    }
+}
+
+/// The mint rules an owner cell sets for the bridges it owns, every one of them optional.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OwnerCellView {
+    pub two_phase_mint: Option<TwoPhaseMintConfig>,
+    pub large_mint: Option<LargeMintConfig>,
+    /// An asset without decimals is bridged one to one.
+    pub asset_decimals: Vec<AssetDecimalsView>,
+    /// Once set, every mint needs the token info cell of its sUDT, none is needed when unset.
+    pub token_info: Option<TokenInfoConfig>,
+    /// Mints without it take no fee.
+    pub mint_fee: Option<MintFeeConfig>,
+    /// Refunds need it.
+    pub recipient_typescript: Option<RecipientTypescriptConfig>,
+    /// The EVM networks the owner bridges assets to, mints and burns to any other are rejected.
+    pub evm_destinations: Vec<EvmDestination>,
+}
+
+impl OwnerCellView {
+    pub fn new(data: &[u8]) -> Result<OwnerCellView, VerificationError> {
+        let data_reader = OwnerCellDataReader::from_slice(data)?;
+        Ok(OwnerCellView {
+            two_phase_mint: two_phase_mint_from_reader(data_reader.two_phase_mint()),
+            large_mint: large_mint_from_reader(data_reader.large_mint()),
+            asset_decimals: asset_decimals_from_reader(data_reader.asset_decimals()),
+            token_info: token_info_from_reader(data_reader.token_info()),
+            mint_fee: mint_fee_from_reader(data_reader.mint_fee()),
+            recipient_typescript: recipient_typescript_from_reader(
+                data_reader.recipient_typescript(),
+            ),
+            evm_destinations: evm_destinations_from_reader(data_reader.evm_destinations()),
+        })
+    }
+
+    /// The decimals of the asset the bridge of `args` bridges, `None` if it is bridged one to
+    /// one.
+    pub fn decimals(&self, args: &BridgeArgsRef) -> Option<AssetDecimals> {
+        self.asset_decimals
+            .iter()
+            .find(|decimals| decimals.bridged_asset.is_bridged_by(args))
+            .map(|decimals| decimals.decimals)
+    }
+
+    pub fn as_molecule_data(&self) -> Bytes {
+        OwnerCellData::new_builder()
+            .two_phase_mint(self.two_phase_mint_molecule())
+            .large_mint(self.large_mint_molecule())
+            .asset_decimals(self.asset_decimals_molecule())
+            .token_info(self.token_info_molecule())
+            .mint_fee(self.mint_fee_molecule())
+            .recipient_typescript(self.recipient_typescript_molecule())
+            .evm_destinations(self.evm_destinations_molecule())
+            .build()
+            .as_bytes()
+    }
+
+    fn two_phase_mint_molecule(&self) -> TwoPhaseMintOpt {
+        let two_phase_mint = self.two_phase_mint.map(|two_phase_mint| {
+            TwoPhaseMint::new_builder()
+                .guardian_lock_hash(
+                    two_phase_mint
+                        .guardian_lock_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("guardian_lock_hash convert fail"),
+                )
+                .challenge_period(two_phase_mint.challenge_period.into())
+                .build()
+        });
+        TwoPhaseMintOpt::new_builder().set(two_phase_mint).build()
+    }
+
+    fn large_mint_molecule(&self) -> LargeMintOpt {
+        let large_mint = self.large_mint.as_ref().map(|large_mint| {
+            LargeMint::new_builder()
+                .high_value_signer_lock_hash(
+                    large_mint
+                        .signer_lock_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("high_value_signer_lock_hash convert fail"),
+                )
+                .mint_thresholds(large_mint.thresholds_molecule())
+                .build()
+        });
+        LargeMintOpt::new_builder().set(large_mint).build()
+    }
+
+    fn asset_decimals_molecule(&self) -> AssetDecimalsVec {
+        let asset_decimals = self
+            .asset_decimals
+            .iter()
+            .map(|decimals| {
+                AssetDecimalsMol::new_builder()
+                    .bridged_asset(decimals.bridged_asset.as_molecule())
+                    .xchain_decimals(decimals.decimals.xchain_decimals.into())
+                    .sudt_decimals(decimals.decimals.sudt_decimals.into())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        AssetDecimalsVec::new_builder().set(asset_decimals).build()
+    }
+
+    fn token_info_molecule(&self) -> TokenInfoConfigOpt {
+        let token_info = self.token_info.map(|token_info| {
+            TokenInfoConfigMol::new_builder()
+                .code_hash(
+                    token_info
+                        .code_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("code_hash convert fail"),
+                )
+                .hash_type(token_info.hash_type.into())
+                .build()
+        });
+        TokenInfoConfigOpt::new_builder().set(token_info).build()
+    }
+
+    fn mint_fee_molecule(&self) -> MintFeeOpt {
+        let mint_fee = self.mint_fee.map(|mint_fee| {
+            MintFee::new_builder()
+                .collector_lock_hash(
+                    mint_fee
+                        .collector_lock_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("collector_lock_hash convert fail"),
+                )
+                .build()
+        });
+        MintFeeOpt::new_builder().set(mint_fee).build()
+    }
+
+    fn evm_destinations_molecule(&self) -> EvmDestinationVec {
+        let evm_destinations = self
+            .evm_destinations
+            .iter()
+            .map(EvmDestination::as_molecule)
+            .collect::<Vec<_>>();
+        EvmDestinationVec::new_builder()
+            .set(evm_destinations)
+            .build()
+    }
+
+    fn recipient_typescript_molecule(&self) -> RecipientTypescriptConfigOpt {
+        let recipient_typescript = self.recipient_typescript.map(|recipient_typescript| {
+            RecipientTypescriptConfigMol::new_builder()
+                .code_hash(
+                    recipient_typescript
+                        .code_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("code_hash convert fail"),
+                )
+                .hash_type(recipient_typescript.hash_type.into())
+                .build()
+        });
+        RecipientTypescriptConfigOpt::new_builder()
+            .set(recipient_typescript)
+            .build()
+    }
+}
+
+impl LargeMintConfig {
+    fn thresholds_molecule(&self) -> MintThresholdVec {
+        let mint_thresholds = self
+            .thresholds
+            .iter()
+            .map(|threshold| {
+                MintThreshold::new_builder()
+                    .bridged_asset(threshold.bridged_asset.as_molecule())
+                    .threshold(threshold.threshold.into())
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        MintThresholdVec::new_builder().set(mint_thresholds).build()
+    }
+}
+
+impl BridgedAssetView {
+    fn from_reader(reader: BridgedAssetReader) -> BridgedAssetView {
+        BridgedAssetView {
+            chain: reader.chain().to_entity().into(),
+            asset: reader.asset().raw_data().to_vec(),
+            evm_destination: reader
+                .evm_destination()
+                .to_opt()
+                .map(EvmDestination::from_reader),
+        }
+    }
+
+    fn as_molecule(&self) -> BridgedAsset {
+        let evm_destination = self
+            .evm_destination
+            .map(|evm_destination| evm_destination.as_molecule());
+        BridgedAsset::new_builder()
+            .chain(self.chain.into())
+            .asset(self.asset.clone().into())
+            .evm_destination(
+                EvmDestinationOpt::new_builder()
+                    .set(evm_destination)
+                    .build(),
+            )
+            .build()
+    }
+}
+
+fn two_phase_mint_from_reader(reader: TwoPhaseMintOptReader) -> Option<TwoPhaseMintConfig> {
+    reader.to_opt().map(|reader| {
+        let mut guardian_lock_hash = [0u8; 32];
+        guardian_lock_hash.copy_from_slice(reader.guardian_lock_hash().raw_data());
+
+        let mut challenge_period = [0u8; 8];
+        challenge_period.copy_from_slice(reader.challenge_period().raw_data());
+
+        TwoPhaseMintConfig {
+            guardian_lock_hash,
+            challenge_period: u64::from_le_bytes(challenge_period),
+        }
+    })
+}
+
+fn large_mint_from_reader(reader: LargeMintOptReader) -> Option<LargeMintConfig> {
+    reader.to_opt().map(|reader| {
+        let mut signer_lock_hash = [0u8; 32];
+        signer_lock_hash.copy_from_slice(reader.high_value_signer_lock_hash().raw_data());
+        LargeMintConfig {
+            signer_lock_hash,
+            thresholds: thresholds_from_reader(reader.mint_thresholds()),
+        }
+    })
+}
+
+fn token_info_from_reader(reader: TokenInfoConfigOptReader) -> Option<TokenInfoConfig> {
+    reader.to_opt().map(|reader| {
+        let mut code_hash = [0u8; 32];
+        code_hash.copy_from_slice(reader.code_hash().raw_data());
+        TokenInfoConfig {
+            code_hash,
+            hash_type: reader.hash_type().to_entity().into(),
+        }
+    })
+}
+
+fn mint_fee_from_reader(reader: MintFeeOptReader) -> Option<MintFeeConfig> {
+    reader.to_opt().map(|reader| {
+        let mut collector_lock_hash = [0u8; 32];
+        collector_lock_hash.copy_from_slice(reader.collector_lock_hash().raw_data());
+        MintFeeConfig {
+            collector_lock_hash,
+        }
+    })
+}
+
+fn recipient_typescript_from_reader(
+    reader: RecipientTypescriptConfigOptReader,
+) -> Option<RecipientTypescriptConfig> {
+    reader.to_opt().map(|reader| {
+        let mut code_hash = [0u8; 32];
+        code_hash.copy_from_slice(reader.code_hash().raw_data());
+        RecipientTypescriptConfig {
+            code_hash,
+            hash_type: reader.hash_type().to_entity().into(),
+        }
+    })
+}
+
+fn evm_destinations_from_reader(reader: EvmDestinationVecReader) -> Vec<EvmDestination> {
+    reader.iter().map(EvmDestination::from_reader).collect()
+}
+
+fn asset_decimals_from_reader(reader: AssetDecimalsVecReader) -> Vec<AssetDecimalsView> {
+    reader
+        .iter()
+        .map(|reader| AssetDecimalsView {
+            bridged_asset: BridgedAssetView::from_reader(reader.bridged_asset()),
+            decimals: AssetDecimals {
+                xchain_decimals: reader.xchain_decimals().to_entity().into(),
+                sudt_decimals: reader.sudt_decimals().to_entity().into(),
+            },
+        })
+        .collect()
+}
+
+fn thresholds_from_reader(reader: MintThresholdVecReader) -> Vec<MintThresholdView> {
+    reader
+        .iter()
+        .map(|reader| {
+            let mut threshold = [0u8; 16];
+            threshold.copy_from_slice(reader.threshold().raw_data());
+            MintThresholdView {
+                bridged_asset: BridgedAssetView::from_reader(reader.bridged_asset()),
+                threshold: u128::from_le_bytes(threshold),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AssetDecimalsView, BridgedAssetView, LargeMintConfig, MintFeeConfig, MintThresholdView,
+        OwnerCellView, RecipientTypescriptConfig, TokenInfoConfig, TwoPhaseMintConfig,
+    };
+    use crate::bridge_args::BridgeArgsRef;
+    use crate::decimals::AssetDecimals;
+    use crate::evm::EvmDestination;
+
+    const BSC: EvmDestination = EvmDestination {
+        chain_id: 56,
+        bridge_contract_address: [1u8; 20],
+    };
+
+    fn args(asset: &'static [u8], evm_destination: Option<EvmDestination>) -> BridgeArgsRef {
+        BridgeArgsRef {
+            owner_cell_type_hash: &[100u8; 32],
+            chain: 1,
+            asset,
+            evm_destination,
+        }
+    }
+
+    fn trx(evm_destination: Option<EvmDestination>) -> BridgedAssetView {
+        BridgedAssetView {
+            chain: 1,
+            asset: b"trx".to_vec(),
+            evm_destination,
+        }
+    }
+
+    #[test]
+    fn test_owner_cell_data() {
+        let owner_cell = OwnerCellView {
+            two_phase_mint: Some(TwoPhaseMintConfig {
+                guardian_lock_hash: [1u8; 32],
+                challenge_period: 6,
+            }),
+            large_mint: Some(LargeMintConfig {
+                signer_lock_hash: [2u8; 32],
+                thresholds: vec![MintThresholdView {
+                    bridged_asset: trx(None),
+                    threshold: 1000,
+                }],
+            }),
+            asset_decimals: vec![AssetDecimalsView {
+                bridged_asset: trx(Some(BSC)),
+                decimals: AssetDecimals {
+                    xchain_decimals: 18,
+                    sudt_decimals: 8,
+                },
+            }],
+            token_info: Some(TokenInfoConfig {
+                code_hash: [3u8; 32],
+                hash_type: 1,
+            }),
+            mint_fee: Some(MintFeeConfig {
+                collector_lock_hash: [4u8; 32],
+            }),
+            recipient_typescript: Some(RecipientTypescriptConfig {
+                code_hash: [5u8; 32],
+                hash_type: 0,
+            }),
+            evm_destinations: vec![BSC],
+        };
+        let mol_data = owner_cell.as_molecule_data();
+        assert_eq!(OwnerCellView::new(mol_data.as_ref()).unwrap(), owner_cell);
+        assert!(OwnerCellView::new(&mol_data[..mol_data.len() - 1]).is_err());
+
+        let empty = OwnerCellView::default();
+        assert_eq!(
+            OwnerCellView::new(empty.as_molecule_data().as_ref()).unwrap(),
+            empty
+        );
+    }
+
+    #[test]
+    fn test_rules_are_keyed_by_the_bridge_args() {
+        let large_mint = LargeMintConfig {
+            signer_lock_hash: [2u8; 32],
+            thresholds: vec![
+                MintThresholdView {
+                    bridged_asset: trx(None),
+                    threshold: 1000,
+                },
+                MintThresholdView {
+                    bridged_asset: trx(Some(BSC)),
+                    threshold: 10,
+                },
+            ],
+        };
+        assert_eq!(large_mint.threshold(&args(b"trx", None)), Some(1000));
+        assert_eq!(large_mint.threshold(&args(b"trx", Some(BSC))), Some(10));
+        assert_eq!(large_mint.threshold(&args(b"eth", None)), None);
+        let ethereum = EvmDestination { chain_id: 1, ..BSC };
+        assert_eq!(large_mint.threshold(&args(b"trx", Some(ethereum))), None);
+
+        let decimals = AssetDecimals {
+            xchain_decimals: 18,
+            sudt_decimals: 8,
+        };
+        let owner_cell = OwnerCellView {
+            asset_decimals: vec![AssetDecimalsView {
+                bridged_asset: trx(Some(BSC)),
+                decimals,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            owner_cell.decimals(&args(b"trx", Some(BSC))),
+            Some(decimals)
+        );
+        assert_eq!(owner_cell.decimals(&args(b"trx", None)), None);
+    }
+}
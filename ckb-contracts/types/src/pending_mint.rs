@@ -0,0 +1,81 @@
+use crate::config::UDT_LEN;
+use crate::generated::pending_mint::{PendingMint, PendingMintReader};
+use core::convert::TryInto;
+use core::result::Result;
+use molecule::{
+    error::VerificationError,
+    prelude::{Builder, Entity, Reader},
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{borrow::ToOwned, vec::Vec};
+
+/// Data of a pending mint cell: the sUDT amount followed by a `PendingMint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingMintView {
+    pub amount: u128,
+    pub recipient_lock_hash: [u8; 32],
+    pub lock_tx_hash: Vec<u8>,
+}
+
+impl PendingMintView {
+    pub fn from_sudt_data(data: &[u8]) -> Result<PendingMintView, VerificationError> {
+        if data.len() < UDT_LEN {
+            return Err(VerificationError::TotalSizeNotMatch(
+                "PendingMint".to_owned(),
+                UDT_LEN,
+                data.len(),
+            ));
+        }
+        let mut amount = [0u8; UDT_LEN];
+        amount.copy_from_slice(&data[..UDT_LEN]);
+
+        PendingMintReader::verify(&data[UDT_LEN..], false)?;
+        let data_reader = PendingMintReader::new_unchecked(&data[UDT_LEN..]);
+        let mut recipient_lock_hash = [0u8; 32];
+        recipient_lock_hash.copy_from_slice(data_reader.recipient_lock_hash().raw_data());
+
+        Ok(PendingMintView {
+            amount: u128::from_le_bytes(amount),
+            recipient_lock_hash,
+            lock_tx_hash: data_reader.lock_tx_hash().raw_data().to_vec(),
+        })
+    }
+
+    pub fn as_sudt_data(&self) -> Vec<u8> {
+        let pending_mint = PendingMint::new_builder()
+            .recipient_lock_hash(
+                self.recipient_lock_hash
+                    .to_vec()
+                    .try_into()
+                    .expect("recipient_lock_hash convert fail"),
+            )
+            .lock_tx_hash(self.lock_tx_hash.clone().into())
+            .build();
+        let mut data = self.amount.to_le_bytes().to_vec();
+        data.extend_from_slice(pending_mint.as_slice());
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PendingMintView;
+
+    #[test]
+    fn test_pending_mint_data() {
+        let pending_mint = PendingMintView {
+            amount: 100,
+            recipient_lock_hash: [1u8; 32],
+            lock_tx_hash: vec![2u8; 32],
+        };
+        let data = pending_mint.as_sudt_data();
+        assert_eq!(&data[..16], &100u128.to_le_bytes());
+        assert_eq!(
+            PendingMintView::from_sudt_data(&data).unwrap(),
+            pending_mint
+        );
+        assert!(PendingMintView::from_sudt_data(&data[..16]).is_err());
+        assert!(PendingMintView::from_sudt_data(&data[..15]).is_err());
+    }
+}
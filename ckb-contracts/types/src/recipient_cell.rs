@@ -1,4 +1,12 @@
-use crate::generated::recipient_typescript::{RecipientCellData, RecipientCellDataReader};
+use crate::evm::EvmDestination;
+use crate::generated::evm::EvmDestinationOpt;
+use crate::generated::recipient_typescript::{
+    RecipientCellDataV1, RecipientCellDataV1Reader, RecipientCellDataV2, RecipientCellDataV2Reader,
+    RecipientCellDataV3, RecipientCellDataV3Reader, RecipientCellDataV4, RecipientCellDataV4Reader,
+    RecipientCellDataV5, RecipientCellDataV5Reader, RecipientRefund as RecipientRefundMol,
+    RecipientRefundOpt, RecipientRefundReader,
+};
+use crate::versioned;
 use core::convert::TryInto;
 use core::result::Result;
 use molecule::{
@@ -8,27 +16,106 @@ use molecule::{
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Max length of the memo of a recipient cell.
+pub const MAX_MEMO_LEN: usize = 512;
+
+/// Version of the `RecipientCellData` a cell carries, see `versioned`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientDataVersion {
+    V1,
+    /// V1 with a `RecipientRefund`.
+    V2,
+    /// V1 with an optional `RecipientRefund`, and a memo.
+    V3,
+    /// V3 with an `EvmDestination`.
+    V4,
+    /// V4 with an optional `EvmDestination`, and the amount in xchain units.
+    V5,
+}
+
+impl RecipientDataVersion {
+    /// The amount of the data is in xchain units, scaled by the decimals the owner cell lists
+    /// for the asset. The amount of the earlier versions is the sUDT burned.
+    pub fn is_amount_in_xchain_units(self) -> bool {
+        self == RecipientDataVersion::V5
+    }
+}
+
+/// Where and from when a recipient cell nobody relayed can be refunded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecipientRefund {
+    /// Lock hash the re-minted sUDT is paid to.
+    pub lock_hash: [u8; 32],
+    /// Minimum input since of the recipient cell in a refund tx.
+    pub since: u64,
+}
+
+/// Errors decoding a `RecipientCellData`.
+#[derive(Debug)]
+pub enum RecipientDataError {
+    Molecule(VerificationError),
+    /// A string field is not valid UTF-8, carrying the name of the field.
+    InvalidUtf8(&'static str),
+}
+
+impl From<VerificationError> for RecipientDataError {
+    fn from(err: VerificationError) -> Self {
+        RecipientDataError::Molecule(err)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct RecipientDataView {
+    pub version: RecipientDataVersion,
     pub recipient_address: String,
     pub chain: u8,
     pub asset: String,
     pub bridge_lock_code_hash: [u8; 32],
     pub bridge_lock_hash_type: u8,
     pub owner_cell_type_hash: [u8; 32],
+    /// In xchain units for V5 data, see `RecipientDataVersion::is_amount_in_xchain_units`.
     pub amount: u128,
+    /// Always carried by V2 data, optional in later versions.
+    pub refund: Option<RecipientRefund>,
+    /// Forwarded to the xchain unlock, only carried by V3 data and later and empty otherwise.
+    pub memo: Vec<u8>,
+    /// Always carried by V4 data, optional in V5 data, set to burn the sUDT of a
+    /// `ForceBridgeLockscriptArgsV2` bridge.
+    pub evm_destination: Option<EvmDestination>,
 }
 
-impl RecipientDataView {
-    pub fn new(data: &[u8]) -> Result<RecipientDataView, VerificationError> {
-        RecipientCellDataReader::verify(data, false)?;
-        let data_reader = RecipientCellDataReader::new_unchecked(data);
+/// A `RecipientCellData` borrowed from the cell data it is read from, the recipient typescript
+/// checks a burn without copying the strings and the memo of the data.
+#[derive(Debug, Clone, Copy)]
+pub struct RecipientDataRef<'a> {
+    pub version: RecipientDataVersion,
+    pub recipient_address: &'a str,
+    pub chain: u8,
+    pub asset: &'a str,
+    pub bridge_lock_code_hash: [u8; 32],
+    pub bridge_lock_hash_type: u8,
+    pub owner_cell_type_hash: [u8; 32],
+    pub amount: u128,
+    pub refund: Option<RecipientRefund>,
+    pub memo: &'a [u8],
+    pub evm_destination: Option<EvmDestination>,
+}
 
-        let recipient_address = data_reader.recipient_address().to_entity().into();
+// The fields every version shares, read from a reader of any version.
+macro_rules! ref_from_reader {
+    ($data_reader:expr, $version:expr, $refund:expr, $memo:expr, $evm_destination:expr) => {{
+        let data_reader = $data_reader;
+        let recipient_address = utf8_field(
+            data_reader.recipient_address().raw_data(),
+            "recipient_address",
+        )?;
         let chain = data_reader.chain().to_entity().into();
-        let asset = data_reader.asset().to_entity().into();
+        let asset = utf8_field(data_reader.asset().raw_data(), "asset")?;
 
         let mut bridge_lock_code_hash = [0u8; 32];
         bridge_lock_code_hash.copy_from_slice(data_reader.bridge_lock_code_hash().raw_data());
@@ -42,7 +129,8 @@ impl RecipientDataView {
         amount.copy_from_slice(data_reader.amount().raw_data());
         let amount: u128 = u128::from_le_bytes(amount);
 
-        Ok(RecipientDataView {
+        Ok(RecipientDataRef {
+            version: $version,
             recipient_address,
             chain,
             asset,
@@ -50,40 +138,257 @@ impl RecipientDataView {
             bridge_lock_hash_type,
             owner_cell_type_hash,
             amount,
+            refund: $refund,
+            memo: $memo,
+            evm_destination: $evm_destination,
         })
+    }};
+}
+
+impl<'a> RecipientDataRef<'a> {
+    pub fn new(data: &'a [u8]) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        match versioned::split(data) {
+            Some((versioned::V1, table)) => {
+                Self::from_v1(RecipientCellDataV1Reader::from_slice(table)?)
+            }
+            Some((2, table)) => Self::from_v2(RecipientCellDataV2Reader::from_slice(table)?),
+            Some((3, table)) => Self::from_v3(RecipientCellDataV3Reader::from_slice(table)?),
+            Some((4, table)) => Self::from_v4(RecipientCellDataV4Reader::from_slice(table)?),
+            Some((5, table)) => Self::from_v5(RecipientCellDataV5Reader::from_slice(table)?),
+            version => Err(VerificationError::UnknownItem(
+                "RecipientCellData".to_string(),
+                5,
+                version.map_or(0, |(version, _)| version.into()),
+            )
+            .into()),
+        }
     }
 
-    pub fn as_molecule_data(&self) -> Result<Bytes, VerificationError> {
-        let mol_obj = RecipientCellData::new_builder()
-            .recipient_address(self.recipient_address.clone().into())
-            .asset(self.asset.clone().into())
-            .chain(self.chain.into())
+    fn from_v1(
+        data_reader: RecipientCellDataV1Reader<'a>,
+    ) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        ref_from_reader!(data_reader, RecipientDataVersion::V1, None, &[], None)
+    }
+
+    fn from_v2(
+        data_reader: RecipientCellDataV2Reader<'a>,
+    ) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        let mut lock_hash = [0u8; 32];
+        lock_hash.copy_from_slice(data_reader.refund_lock_hash().raw_data());
+        let mut since = [0u8; 8];
+        since.copy_from_slice(data_reader.refund_since().raw_data());
+        let since = u64::from_le_bytes(since);
+
+        ref_from_reader!(
+            data_reader,
+            RecipientDataVersion::V2,
+            Some(RecipientRefund { lock_hash, since }),
+            &[],
+            None
+        )
+    }
+
+    fn from_v3(
+        data_reader: RecipientCellDataV3Reader<'a>,
+    ) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        let refund = data_reader.refund().to_opt().map(refund_from_reader);
+        let memo = data_reader.memo().raw_data();
+
+        ref_from_reader!(data_reader, RecipientDataVersion::V3, refund, memo, None)
+    }
+
+    fn from_v4(
+        data_reader: RecipientCellDataV4Reader<'a>,
+    ) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        let refund = data_reader.refund().to_opt().map(refund_from_reader);
+        let memo = data_reader.memo().raw_data();
+        let evm_destination = EvmDestination::from_reader(data_reader.evm_destination());
+
+        ref_from_reader!(
+            data_reader,
+            RecipientDataVersion::V4,
+            refund,
+            memo,
+            Some(evm_destination)
+        )
+    }
+
+    fn from_v5(
+        data_reader: RecipientCellDataV5Reader<'a>,
+    ) -> Result<RecipientDataRef<'a>, RecipientDataError> {
+        let refund = data_reader.refund().to_opt().map(refund_from_reader);
+        let memo = data_reader.memo().raw_data();
+        let evm_destination = data_reader
+            .evm_destination()
+            .to_opt()
+            .map(EvmDestination::from_reader);
+
+        ref_from_reader!(
+            data_reader,
+            RecipientDataVersion::V5,
+            refund,
+            memo,
+            evm_destination
+        )
+    }
+
+    pub fn to_view(&self) -> RecipientDataView {
+        RecipientDataView {
+            version: self.version,
+            recipient_address: self.recipient_address.to_string(),
+            chain: self.chain,
+            asset: self.asset.to_string(),
+            bridge_lock_code_hash: self.bridge_lock_code_hash,
+            bridge_lock_hash_type: self.bridge_lock_hash_type,
+            owner_cell_type_hash: self.owner_cell_type_hash,
+            amount: self.amount,
+            refund: self.refund,
+            memo: self.memo.to_vec(),
+            evm_destination: self.evm_destination,
+        }
+    }
+}
+
+// The builder of a version with the fields every version shares set.
+macro_rules! builder_with_shared_fields {
+    ($view:expr, $entity:ty) => {{
+        let view = $view;
+        <$entity>::new_builder()
+            .recipient_address(view.recipient_address.clone().into())
+            .asset(view.asset.clone().into())
+            .chain(view.chain.into())
             .owner_cell_type_hash(
-                self.owner_cell_type_hash
+                view.owner_cell_type_hash
                     .to_vec()
                     .try_into()
                     .expect("owner_lock_hash convert fail"),
             )
             .bridge_lock_code_hash(
-                self.bridge_lock_code_hash
+                view.bridge_lock_code_hash
                     .to_vec()
                     .try_into()
                     .expect("bridge_lock_code_hash convert fail"),
             )
-            .bridge_lock_hash_type(self.bridge_lock_hash_type.into())
-            .amount(self.amount.into())
-            .build();
-        Ok(mol_obj.as_bytes())
+            .bridge_lock_hash_type(view.bridge_lock_hash_type.into())
+            .amount(view.amount.into())
+    }};
+}
+
+impl RecipientDataView {
+    pub fn new(data: &[u8]) -> Result<RecipientDataView, RecipientDataError> {
+        RecipientDataRef::new(data).map(|data| data.to_view())
+    }
+
+    pub fn as_molecule_data(&self) -> Result<Bytes, VerificationError> {
+        let data = match self.version {
+            RecipientDataVersion::V1 => {
+                versioned::join(versioned::V1, self.as_molecule_data_v1().as_slice())
+            }
+            RecipientDataVersion::V2 => versioned::join(2, self.as_molecule_data_v2()?.as_slice()),
+            RecipientDataVersion::V3 => versioned::join(3, self.as_molecule_data_v3().as_slice()),
+            RecipientDataVersion::V4 => versioned::join(4, self.as_molecule_data_v4()?.as_slice()),
+            RecipientDataVersion::V5 => versioned::join(5, self.as_molecule_data_v5().as_slice()),
+        };
+        Ok(data)
+    }
+
+    fn as_molecule_data_v1(&self) -> RecipientCellDataV1 {
+        builder_with_shared_fields!(self, RecipientCellDataV1).build()
+    }
+
+    fn as_molecule_data_v2(&self) -> Result<RecipientCellDataV2, VerificationError> {
+        let refund = self.refund.ok_or_else(|| {
+            VerificationError::FieldCountNotMatch("RecipientCellDataV2".to_string(), 9, 7)
+        })?;
+        Ok(builder_with_shared_fields!(self, RecipientCellDataV2)
+            .refund_lock_hash(
+                refund
+                    .lock_hash
+                    .to_vec()
+                    .try_into()
+                    .expect("refund_lock_hash convert fail"),
+            )
+            .refund_since(refund.since.into())
+            .build())
+    }
+
+    fn as_molecule_data_v3(&self) -> RecipientCellDataV3 {
+        builder_with_shared_fields!(self, RecipientCellDataV3)
+            .refund(self.refund_opt())
+            .memo(self.memo.clone().into())
+            .build()
+    }
+
+    fn as_molecule_data_v4(&self) -> Result<RecipientCellDataV4, VerificationError> {
+        let evm_destination = self.evm_destination.ok_or_else(|| {
+            VerificationError::FieldCountNotMatch("RecipientCellDataV4".to_string(), 10, 9)
+        })?;
+        Ok(builder_with_shared_fields!(self, RecipientCellDataV4)
+            .refund(self.refund_opt())
+            .memo(self.memo.clone().into())
+            .evm_destination(evm_destination.as_molecule())
+            .build())
+    }
+
+    fn as_molecule_data_v5(&self) -> RecipientCellDataV5 {
+        let evm_destination = self
+            .evm_destination
+            .map(|evm_destination| evm_destination.as_molecule());
+        builder_with_shared_fields!(self, RecipientCellDataV5)
+            .refund(self.refund_opt())
+            .memo(self.memo.clone().into())
+            .evm_destination(
+                EvmDestinationOpt::new_builder()
+                    .set(evm_destination)
+                    .build(),
+            )
+            .build()
+    }
+
+    fn refund_opt(&self) -> RecipientRefundOpt {
+        let refund = self.refund.map(|refund| {
+            RecipientRefundMol::new_builder()
+                .lock_hash(
+                    refund
+                        .lock_hash
+                        .to_vec()
+                        .try_into()
+                        .expect("refund_lock_hash convert fail"),
+                )
+                .since(refund.since.into())
+                .build()
+        });
+        RecipientRefundOpt::new_builder().set(refund).build()
+    }
+}
+
+fn refund_from_reader(reader: RecipientRefundReader) -> RecipientRefund {
+    let mut lock_hash = [0u8; 32];
+    lock_hash.copy_from_slice(reader.lock_hash().raw_data());
+    let mut since = [0u8; 8];
+    since.copy_from_slice(reader.since().raw_data());
+    RecipientRefund {
+        lock_hash,
+        since: u64::from_le_bytes(since),
     }
 }
 
+fn utf8_field<'a>(raw: &'a [u8], field: &'static str) -> Result<&'a str, RecipientDataError> {
+    core::str::from_utf8(raw).map_err(|_| RecipientDataError::InvalidUtf8(field))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::RecipientDataView;
+    use super::{
+        RecipientDataError, RecipientDataRef, RecipientDataVersion, RecipientDataView,
+        RecipientRefund, MAX_MEMO_LEN,
+    };
+    use crate::evm::EvmDestination;
 
     #[test]
     fn test_eth_recipient_data() {
         let eth_recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V1,
             recipient_address: "TX3MGfWT5aGv81vTSdZtr6hbHxhMVh1FFM".to_string(),
             chain: 1,
             asset: "TRC".to_string(),
@@ -91,10 +396,14 @@ mod tests {
             bridge_lock_hash_type: 0,
             owner_cell_type_hash: [2u8; 32],
             amount: 100,
+            refund: None,
+            memo: vec![],
+            evm_destination: None,
         };
         let mol_data = eth_recipient_data.as_molecule_data().unwrap();
         let new_eth_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
 
+        assert_eq!(eth_recipient_data.version, new_eth_recipient_data.version);
         assert_eq!(
             eth_recipient_data.recipient_address,
             new_eth_recipient_data.recipient_address
@@ -115,4 +424,232 @@ mod tests {
         );
         assert_eq!(eth_recipient_data.amount, new_eth_recipient_data.amount);
     }
+
+    #[test]
+    fn test_unknown_recipient_data_version() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V1,
+            recipient_address: "TX3MGfWT5aGv81vTSdZtr6hbHxhMVh1FFM".to_string(),
+            chain: 1,
+            asset: "TRC".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: None,
+            memo: vec![],
+            evm_destination: None,
+        };
+        let mut mol_data = recipient_data.as_molecule_data().unwrap().to_vec();
+        mol_data.insert(0, 0xff);
+        assert!(RecipientDataView::new(mol_data.as_ref()).is_err());
+        mol_data[0] = 1;
+        assert!(RecipientDataView::new(mol_data.as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_non_utf8_recipient_address_is_rejected() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V1,
+            recipient_address: "TX3MGfWT5aGv81vTSdZtr6hbHxhMVh1FFM".to_string(),
+            chain: 1,
+            asset: "TRC".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: None,
+            memo: vec![],
+            evm_destination: None,
+        };
+        let mut mol_data = recipient_data.as_molecule_data().unwrap().to_vec();
+        let address_start = mol_data
+            .windows(2)
+            .position(|window| window == b"TX")
+            .unwrap();
+        mol_data[address_start] = 0xff;
+        assert!(matches!(
+            RecipientDataView::new(mol_data.as_ref()),
+            Err(RecipientDataError::InvalidUtf8("recipient_address"))
+        ));
+    }
+
+    #[test]
+    fn test_recipient_data_v2_with_refund() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V2,
+            recipient_address: "TX3MGfWT5aGv81vTSdZtr6hbHxhMVh1FFM".to_string(),
+            chain: 1,
+            asset: "TRC".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: Some(RecipientRefund {
+                lock_hash: [3u8; 32],
+                since: 0x2000_0000_0000_0064,
+            }),
+            memo: vec![],
+            evm_destination: None,
+        };
+        let mol_data = recipient_data.as_molecule_data().unwrap();
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+
+        assert_eq!(new_recipient_data.version, RecipientDataVersion::V2);
+        assert_eq!(new_recipient_data.amount, recipient_data.amount);
+        assert_eq!(new_recipient_data.refund, recipient_data.refund);
+
+        let without_refund = RecipientDataView {
+            refund: None,
+            ..recipient_data
+        };
+        assert!(without_refund.as_molecule_data().is_err());
+    }
+
+    #[test]
+    fn test_recipient_data_v3_with_memo() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V3,
+            recipient_address: "0x5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+            chain: 1,
+            asset: "0x0000000000000000000000000000000000000000".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: None,
+            memo: vec![7u8; MAX_MEMO_LEN],
+            evm_destination: None,
+        };
+        let mol_data = recipient_data.as_molecule_data().unwrap();
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+        assert_eq!(new_recipient_data.version, RecipientDataVersion::V3);
+        assert_eq!(new_recipient_data.refund, None);
+        assert_eq!(new_recipient_data.memo, recipient_data.memo);
+
+        let with_refund = RecipientDataView {
+            refund: Some(RecipientRefund {
+                lock_hash: [3u8; 32],
+                since: 100,
+            }),
+            memo: vec![],
+            ..recipient_data
+        };
+        let mol_data = with_refund.as_molecule_data().unwrap();
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+        assert_eq!(new_recipient_data.refund, with_refund.refund);
+        assert!(new_recipient_data.memo.is_empty());
+    }
+
+    #[test]
+    fn test_recipient_data_v4_with_evm_destination() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V4,
+            recipient_address: "0x5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+            chain: 1,
+            asset: "0x0000000000000000000000000000000000000000".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: None,
+            memo: vec![7u8; 3],
+            evm_destination: Some(EvmDestination {
+                chain_id: 56,
+                bridge_contract_address: [4u8; 20],
+            }),
+        };
+        let mol_data = recipient_data.as_molecule_data().unwrap();
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+        assert_eq!(new_recipient_data.version, RecipientDataVersion::V4);
+        assert_eq!(new_recipient_data.memo, recipient_data.memo);
+        assert_eq!(
+            new_recipient_data.evm_destination,
+            recipient_data.evm_destination
+        );
+
+        let without_evm_destination = RecipientDataView {
+            evm_destination: None,
+            ..recipient_data
+        };
+        assert!(without_evm_destination.as_molecule_data().is_err());
+    }
+
+    #[test]
+    fn test_recipient_data_v5_in_xchain_units() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V5,
+            recipient_address: "0x5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+            chain: 1,
+            asset: "0x0000000000000000000000000000000000000000".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 10u128.pow(18),
+            refund: None,
+            memo: vec![],
+            evm_destination: None,
+        };
+        let mol_data = recipient_data.as_molecule_data().unwrap();
+        assert_eq!(mol_data[0], 5);
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+        assert_eq!(new_recipient_data.version, RecipientDataVersion::V5);
+        assert!(new_recipient_data.version.is_amount_in_xchain_units());
+        assert_eq!(new_recipient_data.amount, recipient_data.amount);
+        assert_eq!(new_recipient_data.evm_destination, None);
+
+        let with_evm_destination = RecipientDataView {
+            evm_destination: Some(EvmDestination {
+                chain_id: 56,
+                bridge_contract_address: [4u8; 20],
+            }),
+            ..recipient_data
+        };
+        let mol_data = with_evm_destination.as_molecule_data().unwrap();
+        let new_recipient_data = RecipientDataView::new(mol_data.as_ref()).unwrap();
+        assert_eq!(
+            new_recipient_data.evm_destination,
+            with_evm_destination.evm_destination
+        );
+        assert!(!RecipientDataVersion::V4.is_amount_in_xchain_units());
+    }
+
+    #[test]
+    fn test_recipient_data_ref_borrows_the_data() {
+        let recipient_data = RecipientDataView {
+            version: RecipientDataVersion::V4,
+            recipient_address: "0x5Dc158c90EBE46FfC9f03f1174f36c44497976D4".to_string(),
+            chain: 1,
+            asset: "0x0000000000000000000000000000000000000000".to_string(),
+            bridge_lock_code_hash: [1u8; 32],
+            bridge_lock_hash_type: 0,
+            owner_cell_type_hash: [2u8; 32],
+            amount: 100,
+            refund: Some(RecipientRefund {
+                lock_hash: [3u8; 32],
+                since: 100,
+            }),
+            memo: vec![7u8; 3],
+            evm_destination: Some(EvmDestination {
+                chain_id: 56,
+                bridge_contract_address: [4u8; 20],
+            }),
+        };
+        let mol_data = recipient_data.as_molecule_data().unwrap();
+        let data_ref = RecipientDataRef::new(mol_data.as_ref()).unwrap();
+        let data_range = mol_data.as_ptr_range();
+        assert!(data_range.contains(&data_ref.asset.as_ptr()));
+        assert!(data_range.contains(&data_ref.memo.as_ptr()));
+        assert_eq!(data_ref.recipient_address, recipient_data.recipient_address);
+        assert_eq!(data_ref.asset, recipient_data.asset);
+        assert_eq!(data_ref.memo, recipient_data.memo.as_slice());
+        assert_eq!(data_ref.refund, recipient_data.refund);
+        assert_eq!(data_ref.evm_destination, recipient_data.evm_destination);
+
+        let view = data_ref.to_view();
+        assert_eq!(
+            view.as_molecule_data().unwrap(),
+            recipient_data.as_molecule_data().unwrap()
+        );
+    }
 }
@@ -0,0 +1,78 @@
+use core::str;
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Data of a token info cell, as the sUDT Info RFC lays it out: the decimals, then the name and
+/// the symbol, each a one byte length followed by its UTF-8 bytes. The RFC leaves the bytes
+/// after the symbol to later fields, they are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfoView {
+    pub decimals: u8,
+    pub name: String,
+    pub symbol: String,
+}
+
+impl TokenInfoView {
+    /// `None` if the data is not a token info, or its name or symbol is empty.
+    pub fn from_slice(data: &[u8]) -> Option<TokenInfoView> {
+        let (decimals, rest) = data.split_first()?;
+        let (name, rest) = read_str(rest)?;
+        let (symbol, _) = read_str(rest)?;
+        Some(TokenInfoView {
+            decimals: *decimals,
+            name: name.into(),
+            symbol: symbol.into(),
+        })
+    }
+
+    /// Panics if the name or the symbol is longer than 255 bytes.
+    pub fn as_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(3 + self.name.len() + self.symbol.len());
+        data.push(self.decimals);
+        for field in [&self.name, &self.symbol].iter() {
+            assert!(field.len() <= 255, "token info field too long");
+            data.push(field.len() as u8);
+            data.extend_from_slice(field.as_bytes());
+        }
+        data
+    }
+}
+
+fn read_str(data: &[u8]) -> Option<(&str, &[u8])> {
+    let (len, rest) = data.split_first()?;
+    let len = usize::from(*len);
+    if len == 0 || rest.len() < len {
+        return None;
+    }
+    let field = str::from_utf8(&rest[..len]).ok()?;
+    Some((field, &rest[len..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TokenInfoView;
+
+    #[test]
+    fn test_token_info() {
+        let info = TokenInfoView {
+            decimals: 8,
+            name: "Wrapped Ether".to_string(),
+            symbol: "ETH".to_string(),
+        };
+        let mut data = info.as_bytes();
+        assert_eq!(data[..3], [8, 13, b'W']);
+        assert_eq!(TokenInfoView::from_slice(&data), Some(info.clone()));
+
+        data.extend_from_slice(&[1, 2, 3]);
+        assert_eq!(TokenInfoView::from_slice(&data), Some(info));
+
+        assert_eq!(TokenInfoView::from_slice(&[]), None);
+        assert_eq!(
+            TokenInfoView::from_slice(&[8, 0, 3, b'E', b'T', b'H']),
+            None
+        );
+        assert_eq!(TokenInfoView::from_slice(&[8, 3, b'E', b'T']), None);
+        assert_eq!(TokenInfoView::from_slice(&[8, 1, 0xff, 1, b'E']), None);
+    }
+}
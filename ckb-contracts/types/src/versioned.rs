@@ -0,0 +1,82 @@
+//! Versioned molecule data, for script args and cell data existing cells and hashes depend on.
+//!
+//! Version 1 is the table written before the data was versioned, as is, so its bytes and every
+//! hash of them stay the same. A table starts with its total size, which a version byte followed
+//! by a table never matches. Every later version is its version byte followed by its table.
+
+use core::convert::TryInto;
+use molecule::bytes::Bytes;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+pub const V1: u8 = 1;
+
+/// The version of `data` and its table. `None` for empty data, and for a version 1 table behind
+/// a version byte, which would be a second encoding of the same data.
+pub fn split(data: &[u8]) -> Option<(u8, &[u8])> {
+    if is_unversioned(data) {
+        return Some((V1, data));
+    }
+    match data.split_first() {
+        None | Some((&V1, _)) => None,
+        Some((&version, table)) => Some((version, table)),
+    }
+}
+
+/// `table` of `version`, as `split` reads it.
+pub fn join(version: u8, table: &[u8]) -> Bytes {
+    if version == V1 {
+        return Bytes::from(table.to_vec());
+    }
+    let mut data = Vec::with_capacity(table.len() + 1);
+    data.push(version);
+    data.extend_from_slice(table);
+    Bytes::from(data)
+}
+
+fn is_unversioned(data: &[u8]) -> bool {
+    data.len() >= 4
+        && u32::from_le_bytes(data[..4].try_into().expect("4 bytes")) as usize == data.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, split, V1};
+    use crate::generated::basic::Bytes as MolBytes;
+    use molecule::prelude::{Builder, Entity};
+
+    fn table() -> Vec<u8> {
+        // a table of one `Bytes` field, its total size first.
+        let field = MolBytes::new_builder()
+            .extend(vec![1u8.into(), 2u8.into()])
+            .build();
+        let mut table = ((8 + field.as_slice().len()) as u32).to_le_bytes().to_vec();
+        table.extend_from_slice(&8u32.to_le_bytes());
+        table.extend_from_slice(field.as_slice());
+        table
+    }
+
+    #[test]
+    fn test_v1_is_the_table_as_is() {
+        let table = table();
+        assert_eq!(join(V1, &table).as_ref(), table.as_slice());
+        assert_eq!(split(&table), Some((V1, table.as_slice())));
+    }
+
+    #[test]
+    fn test_later_versions_have_a_version_byte() {
+        let table = table();
+        let data = join(2, &table);
+        assert_eq!(data[0], 2);
+        assert_eq!(split(&data), Some((2, table.as_slice())));
+    }
+
+    #[test]
+    fn test_v1_behind_a_version_byte_is_invalid() {
+        let mut data = vec![V1];
+        data.extend_from_slice(&table());
+        assert_eq!(split(&data), None);
+        assert_eq!(split(&[]), None);
+    }
+}